@@ -0,0 +1,173 @@
+#![no_std]
+
+//! Falcon-512 Key Registry for Soroban.
+//!
+//! Maps account addresses to Falcon-512 public keys, so a single deployment
+//! can serve many accounts. Each account registers and revokes its own key,
+//! and anyone can check a signature against a registered key with
+//! `verify_for`.
+//!
+//! Verification is embedded, as in the smart account: the verifier's core
+//! modules are compiled into this contract instead of being called across
+//! contracts.
+
+use soroban_sdk::{
+    contract, contracterror, contractimpl, symbol_short, Address, Bytes, Env, Symbol,
+};
+
+mod ntt;
+pub mod verify;
+
+#[cfg(feature = "debug")]
+pub use verify::NormDiagnostic;
+#[cfg(feature = "testutils")]
+pub use verify::VerifyCost;
+pub use verify::{
+    falcon_l2_bound, sig_size, FalconSignature, FalconVerifier, FalconVerifyContext, HashMode,
+    Packing, SigFormat, VerifyError, VerifyOutcome, VerifyState,
+};
+
+// Falcon-512 constants
+pub const FALCON_512_LOGN: u32 = 9;
+pub const FALCON_512_N: usize = 512;
+pub const FALCON_512_PUBKEY_SIZE: usize = 897;
+// Header byte plus 512 coefficients packed at 14 bits each
+const _: () = assert!(FALCON_512_PUBKEY_SIZE == 1 + (FALCON_512_N * 14).div_ceil(8));
+/// Length of the signature nonce, the same for Falcon-512 and Falcon-1024
+pub const NONCE_LEN: usize = 40;
+/// Smallest Falcon-512 signature: header, nonce and a compressed body of at
+/// least 9 bits per coefficient
+pub const FALCON_SIG_MIN_SIZE: u32 = 1 + NONCE_LEN as u32 + (FALCON_512_N as u32 * 9).div_ceil(8);
+/// Largest Falcon-512 signature, in the constant-time format
+pub const FALCON_SIG_MAX_SIZE: u32 =
+    sig_size(SigFormat::ConstantTime, FALCON_512_LOGN).unwrap() as u32;
+/// The prime modulus for Falcon ring arithmetic
+pub const Q: u32 = 12289;
+/// Squared L2 norm bound for Falcon-512 signatures
+pub const L2_BOUND_512: u32 = falcon_l2_bound(FALCON_512_LOGN).unwrap() as u32;
+
+pub const FALCON_1024_LOGN: u32 = 10;
+pub const FALCON_1024_N: usize = 1024;
+pub const FALCON_1024_PUBKEY_SIZE: usize = 1793;
+// Header byte plus 1024 coefficients packed at 14 bits each
+const _: () = assert!(FALCON_1024_PUBKEY_SIZE == 1 + (FALCON_1024_N * 14).div_ceil(8));
+/// Squared L2 norm bound for Falcon-1024 signatures
+pub const L2_BOUND_1024: u32 = falcon_l2_bound(FALCON_1024_LOGN).unwrap() as u32;
+
+/// Ledgers a registered key stays live after it is registered or used
+/// (about 30 days at 5 seconds per ledger).
+pub const KEY_TTL_LEDGERS: u32 = 518_400;
+// Use only extends a key whose TTL has dropped by a day or more
+const KEY_TTL_THRESHOLD: u32 = KEY_TTL_LEDGERS - 17_280;
+// Persistent storage namespace for registered keys
+const KEY_PREFIX: Symbol = symbol_short!("F_PUBKEY");
+
+#[contracterror]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum Error {
+    InvalidPublicKeySize = 1,
+    InvalidPublicKey = 2,
+    AlreadyRegistered = 3,
+    NotRegistered = 4,
+}
+
+#[contract]
+pub struct FalconRegistry;
+
+#[contractimpl]
+impl FalconRegistry {
+    /// Register the Falcon public key of an account.
+    ///
+    /// Requires authorization by `id`, so an account can only register its
+    /// own key. The key is kept in persistent storage for
+    /// [`KEY_TTL_LEDGERS`] ledgers, extended whenever it is used. To replace
+    /// a key, [`revoke`](Self::revoke) it first.
+    ///
+    /// # Arguments
+    /// * `id` - The account the key belongs to
+    /// * `pubkey` - 897-byte Falcon-512 public key
+    ///
+    /// # Returns
+    /// * `Ok(())` if the key was registered
+    /// * `Err(Error::InvalidPublicKeySize)` if the key is not 897 bytes
+    /// * `Err(Error::InvalidPublicKey)` if the key does not decode
+    /// * `Err(Error::AlreadyRegistered)` if `id` already has a key
+    pub fn register(env: Env, id: Address, pubkey: Bytes) -> Result<(), Error> {
+        id.require_auth();
+
+        if pubkey.len() != FALCON_512_PUBKEY_SIZE as u32 {
+            return Err(Error::InvalidPublicKeySize);
+        }
+        let mut pk_bytes = [0u8; FALCON_512_PUBKEY_SIZE];
+        pubkey.copy_into_slice(&mut pk_bytes);
+        let mut h = [0u16; FALCON_512_N];
+        if !FalconVerifier::decode_pubkey(&pk_bytes, &mut h) {
+            return Err(Error::InvalidPublicKey);
+        }
+
+        let storage = env.storage().persistent();
+        let key = (KEY_PREFIX, id);
+        if storage.has(&key) {
+            return Err(Error::AlreadyRegistered);
+        }
+        storage.set(&key, &pubkey);
+        storage.extend_ttl(&key, KEY_TTL_THRESHOLD, KEY_TTL_LEDGERS);
+        Ok(())
+    }
+
+    /// Get the Falcon public key registered for `id`.
+    ///
+    /// Returns `None` if `id` has no key.
+    pub fn get_pubkey(env: Env, id: Address) -> Option<Bytes> {
+        env.storage().persistent().get(&(KEY_PREFIX, id))
+    }
+
+    /// Verify a Falcon-512 signature against the key registered for `id`.
+    ///
+    /// The whole message is hashed, with no length cap.
+    ///
+    /// # Arguments
+    /// * `id` - The account whose key should have signed
+    /// * `message` - Message that was signed
+    /// * `signature` - Falcon signature (any supported format)
+    ///
+    /// # Returns
+    /// * `Ok(true)` if the signature is valid, `Ok(false)` otherwise
+    /// * `Err(Error::NotRegistered)` if `id` has no key
+    pub fn verify_for(
+        env: Env,
+        id: Address,
+        message: Bytes,
+        signature: Bytes,
+    ) -> Result<bool, Error> {
+        let storage = env.storage().persistent();
+        let key = (KEY_PREFIX, id);
+        let pubkey: Bytes = storage.get(&key).ok_or(Error::NotRegistered)?;
+        storage.extend_ttl(&key, KEY_TTL_THRESHOLD, KEY_TTL_LEDGERS);
+
+        Ok(FalconVerifier::verify_512_streaming(
+            &pubkey, &message, &signature,
+        ))
+    }
+
+    /// Remove the key registered for `id`.
+    ///
+    /// Requires authorization by `id`. Afterwards `verify_for` fails with
+    /// `NotRegistered` until a new key is registered.
+    ///
+    /// # Returns
+    /// * `Ok(())` if the key was removed
+    /// * `Err(Error::NotRegistered)` if `id` has no key
+    pub fn revoke(env: Env, id: Address) -> Result<(), Error> {
+        id.require_auth();
+
+        let storage = env.storage().persistent();
+        let key = (KEY_PREFIX, id);
+        if !storage.has(&key) {
+            return Err(Error::NotRegistered);
+        }
+        storage.remove(&key);
+        Ok(())
+    }
+}
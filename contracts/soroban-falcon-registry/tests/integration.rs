@@ -0,0 +1,132 @@
+//! Integration tests for the Falcon key registry.
+
+#![cfg(feature = "testutils")]
+
+use soroban_sdk::testutils::{storage::Persistent, Address as _};
+use soroban_sdk::{symbol_short, Address, Bytes, Env};
+
+use soroban_falcon_registry::{Error, FalconRegistry, FalconRegistryClient, KEY_TTL_LEDGERS};
+
+// Signature over "Hello, Falcon!" (padded format, 666 bytes)
+const TEST_PUBKEY_HEX: &str = include_str!("fixtures/test_pubkey.hex");
+const TEST_SIGNATURE_HEX: &str = include_str!("fixtures/test_signature.hex");
+const TEST_MESSAGE: &[u8] = b"Hello, Falcon!";
+// An unrelated keypair, generated with falcon-wasm
+const OTHER_PUBKEY_HEX: &str = include_str!("fixtures/other_pubkey.hex");
+
+fn bytes_from_hex(env: &Env, hex: &str) -> Bytes {
+    Bytes::from_slice(env, &hex::decode(hex.trim()).expect("Invalid hex"))
+}
+
+#[test]
+fn test_register_and_verify_for() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let registry = env.register(FalconRegistry, ());
+    let client = FalconRegistryClient::new(&env, &registry);
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let alice_key = bytes_from_hex(&env, TEST_PUBKEY_HEX);
+    let bob_key = bytes_from_hex(&env, OTHER_PUBKEY_HEX);
+    client.register(&alice, &alice_key);
+    client.register(&bob, &bob_key);
+    assert_eq!(client.get_pubkey(&alice), Some(alice_key));
+    assert_eq!(client.get_pubkey(&bob), Some(bob_key));
+
+    let message = Bytes::from_slice(&env, TEST_MESSAGE);
+    let signature = bytes_from_hex(&env, TEST_SIGNATURE_HEX);
+    assert!(client.verify_for(&alice, &message, &signature));
+    let wrong_message = Bytes::from_slice(&env, b"Wrong message");
+    assert!(!client.verify_for(&alice, &wrong_message, &signature));
+    // The lookup is per account: Bob's key did not sign it
+    assert!(!client.verify_for(&bob, &message, &signature));
+
+    // Registering extends the key's TTL
+    env.as_contract(&registry, || {
+        let key = (symbol_short!("F_PUBKEY"), alice.clone());
+        assert_eq!(env.storage().persistent().get_ttl(&key), KEY_TTL_LEDGERS);
+    });
+}
+
+#[test]
+fn test_register_rejections() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = FalconRegistryClient::new(&env, &env.register(FalconRegistry, ()));
+    let alice = Address::generate(&env);
+    let key_bytes = hex::decode(TEST_PUBKEY_HEX.trim()).unwrap();
+
+    let short = Bytes::from_slice(&env, &key_bytes[..100]);
+    assert_eq!(
+        client.try_register(&alice, &short),
+        Err(Ok(Error::InvalidPublicKeySize))
+    );
+
+    // Coefficient value 16383 is out of range for q = 12289
+    let mut undecodable = key_bytes.clone();
+    undecodable[1] = 0xFF;
+    undecodable[2] = 0xFF;
+    let undecodable = Bytes::from_slice(&env, &undecodable);
+    assert_eq!(
+        client.try_register(&alice, &undecodable),
+        Err(Ok(Error::InvalidPublicKey))
+    );
+    assert_eq!(client.get_pubkey(&alice), None);
+
+    let key = Bytes::from_slice(&env, &key_bytes);
+    client.register(&alice, &key);
+    let other = bytes_from_hex(&env, OTHER_PUBKEY_HEX);
+    assert_eq!(
+        client.try_register(&alice, &other),
+        Err(Ok(Error::AlreadyRegistered))
+    );
+    assert_eq!(client.get_pubkey(&alice), Some(key));
+}
+
+#[test]
+fn test_revoke() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = FalconRegistryClient::new(&env, &env.register(FalconRegistry, ()));
+    let alice = Address::generate(&env);
+    let key = bytes_from_hex(&env, TEST_PUBKEY_HEX);
+    let message = Bytes::from_slice(&env, TEST_MESSAGE);
+    let signature = bytes_from_hex(&env, TEST_SIGNATURE_HEX);
+
+    assert_eq!(
+        client.try_verify_for(&alice, &message, &signature),
+        Err(Ok(Error::NotRegistered))
+    );
+    assert_eq!(client.try_revoke(&alice), Err(Ok(Error::NotRegistered)));
+
+    client.register(&alice, &key);
+    client.revoke(&alice);
+    assert_eq!(client.get_pubkey(&alice), None);
+    assert_eq!(
+        client.try_verify_for(&alice, &message, &signature),
+        Err(Ok(Error::NotRegistered))
+    );
+
+    // A revoked account can register again, e.g. with a rotated key
+    let other = bytes_from_hex(&env, OTHER_PUBKEY_HEX);
+    client.register(&alice, &other);
+    assert!(!client.verify_for(&alice, &message, &signature));
+}
+
+#[test]
+fn test_register_and_revoke_require_account_auth() {
+    let env = Env::default();
+    let client = FalconRegistryClient::new(&env, &env.register(FalconRegistry, ()));
+    let alice = Address::generate(&env);
+    let key = bytes_from_hex(&env, TEST_PUBKEY_HEX);
+
+    assert!(client.try_register(&alice, &key).is_err());
+    assert_eq!(client.get_pubkey(&alice), None);
+
+    env.mock_all_auths();
+    client.register(&alice, &key);
+    env.set_auths(&[]);
+    assert!(client.try_revoke(&alice).is_err());
+    assert_eq!(client.get_pubkey(&alice), Some(key));
+}
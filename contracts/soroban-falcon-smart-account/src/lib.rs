@@ -7,33 +7,113 @@
 //!
 //! The contract is initialized at deployment with a Falcon public key via the
 //! constructor. All subsequent transactions are authenticated using Falcon
-//! signatures.
+//! signatures. The key can later be replaced with `rotate_key`, which is itself
+//! authorized by the current key. With a grace period set, the replaced key
+//! stays valid for a few ledgers so in-flight transactions still go through.
+//!
+//! An account can also be deployed in hybrid mode, where every authorization
+//! additionally needs an Ed25519 signature from a key fixed at construction.
+//!
+//! Optionally the account keeps an audit log of its most recent successful
+//! authorizations, see `set_audit_log`.
 
+use sha3::{Digest, Sha3_256};
 use soroban_sdk::{
     auth::{Context, CustomAccountInterface},
-    contract, contracterror, contractimpl,
+    contract, contracterror, contractimpl, contracttype,
     crypto::Hash,
-    symbol_short, Bytes, Env, Symbol, Vec,
+    symbol_short,
+    xdr::ToXdr,
+    Address, Bytes, BytesN, Env, Symbol, Vec,
 };
 
 mod ntt;
 pub mod verify;
 
-pub use verify::FalconVerifier;
+#[cfg(feature = "debug")]
+pub use verify::NormDiagnostic;
+#[cfg(feature = "testutils")]
+pub use verify::VerifyCost;
+pub use verify::{
+    falcon_l2_bound, sig_size, FalconSignature, FalconVerifier, FalconVerifyContext, HashMode,
+    Packing, SigFormat, VerifyError, VerifyOutcome, VerifyState,
+};
 
 // Storage key for the Falcon public key
 const FALCON_PUBKEY_KEY: Symbol = symbol_short!("F_PUBKEY");
+// Storage key for the key version, bumped on every key change
+const KEY_VERSION_KEY: Symbol = symbol_short!("KEY_VER");
+// Storage key for the ledgers a replaced key stays valid; 0 disables it
+const KEY_GRACE_KEY: Symbol = symbol_short!("KEY_GRACE");
+// Storage key for the replaced key and the ledger it was rotated out in
+const PREVIOUS_KEY_KEY: Symbol = symbol_short!("PREV_KEY");
+// Storage key for the only signature format accepted, if restricted
+const ALLOWED_FORMAT_KEY: Symbol = symbol_short!("SIG_FMT");
+// Storage key for the Ed25519 public key required alongside Falcon, if any
+const ED25519_PUBKEY_KEY: Symbol = symbol_short!("ED_PUBKEY");
+// Storage key for the nonce window size; 0 disables nonce tracking
+const NONCE_WINDOW_KEY: Symbol = symbol_short!("NONCE_WIN");
+// Persistent storage key for fingerprints of recently accepted nonces
+const SEEN_NONCES_KEY: Symbol = symbol_short!("NONCES");
+// Storage key for whether signers sign the structured AuthPayload
+const STRUCTURED_AUTH_KEY: Symbol = symbol_short!("AUTH_PAY");
+// Storage key for the audit log capacity; 0 disables the log
+const AUDIT_CAPACITY_KEY: Symbol = symbol_short!("AUDIT_CAP");
+// Persistent storage key for the audit log, oldest record first
+const AUDIT_LOG_KEY: Symbol = symbol_short!("AUDIT_LOG");
+
+/// Largest XDR encoding of an [`AuthPayload`]: fixed-size fields plus a
+/// function name of at most 32 characters.
+const MAX_AUTH_PAYLOAD_LEN: usize = 256;
+
+/// Largest number of nonce fingerprints the account will retain.
+pub const MAX_NONCE_WINDOW: u32 = 256;
+
+/// Ledgers the nonce fingerprints stay live after they are written (about 30
+/// days at 5 seconds per ledger).
+pub const NONCE_TTL_LEDGERS: u32 = 518_400;
+// A write only extends the fingerprints once their TTL has dropped by a day or more
+const NONCE_TTL_THRESHOLD: u32 = NONCE_TTL_LEDGERS - 17_280;
+
+/// Largest number of authorization records the audit log will retain.
+pub const MAX_AUDIT_LOG: u32 = 64;
+
+/// Ledgers the audit log stays live after it is written (about 30 days at 5
+/// seconds per ledger).
+pub const AUDIT_TTL_LEDGERS: u32 = 518_400;
+// A write only extends the log once its TTL has dropped by a day or more
+const AUDIT_TTL_THRESHOLD: u32 = AUDIT_TTL_LEDGERS - 17_280;
+
+/// Longest grace period for a replaced key, in ledgers (about 7 days at 5
+/// seconds per ledger).
+pub const MAX_KEY_GRACE_LEDGERS: u32 = 120_960;
 
 // Falcon-512 constants
 pub const FALCON_512_LOGN: u32 = 9;
 pub const FALCON_512_N: usize = 512;
 pub const FALCON_512_PUBKEY_SIZE: usize = 897;
-pub const FALCON_SIG_MIN_SIZE: u32 = 42;
-pub const FALCON_SIG_MAX_SIZE: u32 = 700;
+// Header byte plus 512 coefficients packed at 14 bits each
+const _: () = assert!(FALCON_512_PUBKEY_SIZE == 1 + (FALCON_512_N * 14).div_ceil(8));
+/// Length of the signature nonce, the same for Falcon-512 and Falcon-1024
+pub const NONCE_LEN: usize = 40;
+/// Smallest Falcon-512 signature: header, nonce and a compressed body of at
+/// least 9 bits per coefficient
+pub const FALCON_SIG_MIN_SIZE: u32 = 1 + NONCE_LEN as u32 + (FALCON_512_N as u32 * 9).div_ceil(8);
+/// Largest Falcon-512 signature, in the constant-time format
+pub const FALCON_SIG_MAX_SIZE: u32 =
+    sig_size(SigFormat::ConstantTime, FALCON_512_LOGN).unwrap() as u32;
 /// The prime modulus for Falcon ring arithmetic
 pub const Q: u32 = 12289;
 /// Squared L2 norm bound for Falcon-512 signatures
-pub const L2_BOUND_512: u32 = 34034726;
+pub const L2_BOUND_512: u32 = falcon_l2_bound(FALCON_512_LOGN).unwrap() as u32;
+
+pub const FALCON_1024_LOGN: u32 = 10;
+pub const FALCON_1024_N: usize = 1024;
+pub const FALCON_1024_PUBKEY_SIZE: usize = 1793;
+// Header byte plus 1024 coefficients packed at 14 bits each
+const _: () = assert!(FALCON_1024_PUBKEY_SIZE == 1 + (FALCON_1024_N * 14).div_ceil(8));
+/// Squared L2 norm bound for Falcon-1024 signatures
+pub const L2_BOUND_1024: u32 = falcon_l2_bound(FALCON_1024_LOGN).unwrap() as u32;
 
 #[contracterror]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -42,6 +122,147 @@ pub enum Error {
     InvalidPublicKeySize = 1,
     InvalidSignatureSize = 2,
     VerificationFailed = 3,
+    SignatureDecodeFailed = 4,
+    NormTooLarge = 5,
+    BadFormat = 6,
+    InvalidPublicKey = 7,
+    KeyUnchanged = 8,
+    NonceReused = 9,
+    NonceWindowTooLarge = 10,
+    DisallowedFormat = 11,
+    Ed25519KeyMismatch = 12,
+    UnsupportedAuthContext = 13,
+    AuditLogTooLarge = 14,
+    KeyGraceTooLong = 15,
+    GraceKeyAdminCall = 16,
+}
+
+/// Authorization signature accepted by [`FalconSmartAccount`].
+///
+/// The Ed25519 fields are only checked by accounts deployed with an Ed25519
+/// key; other accounts ignore them.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HybridSignature {
+    /// Falcon-512 signature over the payload (617-809 bytes)
+    pub falcon: Bytes,
+    /// Must equal the account's Ed25519 key
+    pub ed25519_pk: BytesN<32>,
+    /// Ed25519 signature over the payload
+    pub ed25519_sig: BytesN<64>,
+}
+
+/// Message signed by accounts with structured authorization enabled, see
+/// [`FalconSmartAccount::set_structured_auth`].
+///
+/// The signed bytes are the XDR encoding of this value as an `ScVal`, which
+/// off-chain signers build from the same call. Naming the invoked contract,
+/// function and arguments lets a signer show what it authorizes, and the
+/// account rebuilds the value from the call it is asked to authorize.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuthPayload {
+    /// The payload hash passed to `__check_auth`. It commits to the
+    /// authorization nonce and expiration ledger, so a structured signature
+    /// cannot be replayed in another authorization.
+    pub payload: BytesN<32>,
+    /// Contract being invoked
+    pub contract: Address,
+    /// Function being invoked
+    pub fn_name: Symbol,
+    /// SHA-256 of the XDR encoding of the call arguments
+    pub args_hash: BytesN<32>,
+}
+
+impl AuthPayload {
+    /// Builds the payload for an authorization covering exactly one
+    /// contract call.
+    ///
+    /// # Returns
+    /// * `Err(Error::UnsupportedAuthContext)` if `auth_contexts` is not a
+    ///   single contract call
+    pub fn from_contexts(
+        env: &Env,
+        signature_payload: &Hash<32>,
+        auth_contexts: &Vec<Context>,
+    ) -> Result<Self, Error> {
+        let call = match auth_contexts.len() {
+            1 => auth_contexts.get_unchecked(0),
+            _ => return Err(Error::UnsupportedAuthContext),
+        };
+        let Context::Contract(call) = call else {
+            return Err(Error::UnsupportedAuthContext);
+        };
+        Ok(AuthPayload {
+            payload: BytesN::from_array(env, &payload_bytes(signature_payload)),
+            contract: call.contract,
+            fn_name: call.fn_name,
+            args_hash: env.crypto().sha256(&call.args.to_xdr(env)).into(),
+        })
+    }
+}
+
+/// One successful authorization, as kept in the audit log, see
+/// [`FalconSmartAccount::set_audit_log`].
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuthRecord {
+    /// Ledger sequence the authorization was checked in
+    pub ledger: u32,
+    /// The payload hash passed to `__check_auth`
+    pub payload: BytesN<32>,
+    /// First 16 bytes of the SHA-256 of the Falcon signature's nonce, as
+    /// used for nonce reuse detection
+    pub nonce_fingerprint: BytesN<16>,
+}
+
+/// Signature format an account can be locked to at deployment.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum AllowedFormat {
+    Compressed = 0,
+    Padded = 1,
+    ConstantTime = 2,
+}
+
+impl From<AllowedFormat> for SigFormat {
+    fn from(format: AllowedFormat) -> Self {
+        match format {
+            AllowedFormat::Compressed => SigFormat::Compressed,
+            AllowedFormat::Padded => SigFormat::Padded,
+            AllowedFormat::ConstantTime => SigFormat::ConstantTime,
+        }
+    }
+}
+
+impl From<VerifyError> for Error {
+    fn from(err: VerifyError) -> Self {
+        match err {
+            VerifyError::InvalidSignatureSize => Error::InvalidSignatureSize,
+            VerifyError::BadFormat => Error::BadFormat,
+            VerifyError::SignatureDecodeFailed => Error::SignatureDecodeFailed,
+            VerifyError::NormTooLarge => Error::NormTooLarge,
+            // The stored key is only size-checked at construction
+            VerifyError::InvalidPublicKey => Error::VerificationFailed,
+            // The account never bounds the payload length
+            VerifyError::MessageTooLarge => Error::VerificationFailed,
+            VerifyError::ChallengeExhausted => Error::VerificationFailed,
+            // Only reported for caller-supplied keys
+            VerifyError::LikelyArgumentSwap => Error::VerificationFailed,
+        }
+    }
+}
+
+/// The bytes an account signer signs to authorize `signature_payload`.
+///
+/// This is the single definition of the signed message for both the Falcon
+/// and the Ed25519 signature: currently the 32-byte payload hash exactly as
+/// the host passes it to `__check_auth`, with no prefix or domain tag.
+/// Off-chain signers must sign the same bytes. Accounts with structured
+/// authorization enabled sign an [`AuthPayload`] instead.
+pub fn payload_bytes(signature_payload: &Hash<32>) -> [u8; 32] {
+    signature_payload.to_array()
 }
 
 #[contract]
@@ -53,10 +274,21 @@ impl FalconSmartAccount {
     ///
     /// # Arguments
     /// * `falcon_pubkey` - The 897-byte Falcon-512 public key
+    /// * `allowed_format` - If set, the only signature format `__check_auth`
+    ///   accepts, e.g. constant-time for a fixed signature size and cost.
+    ///   The policy cannot be changed after deployment.
+    /// * `ed25519_pubkey` - If set, `__check_auth` also requires an Ed25519
+    ///   signature from this key, for hybrid classical and post-quantum
+    ///   authorization. Fixed at deployment.
     ///
     /// # Panics
     /// Panics if the public key is not exactly 897 bytes.
-    pub fn __constructor(env: Env, falcon_pubkey: Bytes) {
+    pub fn __constructor(
+        env: Env,
+        falcon_pubkey: Bytes,
+        allowed_format: Option<AllowedFormat>,
+        ed25519_pubkey: Option<BytesN<32>>,
+    ) {
         if falcon_pubkey.len() != FALCON_512_PUBKEY_SIZE as u32 {
             panic!("Invalid public key size: expected 897 bytes");
         }
@@ -64,6 +296,15 @@ impl FalconSmartAccount {
         env.storage()
             .instance()
             .set(&FALCON_PUBKEY_KEY, &falcon_pubkey);
+        env.storage().instance().set(&KEY_VERSION_KEY, &0u32);
+        if let Some(format) = allowed_format {
+            env.storage().instance().set(&ALLOWED_FORMAT_KEY, &format);
+        }
+        if let Some(ed25519_pubkey) = ed25519_pubkey {
+            env.storage()
+                .instance()
+                .set(&ED25519_PUBKEY_KEY, &ed25519_pubkey);
+        }
     }
 
     /// Get the stored Falcon public key.
@@ -76,31 +317,387 @@ impl FalconSmartAccount {
             .get(&FALCON_PUBKEY_KEY)
             .expect("Public key not set")
     }
+
+    /// Get the only signature format this account accepts.
+    ///
+    /// Returns `None` when every format is accepted.
+    pub fn get_allowed_format(env: Env) -> Option<AllowedFormat> {
+        env.storage().instance().get(&ALLOWED_FORMAT_KEY)
+    }
+
+    /// Get the Ed25519 key that must co-sign every authorization.
+    ///
+    /// Returns `None` when the account only requires Falcon signatures.
+    pub fn get_ed25519_pubkey(env: Env) -> Option<BytesN<32>> {
+        env.storage().instance().get(&ED25519_PUBKEY_KEY)
+    }
+
+    /// Get the version of the stored public key.
+    ///
+    /// The version starts at 0 when the account is deployed and increases by
+    /// one on every key change, so indexers can detect that the key must be
+    /// re-fetched.
+    pub fn get_key_version(env: Env) -> u32 {
+        env.storage().instance().get(&KEY_VERSION_KEY).unwrap_or(0)
+    }
+
+    /// Get the SHA3-256 fingerprint of the stored Falcon public key.
+    ///
+    /// A compact identifier for indexers and UIs that do not need the full
+    /// 897-byte key. It changes whenever the key is rotated.
+    pub fn pubkey_fingerprint(env: Env) -> BytesN<32> {
+        let mut pubkey = [0u8; FALCON_512_PUBKEY_SIZE];
+        Self::get_pubkey(env.clone()).copy_into_slice(&mut pubkey);
+        BytesN::from_array(&env, &Sha3_256::digest(pubkey).into())
+    }
+
+    /// Replace the stored Falcon public key.
+    ///
+    /// Requires authorization by the account itself, i.e. a signature from
+    /// the current key. The key and its version are written in the same
+    /// invocation, so they can never be observed out of step. If a grace
+    /// period is set, the replaced key keeps authorizing for that many
+    /// ledgers, see [`set_key_grace_period`](Self::set_key_grace_period).
+    ///
+    /// # Arguments
+    /// * `new_pubkey` - The 897-byte Falcon-512 public key to switch to
+    ///
+    /// # Returns
+    /// * `Ok(())` if the key was replaced
+    /// * `Err(Error::InvalidPublicKeySize)` if the new key is not 897 bytes
+    /// * `Err(Error::InvalidPublicKey)` if the new key does not decode
+    /// * `Err(Error::KeyUnchanged)` if the new key equals the current one
+    pub fn rotate_key(env: Env, new_pubkey: Bytes) -> Result<(), Error> {
+        env.current_contract_address().require_auth();
+
+        if new_pubkey.len() != FALCON_512_PUBKEY_SIZE as u32 {
+            return Err(Error::InvalidPublicKeySize);
+        }
+        let mut new_bytes = [0u8; FALCON_512_PUBKEY_SIZE];
+        new_pubkey.copy_into_slice(&mut new_bytes);
+        let mut h = [0u16; FALCON_512_N];
+        if !FalconVerifier::decode_pubkey(&new_bytes, &mut h) {
+            return Err(Error::InvalidPublicKey);
+        }
+
+        let old_pubkey = Self::get_pubkey(env.clone());
+        let mut old_bytes = [0u8; FALCON_512_PUBKEY_SIZE];
+        old_pubkey.copy_into_slice(&mut old_bytes);
+        if FalconVerifier::pubkeys_equivalent(&old_bytes, &new_bytes) {
+            return Err(Error::KeyUnchanged);
+        }
+
+        let version = Self::get_key_version(env.clone()) + 1;
+        let grace = Self::get_key_grace_period(env.clone());
+        let storage = env.storage().instance();
+        storage.set(&FALCON_PUBKEY_KEY, &new_pubkey);
+        storage.set(&KEY_VERSION_KEY, &version);
+        // Only the key replaced last gets a grace period
+        if grace > 0 {
+            storage.set(&PREVIOUS_KEY_KEY, &(old_pubkey, env.ledger().sequence()));
+        } else {
+            storage.remove(&PREVIOUS_KEY_KEY);
+        }
+        Ok(())
+    }
+
+    /// Get the number of ledgers a replaced key stays valid after a rotation.
+    ///
+    /// Returns 0 when replaced keys are rejected at once, which is the default.
+    pub fn get_key_grace_period(env: Env) -> u32 {
+        env.storage().instance().get(&KEY_GRACE_KEY).unwrap_or(0)
+    }
+
+    /// Set how many ledgers a replaced key stays valid after a rotation.
+    ///
+    /// Transactions signed just before a rotation may only land after it.
+    /// While the grace period runs, `__check_auth` accepts a signature from
+    /// the key replaced by the last [`rotate_key`](Self::rotate_key) if the
+    /// current key rejects it, for `ledgers` ledgers starting with the one
+    /// the rotation happened in. The period applies to the running window
+    /// too; setting it to 0 ends the window and forgets the replaced key.
+    ///
+    /// The replaced key only authorizes calls on other contracts. Calls on
+    /// the account itself, such as [`rotate_key`](Self::rotate_key) or this
+    /// function, always need the current key, so a leaked key that was
+    /// rotated out cannot take the account back during the grace period.
+    ///
+    /// Requires authorization by the account itself.
+    ///
+    /// # Returns
+    /// * `Ok(())` if the grace period was updated
+    /// * `Err(Error::KeyGraceTooLong)` if `ledgers` exceeds
+    ///   [`MAX_KEY_GRACE_LEDGERS`]
+    pub fn set_key_grace_period(env: Env, ledgers: u32) -> Result<(), Error> {
+        env.current_contract_address().require_auth();
+
+        if ledgers > MAX_KEY_GRACE_LEDGERS {
+            return Err(Error::KeyGraceTooLong);
+        }
+
+        let storage = env.storage().instance();
+        if ledgers == 0 {
+            storage.remove(&PREVIOUS_KEY_KEY);
+        }
+        storage.set(&KEY_GRACE_KEY, &ledgers);
+        Ok(())
+    }
+
+    /// Get the number of accepted nonces remembered for reuse detection.
+    ///
+    /// Returns 0 when nonce tracking is disabled, which is the default.
+    pub fn get_nonce_window(env: Env) -> u32 {
+        env.storage().instance().get(&NONCE_WINDOW_KEY).unwrap_or(0)
+    }
+
+    /// Whether signers sign the structured [`AuthPayload`] rather than the
+    /// raw payload hash.
+    ///
+    /// Returns `false` by default.
+    pub fn get_structured_auth(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&STRUCTURED_AUTH_KEY)
+            .unwrap_or(false)
+    }
+
+    /// Switch between signing the raw payload hash and the structured
+    /// [`AuthPayload`].
+    ///
+    /// While enabled, `__check_auth` only accepts authorizations of a single
+    /// contract call, with both signatures over the XDR encoding of its
+    /// `AuthPayload`. Requires authorization by the account itself, checked
+    /// under the mode in force before the call.
+    pub fn set_structured_auth(env: Env, enabled: bool) {
+        env.current_contract_address().require_auth();
+        env.storage().instance().set(&STRUCTURED_AUTH_KEY, &enabled);
+    }
+
+    /// Enable, resize or disable nonce reuse detection.
+    ///
+    /// Falcon nonces are 40 random bytes and should never repeat; a repeat
+    /// points at a faulty signer. While enabled, `__check_auth` remembers a
+    /// 16-byte fingerprint of the nonce of each of the last `window` accepted
+    /// signatures and rejects a signature whose nonce was seen among them.
+    /// Shrinking the window drops the oldest fingerprints; a window of 0
+    /// disables the check and clears the stored fingerprints. Each write
+    /// keeps the fingerprints live for [`NONCE_TTL_LEDGERS`] ledgers.
+    ///
+    /// Requires authorization by the account itself.
+    ///
+    /// # Returns
+    /// * `Ok(())` if the window was updated
+    /// * `Err(Error::NonceWindowTooLarge)` if `window` exceeds [`MAX_NONCE_WINDOW`]
+    pub fn set_nonce_window(env: Env, window: u32) -> Result<(), Error> {
+        env.current_contract_address().require_auth();
+
+        if window > MAX_NONCE_WINDOW {
+            return Err(Error::NonceWindowTooLarge);
+        }
+
+        let persistent = env.storage().persistent();
+        if window == 0 {
+            persistent.remove(&SEEN_NONCES_KEY);
+        } else if let Some(mut seen) = persistent.get::<_, Vec<BytesN<16>>>(&SEEN_NONCES_KEY) {
+            while seen.len() > window {
+                seen.pop_front();
+            }
+            persistent.set(&SEEN_NONCES_KEY, &seen);
+            persistent.extend_ttl(&SEEN_NONCES_KEY, NONCE_TTL_THRESHOLD, NONCE_TTL_LEDGERS);
+        }
+        env.storage().instance().set(&NONCE_WINDOW_KEY, &window);
+        Ok(())
+    }
+
+    /// Get the number of authorizations the audit log retains.
+    ///
+    /// Returns 0 when the audit log is disabled, which is the default.
+    pub fn get_audit_log_capacity(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&AUDIT_CAPACITY_KEY)
+            .unwrap_or(0)
+    }
+
+    /// Enable, resize or disable the audit log.
+    ///
+    /// While enabled, every successful `__check_auth` appends an
+    /// [`AuthRecord`] to a log of the last `capacity` authorizations kept in
+    /// persistent storage, so the account holder can review what the key
+    /// authorized. Once full, each new record evicts the oldest one.
+    /// Shrinking the log drops the oldest records; a capacity of 0 disables
+    /// the log and clears it. Each write keeps the log live for
+    /// [`AUDIT_TTL_LEDGERS`] ledgers.
+    ///
+    /// Requires authorization by the account itself.
+    ///
+    /// # Returns
+    /// * `Ok(())` if the capacity was updated
+    /// * `Err(Error::AuditLogTooLarge)` if `capacity` exceeds [`MAX_AUDIT_LOG`]
+    pub fn set_audit_log(env: Env, capacity: u32) -> Result<(), Error> {
+        env.current_contract_address().require_auth();
+
+        if capacity > MAX_AUDIT_LOG {
+            return Err(Error::AuditLogTooLarge);
+        }
+
+        let persistent = env.storage().persistent();
+        if capacity == 0 {
+            persistent.remove(&AUDIT_LOG_KEY);
+        } else if let Some(mut log) = persistent.get::<_, Vec<AuthRecord>>(&AUDIT_LOG_KEY) {
+            while log.len() > capacity {
+                log.pop_front();
+            }
+            persistent.set(&AUDIT_LOG_KEY, &log);
+            persistent.extend_ttl(&AUDIT_LOG_KEY, AUDIT_TTL_THRESHOLD, AUDIT_TTL_LEDGERS);
+        }
+        env.storage().instance().set(&AUDIT_CAPACITY_KEY, &capacity);
+        Ok(())
+    }
+
+    /// Get up to `n` of the most recent authorizations in the audit log,
+    /// newest first.
+    ///
+    /// Returns an empty list when the audit log is disabled.
+    pub fn get_recent_auths(env: Env, n: u32) -> Vec<AuthRecord> {
+        let log: Vec<AuthRecord> = env
+            .storage()
+            .persistent()
+            .get(&AUDIT_LOG_KEY)
+            .unwrap_or_else(|| Vec::new(&env));
+        let mut recent = Vec::new(&env);
+        for record in log.iter().rev().take(n as usize) {
+            recent.push_back(record);
+        }
+        recent
+    }
+}
+
+impl FalconSmartAccount {
+    /// Whether any of `auth_contexts` is a call on the account itself.
+    fn calls_self(env: &Env, auth_contexts: &Vec<Context>) -> bool {
+        let this = env.current_contract_address();
+        auth_contexts
+            .iter()
+            .any(|context| matches!(context, Context::Contract(call) if call.contract == this))
+    }
+
+    /// Whether the key replaced by the last rotation is still within its
+    /// grace period and accepts `signature` over `message`.
+    fn previous_key_verifies(env: &Env, message: &[u8], signature: &[u8]) -> bool {
+        let Some((pubkey, rotated_at)) = env
+            .storage()
+            .instance()
+            .get::<_, (Bytes, u32)>(&PREVIOUS_KEY_KEY)
+        else {
+            return false;
+        };
+        let elapsed = env.ledger().sequence().saturating_sub(rotated_at);
+        if elapsed >= Self::get_key_grace_period(env.clone()) {
+            return false;
+        }
+
+        let mut pk_bytes = [0u8; FALCON_512_PUBKEY_SIZE];
+        pubkey.copy_into_slice(&mut pk_bytes);
+        FalconVerifier::verify_512(&pk_bytes, message, signature)
+    }
+
+    /// The first 16 bytes of the SHA-256 of a signature nonce.
+    fn nonce_fingerprint(env: &Env, nonce: &[u8]) -> BytesN<16> {
+        let digest = env
+            .crypto()
+            .sha256(&Bytes::from_slice(env, nonce))
+            .to_array();
+        let mut prefix = [0u8; 16];
+        prefix.copy_from_slice(&digest[..16]);
+        BytesN::from_array(env, &prefix)
+    }
+
+    /// Record the fingerprint of an accepted signature's nonce, rejecting it
+    /// if it is already among the last `window` fingerprints.
+    fn record_nonce(env: &Env, fingerprint: BytesN<16>, window: u32) -> Result<(), Error> {
+        let persistent = env.storage().persistent();
+        let mut seen: Vec<BytesN<16>> = persistent
+            .get(&SEEN_NONCES_KEY)
+            .unwrap_or_else(|| Vec::new(env));
+        if seen.contains(&fingerprint) {
+            return Err(Error::NonceReused);
+        }
+
+        seen.push_back(fingerprint);
+        while seen.len() > window {
+            seen.pop_front();
+        }
+        persistent.set(&SEEN_NONCES_KEY, &seen);
+        persistent.extend_ttl(&SEEN_NONCES_KEY, NONCE_TTL_THRESHOLD, NONCE_TTL_LEDGERS);
+        Ok(())
+    }
+
+    /// Append a record to the audit log, evicting the oldest records beyond
+    /// `capacity`.
+    fn record_auth(env: &Env, record: AuthRecord, capacity: u32) {
+        let persistent = env.storage().persistent();
+        let mut log: Vec<AuthRecord> = persistent
+            .get(&AUDIT_LOG_KEY)
+            .unwrap_or_else(|| Vec::new(env));
+        log.push_back(record);
+        while log.len() > capacity {
+            log.pop_front();
+        }
+        persistent.set(&AUDIT_LOG_KEY, &log);
+        persistent.extend_ttl(&AUDIT_LOG_KEY, AUDIT_TTL_THRESHOLD, AUDIT_TTL_LEDGERS);
+    }
 }
 
 #[contractimpl]
 impl CustomAccountInterface for FalconSmartAccount {
-    type Signature = Bytes;
+    type Signature = HybridSignature;
     type Error = Error;
 
     /// Verify authorization using Falcon-512 post-quantum signature.
     ///
+    /// For hybrid accounts the Ed25519 signature is checked after the Falcon
+    /// one; an invalid Ed25519 signature aborts the call in the host. During
+    /// a key grace period, a Falcon signature from the replaced key is
+    /// accepted as well, but only for calls on other contracts. An accepted
+    /// authorization is appended to the audit log, if enabled.
+    ///
     /// # Arguments
     /// * `signature_payload` - The 32-byte hash of the transaction to verify
-    /// * `signature` - The Falcon signature (variable size, 42-700 bytes)
-    /// * `_auth_contexts` - Authorization contexts (unused)
+    /// * `signature` - The Falcon signature (variable size, 617-809 bytes) and,
+    ///   for hybrid accounts, the Ed25519 key and signature
+    /// * `auth_contexts` - Authorization contexts, used to build the
+    ///   [`AuthPayload`] when structured authorization is enabled
     ///
     /// # Returns
     /// * `Ok(())` if the signature is valid
     /// * `Err(Error::InvalidSignatureSize)` if signature size is invalid
-    /// * `Err(Error::VerificationFailed)` if signature verification fails
+    /// * `Err(Error::DisallowedFormat)` if the account is locked to another format
+    /// * `Err(Error::BadFormat)` if the signature header is not Falcon-512
+    /// * `Err(Error::SignatureDecodeFailed)` if the signature body does not decode
+    /// * `Err(Error::NormTooLarge)` if the signature decodes but does not verify
+    /// * `Err(Error::VerificationFailed)` if the stored public key does not decode
+    /// * `Err(Error::Ed25519KeyMismatch)` if the account is hybrid and the
+    ///   signature carries a different Ed25519 key
+    /// * `Err(Error::NonceReused)` if nonce tracking is enabled and the
+    ///   signature's nonce was already accepted
+    /// * `Err(Error::UnsupportedAuthContext)` if structured authorization is
+    ///   enabled and the authorization is not a single contract call
+    /// * `Err(Error::GraceKeyAdminCall)` if only the replaced key verifies
+    ///   and the authorization covers a call on the account itself
     #[allow(non_snake_case)]
     fn __check_auth(
         env: Env,
         signature_payload: Hash<32>,
-        signature: Bytes,
-        _auth_contexts: Vec<Context>,
+        signature: HybridSignature,
+        auth_contexts: Vec<Context>,
     ) -> Result<(), Error> {
+        let HybridSignature {
+            falcon: signature,
+            ed25519_pk,
+            ed25519_sig,
+        } = signature;
+
         // Get stored public key
         let pubkey: Bytes = env
             .storage()
@@ -114,30 +711,74 @@ impl CustomAccountInterface for FalconSmartAccount {
             return Err(Error::InvalidSignatureSize);
         }
 
+        // One host call per buffer rather than one per byte
         let mut pk_bytes = [0u8; FALCON_512_PUBKEY_SIZE];
-        for i in 0..FALCON_512_PUBKEY_SIZE {
-            pk_bytes[i] = pubkey.get(i as u32).unwrap();
+        pubkey.copy_into_slice(&mut pk_bytes);
+
+        if let Some(allowed) = Self::get_allowed_format(env.clone()) {
+            let format = SigFormat::from_header(signature.get(0).unwrap());
+            if format != Some(allowed.into()) {
+                return Err(Error::DisallowedFormat);
+            }
         }
 
         let sig_len_usize = sig_len as usize;
-        let mut sig_bytes = [0u8; 700];
-        for i in 0..sig_len_usize {
-            sig_bytes[i] = signature.get(i as u32).unwrap();
+        let mut sig_bytes = [0u8; FALCON_SIG_MAX_SIZE as usize];
+        signature.copy_into_slice(&mut sig_bytes[..sig_len_usize]);
+
+        let payload_array = payload_bytes(&signature_payload);
+        let mut structured = [0u8; MAX_AUTH_PAYLOAD_LEN];
+        let message: &[u8] = if Self::get_structured_auth(env.clone()) {
+            let xdr =
+                AuthPayload::from_contexts(&env, &signature_payload, &auth_contexts)?.to_xdr(&env);
+            let message = &mut structured[..xdr.len() as usize];
+            xdr.copy_into_slice(message);
+            message
+        } else {
+            &payload_array
+        };
+
+        let falcon_sig = &sig_bytes[..sig_len_usize];
+        if let Err(err) = FalconVerifier::verify_512_detailed(&pk_bytes, message, falcon_sig) {
+            // The current key's error is reported if the replaced key fails too
+            if !Self::previous_key_verifies(&env, message, falcon_sig) {
+                return Err(err.into());
+            }
+            if Self::calls_self(&env, &auth_contexts) {
+                return Err(Error::GraceKeyAdminCall);
+            }
         }
 
-        let payload_array = signature_payload.to_array();
-
-        let is_valid = FalconVerifier::verify_512(
-            &pk_bytes,
-            payload_array.as_slice(),
-            &sig_bytes[..sig_len_usize],
-        );
+        if let Some(required) = Self::get_ed25519_pubkey(env.clone()) {
+            if ed25519_pk != required {
+                return Err(Error::Ed25519KeyMismatch);
+            }
+            env.crypto().ed25519_verify(
+                &ed25519_pk,
+                &Bytes::from_slice(&env, message),
+                &ed25519_sig,
+            );
+        }
 
-        if is_valid {
-            Ok(())
-        } else {
-            Err(Error::VerificationFailed)
+        // Only signatures that verified are remembered
+        let window = Self::get_nonce_window(env.clone());
+        let capacity = Self::get_audit_log_capacity(env.clone());
+        if window == 0 && capacity == 0 {
+            return Ok(());
+        }
+        let fingerprint = Self::nonce_fingerprint(&env, &sig_bytes[1..1 + NONCE_LEN]);
+        if window > 0 {
+            Self::record_nonce(&env, fingerprint.clone(), window)?;
         }
+        if capacity > 0 {
+            let record = AuthRecord {
+                ledger: env.ledger().sequence(),
+                payload: BytesN::from_array(&env, &payload_array),
+                nonce_fingerprint: fingerprint,
+            };
+            Self::record_auth(&env, record, capacity);
+        }
+        Ok(())
     }
 }
 
@@ -154,7 +795,10 @@ mod test {
         pubkey_data[0] = 9; // Falcon-512 header
         let pubkey = Bytes::from_array(&env, &pubkey_data);
 
-        let contract_id = env.register(FalconSmartAccount, (&pubkey,));
+        let contract_id = env.register(
+            FalconSmartAccount,
+            (&pubkey, None::<AllowedFormat>, None::<BytesN<32>>),
+        );
         let client = FalconSmartAccountClient::new(&env, &contract_id);
 
         assert_eq!(client.get_pubkey(), pubkey);
@@ -167,7 +811,10 @@ mod test {
 
         let bad_pubkey = Bytes::from_array(&env, &[0u8; 100]);
 
-        let _contract_id = env.register(FalconSmartAccount, (&bad_pubkey,));
+        let _contract_id = env.register(
+            FalconSmartAccount,
+            (&bad_pubkey, None::<AllowedFormat>, None::<BytesN<32>>),
+        );
     }
 
     #[test]
@@ -178,7 +825,10 @@ mod test {
         let pubkey_bytes = hex::decode(pubkey_hex).unwrap();
         let pubkey = Bytes::from_slice(&env, &pubkey_bytes);
 
-        let contract_id = env.register(FalconSmartAccount, (&pubkey,));
+        let contract_id = env.register(
+            FalconSmartAccount,
+            (&pubkey, None::<AllowedFormat>, None::<BytesN<32>>),
+        );
         let client = FalconSmartAccountClient::new(&env, &contract_id);
 
         let stored_pubkey = client.get_pubkey();
@@ -212,4 +862,27 @@ mod test {
             "Wrong message should fail verification"
         );
     }
+
+    #[test]
+    fn test_auth_payload_fits_buffer() {
+        use soroban_sdk::{
+            auth::ContractContext, testutils::Address as _, xdr::ToXdr, IntoVal, String,
+        };
+
+        let env = Env::default();
+        let fn_name = Symbol::new(&env, "abcdefghijklmnopqrstuvwxyz_01234");
+        let call = Context::Contract(ContractContext {
+            contract: Address::generate(&env),
+            fn_name,
+            args: (
+                Bytes::from_array(&env, &[0; 1024]),
+                String::from_str(&env, "x"),
+            )
+                .into_val(&env),
+        });
+        let payload = env.crypto().sha256(&Bytes::new(&env));
+        let auth = AuthPayload::from_contexts(&env, &payload, &Vec::from_array(&env, [call]));
+        // Longest function name; the arguments only contribute their hash
+        assert!(auth.unwrap().to_xdr(&env).len() as usize <= MAX_AUTH_PAYLOAD_LEN);
+    }
 }
@@ -4,8 +4,11 @@
 
 #![cfg(feature = "testutils")]
 
-use soroban_sdk::{Bytes, Env};
-use soroban_falcon_smart_account::{FalconSmartAccount, FalconSmartAccountClient, FalconVerifier};
+use soroban_sdk::{Bytes, BytesN, Env, IntoVal, Vec};
+use soroban_falcon_smart_account::{
+    AllowedFormat, Error, FalconSignature, FalconSmartAccount, FalconSmartAccountClient,
+    FalconVerifier, HybridSignature, VerifyCost, FALCON_512_N, FALCON_512_PUBKEY_SIZE,
+};
 
 // Test vectors from C FFI bindings
 const TEST_PUBKEY_HEX: &str = "0902c671f64d92df6c446a63f5061d73fab61be667e74db66752251102a105922a6fe56a7b3a48196bafc22de2275600dfd8b4149842bf0a5f3b7df4e1f6608f5394aae63e918a7bc492426a62e64d1873fb72c020a3c6be3a9295bc29aaf1c351267c6b00ffc2aa003f64fa9133628b2996b4327b7ee6366b9acb4067e30715fcf68273e04880a453eb468eff0a8d563af3235c6cae44984e8ed8911a34222ed6ec3274f8c491893a9f74ab6b1d67daa0083eb666c098acd4745aa208362a8e14b906437c2cc1ca044a5b903724c9066cd662a622cc38165a4d91322e193c48d12b5e20977bdb4816d6c1aa6a8a4118705029de6fd8723d3ca408ea0c296ceba31e903fbbc9dd60b0c1ca74a1a995d3cf449518815ab29f227d257491f758630484e3a6e36c83008069e538e3e65272f0a5440d8e6998e516e1a5390045b986c24975567c8ce8eae5b29916797516c04f69085a0112e9295b8d96e878410e12507ff9ba012c1f352a84be660a467a95321c8947b07440d58ac215b9cc2ee3d2e5c5af1e9044aed41e94305390c5110c27e5ee3a620c898f90671911e58f75c1085551618b5b4443e3e3527955357007d8696bb59e0d625f248f513de19916a093b43ef00b8d8211a3801874c9687b792e9588a59622b748ae5adc1ff98d0040506cd7c720e64123631bdd70628fa2534bf1094d92b82f2d5fb586d715dee362ac6cd33268a3249669c853fde1643222968b072d07be36764962d3c6a0550038bce88219585357616fb63e701f923ae986247850c7c5ad74bd3e8cf342623cabb8e467fe55a1103975f9af1235995ca30bfe8ea9af0619a2995a283e5cd49bae9a9737201d152d253f50e526d55c59ae8675eeca051bbf44f4c9e530cdfca2c0b192cf8f779a85de921e06a48b71ac1170af6c50c16d3328149c5a682ceb18a01f1de6207319d54a5f205ff82d8ae5536a924721e68c83b82d47dbc0854db1d392e055e2702e8a9401e200616d43aa8c25075712b1f0274f097cf51423685a051d35afb9a9d3217e365e95d95bff5a31e8320bc423bc5052d1ec04739005090a8e6f95b53014129aa30b937cf157c6d0bfa77263e3a2d435954e30f790a4ca062e7d17aa2d52a5a4aec83108c12e24fcf97a9119554eadf26b5447b1d0d7e0484b58122a1b68aa15bd3e5db8927b4240785966f5cba8784b752d723a86c13c005ec57fe22bb18afd43d1093d232ac8b09f920d2a8cbec54e56f93edd6dd235a1ef";
@@ -13,6 +16,12 @@ const TEST_PUBKEY_HEX: &str = "0902c671f64d92df6c446a63f5061d73fab61be667e74db66
 // Padded signature for "Hello, Falcon!" (666 bytes)
 const TEST_SIGNATURE_HEX: &str = "399e11dbc7c5328dbdd260d989a2e58c18e698b7ee2c94235312fabbae38c24058d1dd43fe030b3f2583c4e2dcc445a1c76624aa2e2a0527fd6a6398a521b5c6d6391c9caf0729893d087fd672d38c0232e9ff98e313bebbe069e93a371de31f7e6c2905544a210fa3363aa23ce2418803d6b1fee2a275f3e8f2d6585ffa30ac2bf639345d78b1da59a2c1187a3f79190b3b788537993873fb9755bc8dd7723fbbefeaa5fd89a25298609f4f7ec5988292c4a976f833d6f312eaea792e53d9b49b31bd5bd20ee4bef5a887359d5c71e86e4d14c56848d23d65f2dd65775d2a0f47549d6289b1ab4897142aa12d7424ac17c4ce1ba84ea6094f448e0e57c53ea64521596220cdef215ad311b6d57723de37438ebae27d38fae24e81eefc98a88e9ea39d5418a53b9fd4912624ae4f81e219759ecb1759b6bee72de06285432f3c7c310c0b867b5afdff29658f45610854fbdecb1b04524cc0b6d16edccb37dace29db3becd6779ded4caa6f5a277b852d11ad2a46b8d731c6ef694c39bb3772532bc0f99757ab4ce76ae25d646c7dd8eecdee84b3b3040797975ff39782a11b8eb65507fe415c5a39b6862949f6eeb1c53c996f14be765154c9b239230990621e52513b5da72bcfc6a48433cefcb843a1127a2335d559161f9db54eb798bb15c65d4ad073f0d9f52cc6cba122ed824726758226cbe41d340bd495c131f891eecb1837b9df7e66e8695355fd5853e736d4bedc224063f08ac33b6e9bd5e21ad8ec52a2b14e225299399a26287f28c4d8a3567f3a685fa5dfa2f94ac8476b38793b7d4fd711bafb5ebeac3f65e70466a51455cba3946a6688e6cb14ef1386143efc7638f655910f751bd4ecc5168a142495937fb5afb5e84698a35d829ef83a387336c622f1b8b3bab64d9eca1a0000000000000000000000000000";
 
+/// Asserts that a call verifying plain slices charged nothing: the native
+/// budget meters host calls only, and embedded verification makes none.
+fn assert_guest_only(cost: &VerifyCost) {
+    assert_eq!((cost.cpu, cost.mem), (0, 0), "Unexpected host calls");
+}
+
 #[test]
 fn benchmark_smart_account_deployment() {
     let env = Env::default();
@@ -25,7 +34,10 @@ fn benchmark_smart_account_deployment() {
     env.cost_estimate().budget().reset_default();
 
     // Deploy contract with constructor
-    let contract_id = env.register(FalconSmartAccount, (&pubkey,));
+    let contract_id = env.register(
+        FalconSmartAccount,
+        (&pubkey, None::<AllowedFormat>, None::<BytesN<32>>),
+    );
     let _client = FalconSmartAccountClient::new(&env, &contract_id);
 
     // Print budget consumption
@@ -51,32 +63,14 @@ fn benchmark_embedded_verification() {
     let pubkey_bytes = hex::decode(TEST_PUBKEY_HEX).expect("Invalid pubkey hex");
     let sig_bytes = hex::decode(TEST_SIGNATURE_HEX).expect("Invalid signature hex");
 
-    // Reset budget tracking
-    env.cost_estimate().budget().reset_default();
+    let cost = FalconVerifier::measure(&env, || {
+        FalconVerifier::verify_512(&pubkey_bytes, b"Hello, Falcon!", &sig_bytes)
+    });
+    assert!(cost.result, "Verification should succeed");
+    assert_guest_only(&cost);
 
-    // Run verification directly (simulating __check_auth)
-    let result = FalconVerifier::verify_512(&pubkey_bytes, b"Hello, Falcon!", &sig_bytes);
-    assert!(result, "Verification should succeed");
-
-    // Print budget consumption
-    let budget = env.cost_estimate().budget();
     println!("\n=== Falcon-512 Embedded Verification Benchmark ===");
-    println!("Message: \"Hello, Falcon!\" (14 bytes)");
-    println!("Signature: {} bytes (padded format)", sig_bytes.len());
-    println!("Public key: {} bytes", pubkey_bytes.len());
-    println!();
-
-    let cpu_insns = budget.cpu_instruction_cost();
-    println!("CPU Instructions: {}", cpu_insns);
-
-    let mem_bytes = budget.memory_bytes_cost();
-    println!("Memory Bytes: {}", mem_bytes);
-
-    println!();
-    println!("=== Budget Breakdown ===");
-    budget.print();
-
-    println!("\n=== End Benchmark ===\n");
+    println!("{:?}", cost);
 }
 
 #[test]
@@ -92,26 +86,14 @@ fn benchmark_32byte_message() {
     let sig_bytes = hex::decode(sig_hex).expect("Invalid signature hex");
     let message = b"Test message for cross-implementation verification";
 
-    // Reset budget tracking
-    env.cost_estimate().budget().reset_default();
+    let cost = FalconVerifier::measure(&env, || {
+        FalconVerifier::verify_512(&pubkey_bytes, message, &sig_bytes)
+    });
+    assert!(cost.result, "Verification should succeed");
+    assert_guest_only(&cost);
 
-    // Run verification
-    let result = FalconVerifier::verify_512(&pubkey_bytes, message, &sig_bytes);
-    assert!(result, "Verification should succeed");
-
-    // Print budget consumption
-    let budget = env.cost_estimate().budget();
     println!("\n=== Falcon-512 Verification (50-byte Message) ===");
-    println!("Message: {} bytes", message.len());
-    println!("Signature: {} bytes (padded format)", sig_bytes.len());
-
-    let cpu_insns = budget.cpu_instruction_cost();
-    println!("CPU Instructions: {}", cpu_insns);
-
-    let mem_bytes = budget.memory_bytes_cost();
-    println!("Memory Bytes: {}", mem_bytes);
-
-    println!("=== End Benchmark ===\n");
+    println!("{:?}", cost);
 }
 
 #[test]
@@ -122,25 +104,191 @@ fn benchmark_failed_verification() {
     let pubkey_bytes = hex::decode(TEST_PUBKEY_HEX).expect("Invalid pubkey hex");
     let sig_bytes = hex::decode(TEST_SIGNATURE_HEX).expect("Invalid signature hex");
 
-    // Reset budget tracking
-    env.cost_estimate().budget().reset_default();
-
-    // Run verification with wrong message (should fail)
-    let result = FalconVerifier::verify_512(&pubkey_bytes, b"Wrong message!", &sig_bytes);
-    assert!(!result, "Verification should fail");
+    let cost = FalconVerifier::measure(&env, || {
+        FalconVerifier::verify_512(&pubkey_bytes, b"Wrong message!", &sig_bytes)
+    });
+    assert!(!cost.result, "Verification should fail");
+    assert_guest_only(&cost);
 
-    // Print budget consumption
-    let budget = env.cost_estimate().budget();
     println!("\n=== Falcon-512 Failed Verification ===");
-    println!("Message: \"Wrong message!\" (mismatch)");
+    println!("{:?}", cost);
+}
+
+#[test]
+fn benchmark_check_auth() {
+    let env = Env::default();
+
+    // Signature over the payload 0x00..0x1f (padded format, 666 bytes)
+    let pubkey_bytes = hex::decode(include_str!("fixtures/auth_pubkey.hex").trim()).unwrap();
+    let sig_bytes = hex::decode(include_str!("fixtures/auth_signature.hex").trim()).unwrap();
+    let pubkey = Bytes::from_slice(&env, &pubkey_bytes);
+    let account = env.register(
+        FalconSmartAccount,
+        (&pubkey, None::<AllowedFormat>, None::<BytesN<32>>),
+    );
+
+    let mut payload = [0u8; 32];
+    for (i, b) in payload.iter_mut().enumerate() {
+        *b = i as u8;
+    }
+    let payload = BytesN::from_array(&env, &payload);
+    let signature = HybridSignature {
+        falcon: Bytes::from_slice(&env, &sig_bytes),
+        ed25519_pk: BytesN::from_array(&env, &[0; 32]),
+        ed25519_sig: BytesN::from_array(&env, &[0; 64]),
+    };
+
+    println!("\n=== Smart Account __check_auth ===");
     println!("Signature: {} bytes (padded format)", sig_bytes.len());
+    // Two authorizations in the same environment, as in a multi-auth transaction
+    let costs: std::vec::Vec<VerifyCost> = (0..2)
+        .map(|_| {
+            FalconVerifier::measure(&env, || {
+                env.try_invoke_contract_check_auth::<Error>(
+                    &account,
+                    &payload,
+                    signature.clone().into_val(&env),
+                    &Vec::new(&env),
+                )
+                .is_ok()
+            })
+        })
+        .collect();
+    for (round, cost) in costs.iter().enumerate() {
+        assert!(cost.result, "Authorization should succeed");
+        println!("Auth {}: {:?}", round + 1, cost);
+    }
+    // Nothing is cached between authorizations
+    assert_eq!(costs[0], costs[1]);
+    println!("=== End Benchmark ===\n");
+}
 
-    let cpu_insns = budget.cpu_instruction_cost();
-    println!("CPU Instructions: {}", cpu_insns);
+#[test]
+fn benchmark_prepared_key_reuse() {
+    use soroban_sdk::symbol_short;
+    use std::time::{Duration, Instant};
 
-    let mem_bytes = budget.memory_bytes_cost();
-    println!("Memory Bytes: {}", mem_bytes);
+    const AUTHS: u32 = 100;
+    let env = Env::default();
 
-    println!("(Note: Failed verification uses similar resources as successful)");
+    // Signature over the payload 0x00..0x1f (padded format, 666 bytes)
+    let pubkey_bytes = hex::decode(include_str!("fixtures/auth_pubkey.hex").trim()).unwrap();
+    let sig_bytes = hex::decode(include_str!("fixtures/auth_signature.hex").trim()).unwrap();
+    let pubkey = Bytes::from_slice(&env, &pubkey_bytes);
+    let account = env.register(
+        FalconSmartAccount,
+        (&pubkey, None::<AllowedFormat>, None::<BytesN<32>>),
+    );
+
+    let mut payload_array = [0u8; 32];
+    for (i, b) in payload_array.iter_mut().enumerate() {
+        *b = i as u8;
+    }
+    let payload = BytesN::from_array(&env, &payload_array);
+    let signature = HybridSignature {
+        falcon: Bytes::from_slice(&env, &sig_bytes),
+        ed25519_pk: BytesN::from_array(&env, &[0; 32]),
+        ed25519_sig: BytesN::from_array(&env, &[0; 64]),
+    };
+
+    // Current contract: every authorization decodes and transforms the stored key.
+    // Each invocation gets a fresh budget, so the CPU is summed per call.
+    let mut check_auth_cpu = 0;
+    let start = Instant::now();
+    for _ in 0..AUTHS {
+        env.cost_estimate().budget().reset_default();
+        let result = env.try_invoke_contract_check_auth::<Error>(
+            &account,
+            &payload,
+            signature.clone().into_val(&env),
+            &Vec::new(&env),
+        );
+        assert_eq!(result, Ok(()));
+        check_auth_cpu += env.cost_estimate().budget().cpu_instruction_cost();
+    }
+    let check_auth_time = start.elapsed();
+
+    // Proposed stored form: h already in the NTT domain, 2 bytes per coefficient
+    let mut h = [0u16; FALCON_512_N];
+    assert!(FalconVerifier::decode_pubkey(&pubkey_bytes, &mut h));
+    FalconVerifier::prepare_pubkey(&mut h);
+    let stored: std::vec::Vec<u8> = h.iter().flat_map(|c| c.to_be_bytes()).collect();
+    let prepared_slot = symbol_short!("F_PREPKEY");
+    env.as_contract(&account, || {
+        env.storage()
+            .instance()
+            .set(&prepared_slot, &Bytes::from_slice(&env, &stored));
+    });
+
+    // The embedded verification of __check_auth, reading the key from storage
+    let decode_each_time = || {
+        let pubkey = FalconSmartAccount::get_pubkey(env.clone());
+        let mut pk = [0u8; FALCON_512_PUBKEY_SIZE];
+        pubkey.copy_into_slice(&mut pk);
+        FalconVerifier::verify_512(&pk, &payload_array, &sig_bytes)
+    };
+    let prepared = || {
+        let stored: Bytes = env.storage().instance().get(&prepared_slot).unwrap();
+        let mut raw = [0u8; 2 * FALCON_512_N];
+        stored.copy_into_slice(&mut raw);
+        let mut h = [0u16; FALCON_512_N];
+        for (c, pair) in h.iter_mut().zip(raw.chunks_exact(2)) {
+            *c = u16::from_be_bytes([pair[0], pair[1]]);
+        }
+        let sig = FalconSignature::from_bytes(&sig_bytes).unwrap();
+        let mut c0 = [0u16; FALCON_512_N];
+        FalconVerifier::hash_to_point(sig.nonce(), &payload_array, &mut c0);
+        FalconVerifier::verify_raw_512(&c0, sig.s2(), &h)
+    };
+    // Native wall-clock time; the Soroban budget does not meter guest code
+    let measure = |path: &dyn Fn() -> bool| -> (u64, Duration) {
+        env.cost_estimate().budget().reset_default();
+        let start = Instant::now();
+        env.as_contract(&account, || {
+            for _ in 0..AUTHS {
+                assert!(path());
+            }
+        });
+        (
+            env.cost_estimate().budget().cpu_instruction_cost(),
+            start.elapsed(),
+        )
+    };
+    let (decode_cpu, decode_time) = measure(&decode_each_time);
+    let (prepared_cpu, prepared_time) = measure(&prepared);
+
+    let saved = |before: f64, after: f64| 100.0 * (before - after) / before;
+    println!(
+        "\n=== Prepared Public Key Reuse ({} authorizations) ===",
+        AUTHS
+    );
+    println!(
+        "__check_auth:       CPU {} total, {} per call; {:?} per call",
+        check_auth_cpu,
+        check_auth_cpu / AUTHS as u64,
+        check_auth_time / AUTHS
+    );
+    println!(
+        "Decode every time:  CPU {} total, {} per call; {:?} per call",
+        decode_cpu,
+        decode_cpu / AUTHS as u64,
+        decode_time / AUTHS
+    );
+    println!(
+        "Prepared key:       CPU {} total, {} per call; {:?} per call",
+        prepared_cpu,
+        prepared_cpu / AUTHS as u64,
+        prepared_time / AUTHS
+    );
+    println!(
+        "Improvement:        host CPU {:.1}%, guest time {:.1}%",
+        saved(decode_cpu as f64, prepared_cpu as f64),
+        saved(decode_time.as_secs_f64(), prepared_time.as_secs_f64())
+    );
+    println!(
+        "(Stored key: {} bytes prepared vs {} bytes packed)",
+        stored.len(),
+        FALCON_512_PUBKEY_SIZE
+    );
     println!("=== End Benchmark ===\n");
 }
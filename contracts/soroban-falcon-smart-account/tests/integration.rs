@@ -2,13 +2,74 @@
 
 #![cfg(feature = "testutils")]
 
-use soroban_sdk::{Bytes, Env};
+use soroban_sdk::testutils::{storage::Persistent, Ledger};
+use soroban_sdk::{Address, Bytes, BytesN, Env, IntoVal, InvokeError, Symbol, Vec};
 
-use soroban_falcon_smart_account::{FalconSmartAccount, FalconSmartAccountClient};
+use soroban_falcon_smart_account::{
+    payload_bytes, AllowedFormat, AuthRecord, Error, FalconSignature, FalconSmartAccount,
+    FalconSmartAccountClient, FalconVerifier, HybridSignature, AUDIT_TTL_LEDGERS, MAX_AUDIT_LOG,
+    MAX_KEY_GRACE_LEDGERS, MAX_NONCE_WINDOW, NONCE_TTL_LEDGERS,
+};
 
 const TEST_PUBKEY_HEX: &str = include_str!("fixtures/test_pubkey.hex");
 const TEST_SIGNATURE_HEX: &str = include_str!("fixtures/test_signature.hex");
 
+// Keypair generated with falcon-wasm from seed 0x00..0x2f; the signature is
+// over the 32-byte payload 0x00..0x1f (padded format, 666 bytes).
+const AUTH_PUBKEY_HEX: &str = include_str!("fixtures/auth_pubkey.hex");
+const AUTH_SIGNATURE_HEX: &str = include_str!("fixtures/auth_signature.hex");
+// Ed25519 key from seed 0x42 repeated, signing the same payload
+const AUTH_ED25519_PUBKEY_HEX: &str = include_str!("fixtures/auth_ed25519_pubkey.hex");
+const AUTH_ED25519_SIGNATURE_HEX: &str = include_str!("fixtures/auth_ed25519_signature.hex");
+// Same keypair, signing the AuthPayload XDR for a call of nonce_win(16) on
+// the account itself with the payload 0x00..0x1f (padded format)
+const STRUCTURED_AUTH_SIGNATURE_HEX: &str = include_str!("fixtures/structured_auth_signature.hex");
+
+fn auth_payload(env: &Env) -> BytesN<32> {
+    let mut payload = [0u8; 32];
+    for (i, b) in payload.iter_mut().enumerate() {
+        *b = i as u8;
+    }
+    BytesN::from_array(env, &payload)
+}
+
+fn deploy_auth_account(env: &Env) -> Address {
+    let pubkey_bytes = hex::decode(AUTH_PUBKEY_HEX.trim()).expect("Invalid pubkey hex");
+    let pubkey = Bytes::from_slice(env, &pubkey_bytes);
+    env.register(
+        FalconSmartAccount,
+        (&pubkey, None::<AllowedFormat>, None::<BytesN<32>>),
+    )
+}
+
+/// Falcon signature with zeroed Ed25519 fields, for non-hybrid accounts.
+fn falcon_only(env: &Env, sig: &[u8]) -> HybridSignature {
+    HybridSignature {
+        falcon: Bytes::from_slice(env, sig),
+        ed25519_pk: BytesN::from_array(env, &[0; 32]),
+        ed25519_sig: BytesN::from_array(env, &[0; 64]),
+    }
+}
+
+fn try_check_auth(
+    env: &Env,
+    account: &Address,
+    payload: &BytesN<32>,
+    signature: &HybridSignature,
+) -> Result<(), Result<Error, InvokeError>> {
+    env.try_invoke_contract_check_auth::<Error>(
+        account,
+        payload,
+        signature.into_val(env),
+        &Vec::new(env),
+    )
+}
+
+fn check_auth(env: &Env, account: &Address, payload: &BytesN<32>, sig: &[u8]) -> Result<(), Error> {
+    try_check_auth(env, account, payload, &falcon_only(env, sig))
+        .map_err(|e| e.expect("contract should return a typed error"))
+}
+
 #[test]
 fn test_smart_account_constructor() {
     let env = Env::default();
@@ -18,7 +79,10 @@ fn test_smart_account_constructor() {
     let pubkey = Bytes::from_slice(&env, &pubkey_bytes);
 
     // Deploy with constructor
-    let smart_account_id = env.register(FalconSmartAccount, (&pubkey,));
+    let smart_account_id = env.register(
+        FalconSmartAccount,
+        (&pubkey, None::<AllowedFormat>, None::<BytesN<32>>),
+    );
     let client = FalconSmartAccountClient::new(&env, &smart_account_id);
 
     // Verify stored value
@@ -33,7 +97,10 @@ fn test_invalid_pubkey_size_on_construction() {
     let bad_pubkey = Bytes::from_slice(&env, &[0u8; 100]);
 
     // This should panic during construction
-    let _smart_account_id = env.register(FalconSmartAccount, (&bad_pubkey,));
+    let _smart_account_id = env.register(
+        FalconSmartAccount,
+        (&bad_pubkey, None::<AllowedFormat>, None::<BytesN<32>>),
+    );
 }
 
 #[test]
@@ -51,7 +118,10 @@ fn test_embedded_verification() {
     let pubkey = Bytes::from_slice(&env, &pubkey_bytes);
 
     // Deploy with constructor
-    let smart_account_id = env.register(FalconSmartAccount, (&pubkey,));
+    let smart_account_id = env.register(
+        FalconSmartAccount,
+        (&pubkey, None::<AllowedFormat>, None::<BytesN<32>>),
+    );
     let client = FalconSmartAccountClient::new(&env, &smart_account_id);
 
     // Verify pubkey is stored
@@ -59,8 +129,6 @@ fn test_embedded_verification() {
     assert_eq!(stored_pubkey.len(), 897);
 
     // Verify directly using FalconVerifier
-    use soroban_falcon_smart_account::FalconVerifier;
-
     let mut pk_bytes = [0u8; 897];
     for i in 0..897 {
         pk_bytes[i] = stored_pubkey.get(i as u32).unwrap();
@@ -69,3 +137,810 @@ fn test_embedded_verification() {
     let result = FalconVerifier::verify_512(&pk_bytes, b"Hello, Falcon!", &sig_bytes);
     assert!(result, "Falcon verification should succeed");
 }
+
+#[test]
+fn test_check_auth_accepts_valid_signature() {
+    let env = Env::default();
+    let account = deploy_auth_account(&env);
+    let sig = hex::decode(AUTH_SIGNATURE_HEX.trim()).expect("Invalid signature hex");
+
+    assert_eq!(
+        check_auth(&env, &account, &auth_payload(&env), &sig),
+        Ok(())
+    );
+}
+
+#[test]
+fn test_payload_bytes() {
+    let env = Env::default();
+    let hash = env.crypto().sha256(&Bytes::from_slice(&env, b"payload"));
+    assert_eq!(payload_bytes(&hash), hash.to_array());
+
+    // The off-chain fixtures sign exactly the 32 payload bytes, with both schemes
+    let payload = auth_payload(&env);
+    let pubkey = hex::decode(AUTH_PUBKEY_HEX.trim()).unwrap();
+    let sig = hex::decode(AUTH_SIGNATURE_HEX.trim()).unwrap();
+    assert!(FalconVerifier::verify_512(
+        &pubkey,
+        &payload.to_array(),
+        &sig
+    ));
+    let hybrid = auth_hybrid_signature(&env);
+    env.crypto().ed25519_verify(
+        &hybrid.ed25519_pk,
+        &payload.clone().into(),
+        &hybrid.ed25519_sig,
+    );
+
+    let account = deploy_hybrid_account(&env);
+    assert_eq!(try_check_auth(&env, &account, &payload, &hybrid), Ok(()));
+}
+
+#[test]
+fn test_check_auth_rejection_reasons() {
+    let env = Env::default();
+    let account = deploy_auth_account(&env);
+    let payload = auth_payload(&env);
+    let sig = hex::decode(AUTH_SIGNATURE_HEX.trim()).expect("Invalid signature hex");
+
+    // Decodes fine but signs a different payload
+    let other_payload = BytesN::from_array(&env, &[0xAA; 32]);
+    assert_eq!(
+        check_auth(&env, &account, &other_payload, &sig),
+        Err(Error::NormTooLarge)
+    );
+
+    // Unknown format nibble in the header
+    let mut bad_header = sig.clone();
+    bad_header[0] = 0x49;
+    assert_eq!(
+        check_auth(&env, &account, &payload, &bad_header),
+        Err(Error::BadFormat)
+    );
+
+    // Non-zero byte in the zero padding
+    let mut bad_body = sig.clone();
+    *bad_body.last_mut().unwrap() = 0xFF;
+    assert_eq!(
+        check_auth(&env, &account, &payload, &bad_body),
+        Err(Error::SignatureDecodeFailed)
+    );
+
+    assert_eq!(
+        check_auth(&env, &account, &payload, &sig[..41]),
+        Err(Error::InvalidSignatureSize)
+    );
+}
+
+#[test]
+fn test_rotate_key_bumps_version() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let key_a = Bytes::from_slice(&env, &hex::decode(TEST_PUBKEY_HEX.trim()).unwrap());
+    let key_b = Bytes::from_slice(&env, &hex::decode(AUTH_PUBKEY_HEX.trim()).unwrap());
+
+    let account = env.register(
+        FalconSmartAccount,
+        (&key_a, None::<AllowedFormat>, None::<BytesN<32>>),
+    );
+    let client = FalconSmartAccountClient::new(&env, &account);
+    assert_eq!(client.get_key_version(), 0);
+
+    client.rotate_key(&key_b);
+    assert_eq!(client.get_pubkey(), key_b);
+    assert_eq!(client.get_key_version(), 1);
+
+    client.rotate_key(&key_a);
+    assert_eq!(client.get_pubkey(), key_a);
+    assert_eq!(client.get_key_version(), 2);
+
+    client.rotate_key(&key_b);
+    assert_eq!(client.get_pubkey(), key_b);
+    assert_eq!(client.get_key_version(), 3);
+}
+
+#[test]
+fn test_pubkey_fingerprint() {
+    use sha3::{Digest, Sha3_256};
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let key_a_bytes = hex::decode(TEST_PUBKEY_HEX.trim()).unwrap();
+    let key_b_bytes = hex::decode(AUTH_PUBKEY_HEX.trim()).unwrap();
+    let key_a = Bytes::from_slice(&env, &key_a_bytes);
+    let key_b = Bytes::from_slice(&env, &key_b_bytes);
+    let fingerprint = |key: &[u8]| BytesN::from_array(&env, &Sha3_256::digest(key).into());
+
+    let account = env.register(
+        FalconSmartAccount,
+        (&key_a, None::<AllowedFormat>, None::<BytesN<32>>),
+    );
+    let client = FalconSmartAccountClient::new(&env, &account);
+    assert_eq!(client.pubkey_fingerprint(), fingerprint(&key_a_bytes));
+    assert_eq!(client.pubkey_fingerprint(), client.pubkey_fingerprint());
+
+    client.rotate_key(&key_b);
+    assert_eq!(client.pubkey_fingerprint(), fingerprint(&key_b_bytes));
+    assert_ne!(client.pubkey_fingerprint(), fingerprint(&key_a_bytes));
+
+    client.rotate_key(&key_a);
+    assert_eq!(client.pubkey_fingerprint(), fingerprint(&key_a_bytes));
+}
+
+#[test]
+fn test_rotate_key_rejections_keep_version() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let key_bytes = hex::decode(TEST_PUBKEY_HEX.trim()).unwrap();
+    let key = Bytes::from_slice(&env, &key_bytes);
+    let account = env.register(
+        FalconSmartAccount,
+        (&key, None::<AllowedFormat>, None::<BytesN<32>>),
+    );
+    let client = FalconSmartAccountClient::new(&env, &account);
+
+    assert_eq!(client.try_rotate_key(&key), Err(Ok(Error::KeyUnchanged)));
+
+    let short = Bytes::from_slice(&env, &key_bytes[..100]);
+    assert_eq!(
+        client.try_rotate_key(&short),
+        Err(Ok(Error::InvalidPublicKeySize))
+    );
+
+    // Coefficient value 16383 is out of range for q = 12289
+    let mut undecodable = key_bytes.clone();
+    undecodable[1] = 0xFF;
+    undecodable[2] = 0xFF;
+    let undecodable = Bytes::from_slice(&env, &undecodable);
+    assert_eq!(
+        client.try_rotate_key(&undecodable),
+        Err(Ok(Error::InvalidPublicKey))
+    );
+
+    assert_eq!(client.get_pubkey(), key);
+    assert_eq!(client.get_key_version(), 0);
+}
+
+#[test]
+fn test_rotate_key_requires_account_auth() {
+    let env = Env::default();
+
+    let key_a = Bytes::from_slice(&env, &hex::decode(TEST_PUBKEY_HEX.trim()).unwrap());
+    let key_b = Bytes::from_slice(&env, &hex::decode(AUTH_PUBKEY_HEX.trim()).unwrap());
+    let account = env.register(
+        FalconSmartAccount,
+        (&key_a, None::<AllowedFormat>, None::<BytesN<32>>),
+    );
+    let client = FalconSmartAccountClient::new(&env, &account);
+
+    assert!(client.try_rotate_key(&key_b).is_err());
+    assert_eq!(client.get_pubkey(), key_a);
+    assert_eq!(client.get_key_version(), 0);
+}
+
+#[test]
+fn test_replaced_key_accepted_during_grace_period() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let account = deploy_auth_account(&env);
+    let client = FalconSmartAccountClient::new(&env, &account);
+    let payload = auth_payload(&env);
+    let old_sig = hex::decode(AUTH_SIGNATURE_HEX.trim()).unwrap();
+    let new_key = Bytes::from_slice(&env, &hex::decode(TEST_PUBKEY_HEX.trim()).unwrap());
+
+    client.set_key_grace_period(&10);
+    env.ledger().set_sequence_number(100);
+    client.rotate_key(&new_key);
+    assert_eq!(check_auth(&env, &account, &payload, &old_sig), Ok(()));
+    env.ledger().set_sequence_number(109);
+    assert_eq!(check_auth(&env, &account, &payload, &old_sig), Ok(()));
+    // The window has expired; the error is the new key's
+    env.ledger().set_sequence_number(110);
+    assert_eq!(
+        check_auth(&env, &account, &payload, &old_sig),
+        Err(Error::NormTooLarge)
+    );
+
+    // Without a grace period the replaced key is rejected at once
+    let account = deploy_auth_account(&env);
+    let client = FalconSmartAccountClient::new(&env, &account);
+    client.rotate_key(&new_key);
+    assert_eq!(
+        check_auth(&env, &account, &payload, &old_sig),
+        Err(Error::NormTooLarge)
+    );
+
+    // Setting the period to 0 ends a running window
+    let account = deploy_auth_account(&env);
+    let client = FalconSmartAccountClient::new(&env, &account);
+    client.set_key_grace_period(&10);
+    client.rotate_key(&new_key);
+    assert_eq!(check_auth(&env, &account, &payload, &old_sig), Ok(()));
+    client.set_key_grace_period(&0);
+    client.set_key_grace_period(&10);
+    assert_eq!(
+        check_auth(&env, &account, &payload, &old_sig),
+        Err(Error::NormTooLarge)
+    );
+}
+
+#[test]
+fn test_replaced_key_cannot_call_account() {
+    use soroban_sdk::auth::{Context, ContractContext};
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::{Symbol, Val};
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let account = deploy_auth_account(&env);
+    let client = FalconSmartAccountClient::new(&env, &account);
+    let payload = auth_payload(&env);
+    let old_sig = falcon_only(&env, &hex::decode(AUTH_SIGNATURE_HEX.trim()).unwrap());
+    let new_key = Bytes::from_slice(&env, &hex::decode(TEST_PUBKEY_HEX.trim()).unwrap());
+    let attacker_key = Bytes::from_slice(&env, &hex::decode(AUTH_PUBKEY_HEX.trim()).unwrap());
+
+    client.set_key_grace_period(&10);
+    client.rotate_key(&new_key);
+
+    let call = |contract: &Address, fn_name: &str, args: Vec<Val>| {
+        Context::Contract(ContractContext {
+            contract: contract.clone(),
+            fn_name: Symbol::new(&env, fn_name),
+            args,
+        })
+    };
+    let check = |contexts: &[Context]| {
+        env.try_invoke_contract_check_auth::<Error>(
+            &account,
+            &payload,
+            old_sig.into_val(&env),
+            &Vec::from_slice(&env, contexts),
+        )
+    };
+
+    // Calls on other contracts still go through with the replaced key
+    let token = Address::generate(&env);
+    let transfer = call(&token, "transfer", (7u32,).into_val(&env));
+    assert_eq!(check(std::slice::from_ref(&transfer)), Ok(()));
+
+    // Admin calls on the account need the current key
+    for admin in [
+        call(
+            &account,
+            "rotate_key",
+            (attacker_key.clone(),).into_val(&env),
+        ),
+        call(
+            &account,
+            "set_key_grace_period",
+            (MAX_KEY_GRACE_LEDGERS,).into_val(&env),
+        ),
+        call(&account, "set_nonce_window", (0u32,).into_val(&env)),
+        call(&account, "set_structured_auth", (false,).into_val(&env)),
+    ] {
+        assert_eq!(
+            check(std::slice::from_ref(&admin)),
+            Err(Ok(Error::GraceKeyAdminCall))
+        );
+        assert_eq!(
+            check(&[transfer.clone(), admin]),
+            Err(Ok(Error::GraceKeyAdminCall))
+        );
+    }
+    assert_eq!(client.get_pubkey(), new_key);
+}
+
+#[test]
+fn test_set_key_grace_period_limits() {
+    let env = Env::default();
+    let account = deploy_auth_account(&env);
+    let client = FalconSmartAccountClient::new(&env, &account);
+
+    assert!(client.try_set_key_grace_period(&10).is_err());
+    assert_eq!(client.get_key_grace_period(), 0);
+
+    env.mock_all_auths();
+    assert_eq!(
+        client.try_set_key_grace_period(&(MAX_KEY_GRACE_LEDGERS + 1)),
+        Err(Ok(Error::KeyGraceTooLong))
+    );
+    client.set_key_grace_period(&MAX_KEY_GRACE_LEDGERS);
+    assert_eq!(client.get_key_grace_period(), MAX_KEY_GRACE_LEDGERS);
+}
+
+// Same key and payload as AUTH_SIGNATURE_HEX, signed with a different nonce
+const AUTH_SIGNATURE_FRESH_NONCE_HEX: &str =
+    include_str!("fixtures/auth_signature_fresh_nonce.hex");
+// Payload 0x20..0x3f signed with the same nonce as AUTH_SIGNATURE_HEX
+const AUTH_SIGNATURE_REUSED_NONCE_HEX: &str =
+    include_str!("fixtures/auth_signature_reused_nonce.hex");
+
+#[test]
+fn test_nonce_tracking_disabled_by_default() {
+    let env = Env::default();
+    let account = deploy_auth_account(&env);
+    let client = FalconSmartAccountClient::new(&env, &account);
+    let payload = auth_payload(&env);
+    let sig = hex::decode(AUTH_SIGNATURE_HEX.trim()).unwrap();
+
+    assert_eq!(client.get_nonce_window(), 0);
+    assert_eq!(check_auth(&env, &account, &payload, &sig), Ok(()));
+    assert_eq!(check_auth(&env, &account, &payload, &sig), Ok(()));
+}
+
+#[test]
+fn test_nonce_tracking_rejects_reused_nonce() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let account = deploy_auth_account(&env);
+    let client = FalconSmartAccountClient::new(&env, &account);
+    client.set_nonce_window(&8);
+
+    let sig = hex::decode(AUTH_SIGNATURE_HEX.trim()).unwrap();
+    let reused = hex::decode(AUTH_SIGNATURE_REUSED_NONCE_HEX.trim()).unwrap();
+    let mut other_payload = [0u8; 32];
+    for (i, b) in other_payload.iter_mut().enumerate() {
+        *b = 0x20 + i as u8;
+    }
+    let other_payload = BytesN::from_array(&env, &other_payload);
+
+    assert_eq!(
+        check_auth(&env, &account, &auth_payload(&env), &sig),
+        Ok(())
+    );
+    // A valid signature over a different payload, but with a repeated nonce
+    assert_eq!(
+        check_auth(&env, &account, &other_payload, &reused),
+        Err(Error::NonceReused)
+    );
+
+    // Rejected signatures are not recorded
+    let env = Env::default();
+    env.mock_all_auths();
+    let account = deploy_auth_account(&env);
+    FalconSmartAccountClient::new(&env, &account).set_nonce_window(&8);
+    assert_eq!(
+        check_auth(&env, &account, &BytesN::from_array(&env, &[0xAA; 32]), &sig),
+        Err(Error::NormTooLarge)
+    );
+    assert_eq!(
+        check_auth(&env, &account, &auth_payload(&env), &sig),
+        Ok(())
+    );
+}
+
+#[test]
+fn test_nonce_window_evicts_oldest() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let account = deploy_auth_account(&env);
+    let client = FalconSmartAccountClient::new(&env, &account);
+    let payload = auth_payload(&env);
+    let sig = hex::decode(AUTH_SIGNATURE_HEX.trim()).unwrap();
+    let fresh = hex::decode(AUTH_SIGNATURE_FRESH_NONCE_HEX.trim()).unwrap();
+
+    client.set_nonce_window(&1);
+    assert_eq!(check_auth(&env, &account, &payload, &sig), Ok(()));
+    assert_eq!(
+        check_auth(&env, &account, &payload, &sig),
+        Err(Error::NonceReused)
+    );
+
+    // The fresh nonce pushes the first one out of the window
+    assert_eq!(check_auth(&env, &account, &payload, &fresh), Ok(()));
+    assert_eq!(check_auth(&env, &account, &payload, &sig), Ok(()));
+
+    // Disabling clears the history
+    client.set_nonce_window(&0);
+    client.set_nonce_window(&1);
+    assert_eq!(check_auth(&env, &account, &payload, &sig), Ok(()));
+}
+
+#[test]
+fn test_nonce_fingerprints_stay_live() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let account = deploy_auth_account(&env);
+    let client = FalconSmartAccountClient::new(&env, &account);
+    let payload = auth_payload(&env);
+    let sig = hex::decode(AUTH_SIGNATURE_HEX.trim()).unwrap();
+    let fresh = hex::decode(AUTH_SIGNATURE_FRESH_NONCE_HEX.trim()).unwrap();
+
+    client.set_nonce_window(&8);
+    assert_eq!(check_auth(&env, &account, &payload, &sig), Ok(()));
+    let key = Symbol::new(&env, "NONCES");
+    let ttl = || env.as_contract(&account, || env.storage().persistent().get_ttl(&key));
+    assert_eq!(ttl(), NONCE_TTL_LEDGERS);
+
+    // Every accepted signature and every resize extends the fingerprints
+    env.ledger().with_mut(|l| l.sequence_number += 20_000);
+    assert_eq!(ttl(), NONCE_TTL_LEDGERS - 20_000);
+    assert_eq!(check_auth(&env, &account, &payload, &fresh), Ok(()));
+    assert_eq!(ttl(), NONCE_TTL_LEDGERS);
+    env.ledger().with_mut(|l| l.sequence_number += 20_000);
+    client.set_nonce_window(&4);
+    assert_eq!(ttl(), NONCE_TTL_LEDGERS);
+    assert_eq!(
+        check_auth(&env, &account, &payload, &sig),
+        Err(Error::NonceReused)
+    );
+}
+
+#[test]
+fn test_set_nonce_window_limits() {
+    let env = Env::default();
+    let account = deploy_auth_account(&env);
+    let client = FalconSmartAccountClient::new(&env, &account);
+
+    assert!(client.try_set_nonce_window(&4).is_err());
+    assert_eq!(client.get_nonce_window(), 0);
+
+    env.mock_all_auths();
+    assert_eq!(
+        client.try_set_nonce_window(&(MAX_NONCE_WINDOW + 1)),
+        Err(Ok(Error::NonceWindowTooLarge))
+    );
+    client.set_nonce_window(&MAX_NONCE_WINDOW);
+    assert_eq!(client.get_nonce_window(), MAX_NONCE_WINDOW);
+}
+
+#[test]
+fn test_audit_log_records_and_evicts() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let account = deploy_auth_account(&env);
+    let client = FalconSmartAccountClient::new(&env, &account);
+    let payload = auth_payload(&env);
+    let sig = hex::decode(AUTH_SIGNATURE_HEX.trim()).unwrap();
+    let fresh = hex::decode(AUTH_SIGNATURE_FRESH_NONCE_HEX.trim()).unwrap();
+    let reused = hex::decode(AUTH_SIGNATURE_REUSED_NONCE_HEX.trim()).unwrap();
+    let mut other_payload = [0u8; 32];
+    for (i, b) in other_payload.iter_mut().enumerate() {
+        *b = 0x20 + i as u8;
+    }
+    let other_payload = BytesN::from_array(&env, &other_payload);
+
+    // Disabled by default: nothing is recorded
+    assert_eq!(client.get_audit_log_capacity(), 0);
+    assert_eq!(check_auth(&env, &account, &payload, &sig), Ok(()));
+    assert!(client.get_recent_auths(&10).is_empty());
+
+    client.set_audit_log(&2);
+    let fingerprint = |sig: &[u8]| {
+        let digest = env.crypto().sha256(&Bytes::from_slice(&env, &sig[1..41]));
+        BytesN::<16>::from_array(&env, &digest.to_array()[..16].try_into().unwrap())
+    };
+    let record = |ledger: u32, payload: &BytesN<32>, sig: &[u8]| AuthRecord {
+        ledger,
+        payload: payload.clone(),
+        nonce_fingerprint: fingerprint(sig),
+    };
+
+    env.ledger().set_sequence_number(100);
+    assert_eq!(check_auth(&env, &account, &payload, &sig), Ok(()));
+    // Rejected authorizations are not recorded
+    assert_eq!(
+        check_auth(&env, &account, &other_payload, &sig),
+        Err(Error::NormTooLarge)
+    );
+    env.ledger().set_sequence_number(101);
+    assert_eq!(check_auth(&env, &account, &payload, &fresh), Ok(()));
+    assert_eq!(
+        client.get_recent_auths(&10),
+        Vec::from_array(
+            &env,
+            [record(101, &payload, &fresh), record(100, &payload, &sig)]
+        )
+    );
+    assert_eq!(client.get_recent_auths(&1).len(), 1);
+
+    // A third record evicts the oldest
+    env.ledger().set_sequence_number(102);
+    assert_eq!(check_auth(&env, &account, &other_payload, &reused), Ok(()));
+    assert_eq!(
+        client.get_recent_auths(&10),
+        Vec::from_array(
+            &env,
+            [
+                record(102, &other_payload, &reused),
+                record(101, &payload, &fresh)
+            ]
+        )
+    );
+
+    // Shrinking keeps the newest records, disabling clears them
+    client.set_audit_log(&1);
+    assert_eq!(
+        client.get_recent_auths(&10),
+        Vec::from_array(&env, [record(102, &other_payload, &reused)])
+    );
+    client.set_audit_log(&0);
+    client.set_audit_log(&1);
+    assert!(client.get_recent_auths(&10).is_empty());
+}
+
+#[test]
+fn test_audit_log_stays_live() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let account = deploy_auth_account(&env);
+    let client = FalconSmartAccountClient::new(&env, &account);
+    let payload = auth_payload(&env);
+    let sig = hex::decode(AUTH_SIGNATURE_HEX.trim()).unwrap();
+
+    client.set_audit_log(&4);
+    assert_eq!(check_auth(&env, &account, &payload, &sig), Ok(()));
+    let key = Symbol::new(&env, "AUDIT_LOG");
+    let ttl = || env.as_contract(&account, || env.storage().persistent().get_ttl(&key));
+    assert_eq!(ttl(), AUDIT_TTL_LEDGERS);
+
+    // Every appended record and every resize extends the log
+    env.ledger().with_mut(|l| l.sequence_number += 20_000);
+    assert_eq!(ttl(), AUDIT_TTL_LEDGERS - 20_000);
+    assert_eq!(check_auth(&env, &account, &payload, &sig), Ok(()));
+    assert_eq!(ttl(), AUDIT_TTL_LEDGERS);
+    env.ledger().with_mut(|l| l.sequence_number += 20_000);
+    client.set_audit_log(&1);
+    assert_eq!(ttl(), AUDIT_TTL_LEDGERS);
+    assert_eq!(client.get_recent_auths(&10).len(), 1);
+}
+
+#[test]
+fn test_set_audit_log_limits() {
+    let env = Env::default();
+    let account = deploy_auth_account(&env);
+    let client = FalconSmartAccountClient::new(&env, &account);
+
+    assert!(client.try_set_audit_log(&4).is_err());
+    assert_eq!(client.get_audit_log_capacity(), 0);
+
+    env.mock_all_auths();
+    assert_eq!(
+        client.try_set_audit_log(&(MAX_AUDIT_LOG + 1)),
+        Err(Ok(Error::AuditLogTooLarge))
+    );
+    client.set_audit_log(&MAX_AUDIT_LOG);
+    assert_eq!(client.get_audit_log_capacity(), MAX_AUDIT_LOG);
+}
+
+/// Re-encodes a signature in the constant-time format (12-bit two's
+/// complement coefficients, MSB-first) with header 0x59.
+fn to_constant_time(sig: &[u8]) -> std::vec::Vec<u8> {
+    let parsed = FalconSignature::from_bytes(sig).expect("signature should parse");
+    let mut out = std::vec![0x59];
+    out.extend_from_slice(parsed.nonce());
+    let mut acc: u32 = 0;
+    let mut acc_len = 0;
+    for &c in parsed.s2() {
+        acc = (acc << 12) | (c as u32 & 0xFFF);
+        acc_len += 12;
+        while acc_len >= 8 {
+            acc_len -= 8;
+            out.push((acc >> acc_len) as u8);
+        }
+    }
+    out
+}
+
+fn deploy_auth_account_with_format(env: &Env, format: AllowedFormat) -> Address {
+    let pubkey_bytes = hex::decode(AUTH_PUBKEY_HEX.trim()).expect("Invalid pubkey hex");
+    let pubkey = Bytes::from_slice(env, &pubkey_bytes);
+    env.register(
+        FalconSmartAccount,
+        (&pubkey, Some(format), None::<BytesN<32>>),
+    )
+}
+
+#[test]
+fn test_format_policy_constant_time_only() {
+    let env = Env::default();
+    let account = deploy_auth_account_with_format(&env, AllowedFormat::ConstantTime);
+    let client = FalconSmartAccountClient::new(&env, &account);
+    let payload = auth_payload(&env);
+    let sig = hex::decode(AUTH_SIGNATURE_HEX.trim()).unwrap();
+    let ct = to_constant_time(&sig);
+    assert_eq!(ct.len(), 809);
+
+    assert_eq!(
+        client.get_allowed_format(),
+        Some(AllowedFormat::ConstantTime)
+    );
+    assert_eq!(check_auth(&env, &account, &payload, &ct), Ok(()));
+    assert_eq!(
+        check_auth(&env, &account, &payload, &sig),
+        Err(Error::DisallowedFormat)
+    );
+
+    // Size limits are still checked first
+    assert_eq!(
+        check_auth(&env, &account, &payload, &ct[..41]),
+        Err(Error::InvalidSignatureSize)
+    );
+}
+
+#[test]
+fn test_format_policy_compressed_only() {
+    let env = Env::default();
+    let account = deploy_auth_account_with_format(&env, AllowedFormat::Compressed);
+    let payload = auth_payload(&env);
+    // The fixture carries the compressed header (0x39) on a zero-padded body
+    let sig = hex::decode(AUTH_SIGNATURE_HEX.trim()).unwrap();
+    let mut padded = sig.clone();
+    padded[0] = 0x29;
+
+    assert_eq!(check_auth(&env, &account, &payload, &sig), Ok(()));
+    for other in [padded, to_constant_time(&sig)] {
+        assert_eq!(
+            check_auth(&env, &account, &payload, &other),
+            Err(Error::DisallowedFormat)
+        );
+    }
+
+    // An unknown format nibble never matches the policy
+    let mut unknown = sig.clone();
+    unknown[0] = 0x49;
+    assert_eq!(
+        check_auth(&env, &account, &payload, &unknown),
+        Err(Error::DisallowedFormat)
+    );
+}
+
+#[test]
+fn test_no_format_policy_accepts_all_formats() {
+    let env = Env::default();
+    let account = deploy_auth_account(&env);
+    let client = FalconSmartAccountClient::new(&env, &account);
+    let payload = auth_payload(&env);
+    let sig = hex::decode(AUTH_SIGNATURE_HEX.trim()).unwrap();
+    let mut padded = sig.clone();
+    padded[0] = 0x29;
+
+    assert_eq!(client.get_allowed_format(), None);
+    for other in [sig.clone(), padded, to_constant_time(&sig)] {
+        assert_eq!(check_auth(&env, &account, &payload, &other), Ok(()));
+    }
+}
+
+fn auth_ed25519_pubkey(env: &Env) -> BytesN<32> {
+    let bytes = hex::decode(AUTH_ED25519_PUBKEY_HEX.trim()).expect("Invalid pubkey hex");
+    BytesN::from_array(env, &bytes.try_into().unwrap())
+}
+
+fn auth_hybrid_signature(env: &Env) -> HybridSignature {
+    let sig = hex::decode(AUTH_ED25519_SIGNATURE_HEX.trim()).expect("Invalid signature hex");
+    HybridSignature {
+        ed25519_pk: auth_ed25519_pubkey(env),
+        ed25519_sig: BytesN::from_array(env, &sig.try_into().unwrap()),
+        ..falcon_only(env, &hex::decode(AUTH_SIGNATURE_HEX.trim()).unwrap())
+    }
+}
+
+fn deploy_hybrid_account(env: &Env) -> Address {
+    let pubkey_bytes = hex::decode(AUTH_PUBKEY_HEX.trim()).expect("Invalid pubkey hex");
+    let pubkey = Bytes::from_slice(env, &pubkey_bytes);
+    let ed25519_pubkey = auth_ed25519_pubkey(env);
+    env.register(
+        FalconSmartAccount,
+        (&pubkey, None::<AllowedFormat>, Some(ed25519_pubkey)),
+    )
+}
+
+#[test]
+fn test_hybrid_account_requires_both_signatures() {
+    let env = Env::default();
+    let account = deploy_hybrid_account(&env);
+    let client = FalconSmartAccountClient::new(&env, &account);
+    let payload = auth_payload(&env);
+    let both = auth_hybrid_signature(&env);
+
+    assert_eq!(client.get_ed25519_pubkey(), Some(auth_ed25519_pubkey(&env)));
+    assert_eq!(try_check_auth(&env, &account, &payload, &both), Ok(()));
+
+    // Falcon only: the host aborts on the invalid Ed25519 signature
+    let mut falcon_only_sig = both.clone();
+    falcon_only_sig.ed25519_sig = BytesN::from_array(&env, &[0x11; 64]);
+    assert!(matches!(
+        try_check_auth(&env, &account, &payload, &falcon_only_sig),
+        Err(Err(_))
+    ));
+
+    // Ed25519 only: the Falcon signature does not verify
+    let mut ed25519_only = both.clone();
+    let mut falcon = hex::decode(AUTH_SIGNATURE_HEX.trim()).unwrap();
+    falcon[100] ^= 0x01;
+    ed25519_only.falcon = Bytes::from_slice(&env, &falcon);
+    assert_eq!(
+        try_check_auth(&env, &account, &payload, &ed25519_only),
+        Err(Ok(Error::NormTooLarge))
+    );
+
+    // A valid signature from another Ed25519 key is not accepted
+    let mut other_key = both.clone();
+    other_key.ed25519_pk = BytesN::from_array(&env, &[0x22; 32]);
+    assert_eq!(
+        try_check_auth(&env, &account, &payload, &other_key),
+        Err(Ok(Error::Ed25519KeyMismatch))
+    );
+}
+
+#[test]
+fn test_non_hybrid_account_ignores_ed25519_fields() {
+    let env = Env::default();
+    let account = deploy_auth_account(&env);
+    let client = FalconSmartAccountClient::new(&env, &account);
+    let payload = auth_payload(&env);
+
+    assert_eq!(client.get_ed25519_pubkey(), None);
+    assert_eq!(
+        try_check_auth(&env, &account, &payload, &auth_hybrid_signature(&env)),
+        Ok(())
+    );
+    let sig = hex::decode(AUTH_SIGNATURE_HEX.trim()).unwrap();
+    assert_eq!(check_auth(&env, &account, &payload, &sig), Ok(()));
+}
+
+#[test]
+fn test_structured_auth() {
+    use soroban_sdk::auth::{Context, ContractContext};
+    use soroban_sdk::{symbol_short, Symbol};
+
+    let env = Env::default();
+    let account = deploy_auth_account(&env);
+    let client = FalconSmartAccountClient::new(&env, &account);
+    let payload = auth_payload(&env);
+    let structured = falcon_only(&env, &hex::decode(STRUCTURED_AUTH_SIGNATURE_HEX).unwrap());
+    let raw = falcon_only(&env, &hex::decode(AUTH_SIGNATURE_HEX.trim()).unwrap());
+
+    let call = |fn_name: Symbol, arg: u32| {
+        Context::Contract(ContractContext {
+            contract: account.clone(),
+            fn_name,
+            args: (arg,).into_val(&env),
+        })
+    };
+    let check = |signature: &HybridSignature, contexts: &[Context]| {
+        env.try_invoke_contract_check_auth::<Error>(
+            &account,
+            &payload,
+            signature.into_val(&env),
+            &Vec::from_slice(&env, contexts),
+        )
+    };
+    let signed_call = [call(symbol_short!("nonce_win"), 16)];
+
+    // The raw payload mode ignores the contexts
+    assert!(!client.get_structured_auth());
+    assert_eq!(check(&raw, &signed_call), Ok(()));
+    assert!(check(&structured, &signed_call).is_err());
+
+    env.mock_all_auths();
+    client.set_structured_auth(&true);
+    assert!(client.get_structured_auth());
+    assert_eq!(check(&structured, &signed_call), Ok(()));
+    assert_eq!(check(&raw, &signed_call), Err(Ok(Error::NormTooLarge)));
+
+    // Any field that differs from the signed call changes the message
+    for other in [
+        call(symbol_short!("nonce_win"), 17),
+        call(symbol_short!("rotate"), 16),
+    ] {
+        assert_eq!(check(&structured, &[other]), Err(Ok(Error::NormTooLarge)));
+    }
+    assert_eq!(
+        check(&structured, &[]),
+        Err(Ok(Error::UnsupportedAuthContext))
+    );
+    assert_eq!(
+        check(
+            &structured,
+            &[signed_call[0].clone(), signed_call[0].clone()]
+        ),
+        Err(Ok(Error::UnsupportedAuthContext))
+    );
+
+    client.set_structured_auth(&false);
+    assert_eq!(check(&raw, &signed_call), Ok(()));
+}
@@ -3,8 +3,8 @@
 # Deploy Falcon-512 Verifier to Soroban (Stellar)
 #
 # Usage:
-#   ./deploy.sh --network testnet [--source <identity>]
-#   ./deploy.sh --network mainnet [--source <identity>]
+#   ./deploy.sh --network testnet [--source <identity>] [--count-stats]
+#   ./deploy.sh --network mainnet [--source <identity>] [--count-stats]
 #
 # Prerequisites:
 #   - stellar CLI installed (https://developers.stellar.org/docs/tools/developer-tools)
@@ -34,11 +34,12 @@ MAINNET_PASSPHRASE="Public Global Stellar Network ; September 2015"
 # =============================================================================
 
 print_usage() {
-    echo "Usage: $0 --network <testnet|mainnet> [--source <identity>]"
+    echo "Usage: $0 --network <testnet|mainnet> [--source <identity>] [--count-stats]"
     echo ""
     echo "Options:"
     echo "  --network    Target network: testnet or mainnet (required)"
     echo "  --source     Stellar identity name for signing (default: default)"
+    echo "  --count-stats Keep on-chain counters of verify calls (one storage write per call)"
     echo ""
     echo "Examples:"
     echo "  $0 --network testnet"
@@ -68,6 +69,7 @@ log_warn() {
 
 NETWORK=""
 SOURCE="default"
+COUNT_STATS="false"
 
 while [[ $# -gt 0 ]]; do
     case $1 in
@@ -79,6 +81,10 @@ while [[ $# -gt 0 ]]; do
             SOURCE="$2"
             shift 2
             ;;
+        --count-stats)
+            COUNT_STATS="true"
+            shift
+            ;;
         --help|-h)
             print_usage
             exit 0
@@ -218,6 +224,8 @@ DEPLOY_OUTPUT=$(stellar contract deploy \
     --source "$SOURCE" \
     --rpc-url "$RPC_URL" \
     --network-passphrase "$NETWORK_PASSPHRASE" \
+    -- \
+    --count_stats "$COUNT_STATS" \
     2>&1)
 
 # Extract contract ID (last line that looks like a contract ID)
@@ -0,0 +1,34 @@
+//! Feeds arbitrary 897-byte inputs to `decode_pubkey`.
+//!
+//! Public keys reach the contracts as untrusted on-chain data, so the decoder
+//! must never panic. Any key it accepts must have every coefficient below q
+//! and match an independent unpacking of the 14-bit big-endian fields.
+//!
+//! Run from the verifier crate with `cargo +nightly fuzz run decode_pubkey`.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use soroban_falcon_verifier::{FalconVerifier, FALCON_512_N, FALCON_512_PUBKEY_SIZE, Q};
+
+fuzz_target!(|pubkey: [u8; FALCON_512_PUBKEY_SIZE]| {
+    let mut h = [0u16; FALCON_512_N];
+    if !FalconVerifier::decode_pubkey(&pubkey, &mut h) {
+        return;
+    }
+
+    assert_eq!(pubkey[0], 0x09);
+    for (i, &c) in h.iter().enumerate() {
+        assert!((c as u32) < Q, "coefficient {i} = {c}");
+
+        let bit = 14 * i;
+        let window = u32::from_be_bytes([
+            pubkey[1 + bit / 8],
+            pubkey[2 + bit / 8],
+            *pubkey.get(3 + bit / 8).unwrap_or(&0),
+            0,
+        ]);
+        let expected = (window >> (32 - 14 - bit % 8)) & 0x3FFF;
+        assert_eq!(c as u32, expected, "coefficient {i}");
+    }
+});
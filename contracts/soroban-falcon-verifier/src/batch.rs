@@ -0,0 +1,158 @@
+//! Experimental batch verification of Falcon-512 signatures under one key.
+//!
+//! After hashing, the cost of a verification is dominated by the forward and
+//! inverse NTT that compute s2·h. Given s1 = c0 - s2·h for every signature,
+//! as written by [`FalconVerifier::verify_512_into`] or kept by the signer,
+//! each norm ||(s1, s2)||² can be checked directly, and what remains is the
+//! linear relation s1 + s2·h = c0. A batch checks random linear combinations
+//! of these relations instead,
+//!
+//! ```text
+//! Σ rᵢ·(c0ᵢ - s1ᵢ) = (Σ rᵢ·s2ᵢ)·h  (mod q)
+//! ```
+//!
+//! with one ring multiplication per combination rather than per signature.
+//!
+//! # Soundness
+//!
+//! If any relation fails, a combination with coefficients uniform in
+//! [1, q-1] still holds with probability at most 1/(q-1): fixing the other
+//! coefficients, a non-zero coefficient of the failing relation is cancelled
+//! by exactly one value of its rᵢ. `BATCH_ROUNDS` = 10 independent
+//! combinations bound the error by (q-1)^-10 < 2^-135 per batch. The
+//! coefficients are derived with SHAKE256 from the key and the whole batch,
+//! so they are deterministic and cannot be known before the batch is fixed.
+//!
+//! This is a research feature. It is not part of the Falcon specification,
+//! has not been reviewed, runs in variable time and needs an s1 hint per
+//! signature. It pays off for batches larger than `BATCH_ROUNDS`.
+
+use sha3::{
+    digest::{ExtendableOutput, Update, XofReader},
+    Shake256,
+};
+
+use crate::ntt::{field_from_signed, field_sub, ntt_forward, ntt_inverse, poly_pointwise_mul};
+use crate::{FalconSignature, FalconVerifier, FALCON_512_N, L2_BOUND_512, Q};
+
+/// Independent random combinations checked per batch.
+const BATCH_ROUNDS: usize = 10;
+/// Domain separation for the combination coefficients.
+const BATCH_DOMAIN: &[u8] = b"falcon512-batch-v0";
+
+/// One signature of a batch for [`FalconVerifier::verify_512_batch`].
+#[derive(Clone, Copy, Debug)]
+pub struct BatchItem<'a> {
+    pub message: &'a [u8],
+    pub signature: &'a [u8],
+    /// s1 = c0 - s2·h in centered form, as written by
+    /// [`FalconVerifier::verify_512_into`].
+    pub s1: &'a [i16; FALCON_512_N],
+}
+
+/// Draws combination coefficients uniform in [1, q-1].
+struct Coefficients(sha3::Shake256Reader);
+
+impl Coefficients {
+    fn new(h: &[u16; FALCON_512_N], batch: &[BatchItem]) -> Self {
+        let mut hasher = Shake256::default();
+        hasher.update(BATCH_DOMAIN);
+        for &c in h {
+            hasher.update(&c.to_le_bytes());
+        }
+        for item in batch {
+            hasher.update(&(item.message.len() as u64).to_le_bytes());
+            hasher.update(item.message);
+            hasher.update(&(item.signature.len() as u64).to_le_bytes());
+            hasher.update(item.signature);
+            for &c in item.s1 {
+                hasher.update(&c.to_le_bytes());
+            }
+        }
+        Coefficients(hasher.finalize_xof())
+    }
+
+    fn next(&mut self) -> u32 {
+        // 5·(q-1) = 61440 is the largest multiple of q-1 below 2^16
+        loop {
+            let mut bytes = [0u8; 2];
+            self.0.read(&mut bytes);
+            let w = u16::from_be_bytes(bytes) as u32;
+            if w < 5 * (Q - 1) {
+                return 1 + w % (Q - 1);
+            }
+        }
+    }
+}
+
+/// Checks ||(s1, s2)||² against the Falcon-512 bound, rejecting an all-zero
+/// s2 like the single-signature verifiers.
+fn is_short(s1: &[i16; FALCON_512_N], s2: &[i16; FALCON_512_N]) -> bool {
+    let norm: u64 = s1
+        .iter()
+        .chain(s2.iter())
+        .map(|&z| (z as i64 * z as i64) as u64)
+        .sum();
+    norm <= L2_BOUND_512 as u64 && s2.iter().any(|&z| z != 0)
+}
+
+/// acc += c·f (mod q), coefficient-wise.
+fn accumulate(acc: &mut [u16; FALCON_512_N], c: u32, f: &[u32; FALCON_512_N]) {
+    for (a, &x) in acc.iter_mut().zip(f.iter()) {
+        *a = ((*a as u32 + c * x) % Q) as u16;
+    }
+}
+
+impl FalconVerifier {
+    /// Verifies a batch of Falcon-512 signatures under one public key.
+    ///
+    /// Returns `true` only if every signature would pass
+    /// [`verify_512`](Self::verify_512), up to the soundness error of the
+    /// random combinations described in the module documentation, and every
+    /// s1 hint is the one its signature produces. An empty batch is
+    /// accepted. Only available with the `research` feature.
+    pub fn verify_512_batch(pubkey: &[u8], batch: &[BatchItem]) -> bool {
+        let mut h = [0u16; FALCON_512_N];
+        if !Self::decode_pubkey(pubkey, &mut h) {
+            return false;
+        }
+        let mut coefficients = Coefficients::new(&h, batch);
+        Self::prepare_pubkey(&mut h);
+
+        // Σ rᵢ·(c0ᵢ - s1ᵢ) and Σ rᵢ·s2ᵢ for each combination
+        let mut lhs = [[0u16; FALCON_512_N]; BATCH_ROUNDS];
+        let mut rhs = [[0u16; FALCON_512_N]; BATCH_ROUNDS];
+        for item in batch {
+            let Ok(sig) = FalconSignature::from_bytes(item.signature) else {
+                return false;
+            };
+            if !is_short(item.s1, sig.s2()) {
+                return false;
+            }
+            let mut c0 = [0u16; FALCON_512_N];
+            if !Self::hash_to_point(sig.nonce(), item.message, &mut c0) {
+                return false;
+            }
+
+            // c0 - s1, with |s1| below q once the norm is within the bound
+            let mut d = [0u32; FALCON_512_N];
+            for (d, (&c, &z)) in d.iter_mut().zip(c0.iter().zip(item.s1.iter())) {
+                *d = field_sub(c as u32, field_from_signed(z as i32));
+            }
+            let s2 = sig.s2().map(|z| field_from_signed(z as i32));
+
+            for (l, r) in lhs.iter_mut().zip(rhs.iter_mut()) {
+                let c = coefficients.next();
+                accumulate(l, c, &d);
+                accumulate(r, c, &s2);
+            }
+        }
+
+        lhs.iter().zip(rhs.iter_mut()).all(|(l, r)| {
+            ntt_forward(r);
+            poly_pointwise_mul(r, &h);
+            ntt_inverse(r);
+            l == r
+        })
+    }
+}
@@ -0,0 +1,48 @@
+//! C ABI export for embedding the verifier outside Soroban, e.g. in a
+//! standalone WASM module or a native library.
+
+use core::slice;
+
+use crate::{FalconVerifier, VerifyError};
+
+/// Borrows `len` bytes at `ptr`, treating a null pointer as empty only when
+/// `len` is 0.
+unsafe fn input<'a>(ptr: *const u8, len: usize) -> Option<&'a [u8]> {
+    if ptr.is_null() {
+        return (len == 0).then_some(&[][..]);
+    }
+    Some(slice::from_raw_parts(ptr, len))
+}
+
+/// Verifies a Falcon-512 signature passed as pointer/length pairs.
+///
+/// Returns `1` if the signature is valid, `0` if the inputs are well-formed
+/// but the signature does not verify, and `-1` on error: a null pointer with
+/// a non-zero length, a public key that does not decode, or a signature that
+/// does not parse.
+///
+/// # Safety
+/// Each non-null pointer must be valid for reads of its length for the
+/// duration of the call.
+#[no_mangle]
+pub unsafe extern "C" fn falcon512_verify(
+    pk_ptr: *const u8,
+    pk_len: usize,
+    msg_ptr: *const u8,
+    msg_len: usize,
+    sig_ptr: *const u8,
+    sig_len: usize,
+) -> i32 {
+    let (Some(pk), Some(msg), Some(sig)) = (
+        input(pk_ptr, pk_len),
+        input(msg_ptr, msg_len),
+        input(sig_ptr, sig_len),
+    ) else {
+        return -1;
+    };
+    match FalconVerifier::verify_512_detailed(pk, msg, sig) {
+        Ok(()) => 1,
+        Err(VerifyError::NormTooLarge) => 0,
+        Err(_) => -1,
+    }
+}
@@ -0,0 +1,165 @@
+//! COSE_Sign1 (RFC 9052) verification of Falcon-512 signatures, for WebAuthn
+//! and attestation flows that wrap signatures in CBOR.
+//!
+//! Only the subset of CBOR that a COSE_Sign1 needs is decoded: definite-length
+//! items, with the unprotected header map skipped rather than interpreted.
+
+extern crate std;
+
+use std::vec::Vec;
+
+use crate::FalconVerifier;
+
+/// CBOR tag marking a COSE_Sign1 message.
+const COSE_SIGN1_TAG: u64 = 18;
+/// Nesting limit when skipping unprotected header values.
+const MAX_DEPTH: u32 = 8;
+
+const MAJOR_BYTES: u8 = 2;
+const MAJOR_TEXT: u8 = 3;
+const MAJOR_ARRAY: u8 = 4;
+const MAJOR_MAP: u8 = 5;
+const MAJOR_TAG: u8 = 6;
+
+struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Option<&'a [u8]> {
+        let end = self.pos.checked_add(len)?;
+        let bytes = self.data.get(self.pos..end)?;
+        self.pos = end;
+        Some(bytes)
+    }
+
+    /// Reads an item head, returning its major type and argument.
+    fn head(&mut self) -> Option<(u8, u64)> {
+        let initial = self.take(1)?[0];
+        let info = initial & 0x1F;
+        let arg = match info {
+            0..=23 => info as u64,
+            24..=27 => self
+                .take(1 << (info - 24))?
+                .iter()
+                .fold(0, |acc, &b| (acc << 8) | b as u64),
+            // Indefinite lengths and reserved values
+            _ => return None,
+        };
+        Some((initial >> 5, arg))
+    }
+
+    fn bytes(&mut self) -> Option<&'a [u8]> {
+        match self.head()? {
+            (MAJOR_BYTES, len) => self.take(usize::try_from(len).ok()?),
+            _ => None,
+        }
+    }
+
+    /// Skips one item of any type, nested at most `depth` deep.
+    fn skip(&mut self, depth: u32) -> Option<()> {
+        let depth = depth.checked_sub(1)?;
+        match self.head()? {
+            (MAJOR_BYTES | MAJOR_TEXT, len) => {
+                self.take(usize::try_from(len).ok()?)?;
+            }
+            (MAJOR_ARRAY, len) => {
+                for _ in 0..len {
+                    self.skip(depth)?;
+                }
+            }
+            (MAJOR_MAP, len) => {
+                for _ in 0..len {
+                    self.skip(depth)?;
+                    self.skip(depth)?;
+                }
+            }
+            (MAJOR_TAG, _) => self.skip(depth)?,
+            // Integers and simple values carry no content past the head
+            _ => {}
+        }
+        Some(())
+    }
+}
+
+/// Splits a COSE_Sign1 into its protected header bytes, payload and
+/// signature. The message may be wrapped in tag 18 and must not carry
+/// trailing bytes.
+fn parse_sign1(cose_sign1: &[u8]) -> Option<(&[u8], &[u8], &[u8])> {
+    let mut reader = Reader {
+        data: cose_sign1,
+        pos: 0,
+    };
+    let mut head = reader.head()?;
+    if head == (MAJOR_TAG, COSE_SIGN1_TAG) {
+        head = reader.head()?;
+    }
+    if head != (MAJOR_ARRAY, 4) {
+        return None;
+    }
+    let protected = reader.bytes()?;
+    let (MAJOR_MAP, entries) = reader.head()? else {
+        return None;
+    };
+    for _ in 0..entries {
+        reader.skip(MAX_DEPTH)?;
+        reader.skip(MAX_DEPTH)?;
+    }
+    // A detached payload is nil, which `bytes` rejects
+    let payload = reader.bytes()?;
+    let signature = reader.bytes()?;
+    (reader.pos == cose_sign1.len()).then_some((protected, payload, signature))
+}
+
+fn write_head(out: &mut Vec<u8>, major: u8, len: usize) {
+    let major = major << 5;
+    match len {
+        0..=23 => out.push(major | len as u8),
+        24..=0xFF => out.extend_from_slice(&[major | 24, len as u8]),
+        0x100..=0xFFFF => {
+            out.push(major | 25);
+            out.extend_from_slice(&(len as u16).to_be_bytes());
+        }
+        0x1_0000..=0xFFFF_FFFF => {
+            out.push(major | 26);
+            out.extend_from_slice(&(len as u32).to_be_bytes());
+        }
+        _ => {
+            out.push(major | 27);
+            out.extend_from_slice(&(len as u64).to_be_bytes());
+        }
+    }
+}
+
+/// Encodes `["Signature1", protected, h'', payload]`, the bytes a COSE_Sign1
+/// signer signs.
+fn sig_structure(protected: &[u8], payload: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(32 + protected.len() + payload.len());
+    write_head(&mut out, MAJOR_ARRAY, 4);
+    write_head(&mut out, MAJOR_TEXT, 10);
+    out.extend_from_slice(b"Signature1");
+    for field in [protected, &[], payload] {
+        write_head(&mut out, MAJOR_BYTES, field.len());
+        out.extend_from_slice(field);
+    }
+    out
+}
+
+impl FalconVerifier {
+    /// Verifies a Falcon-512 signature carried in a COSE_Sign1 message.
+    ///
+    /// The signature must cover the COSE `Sig_structure` built from the
+    /// protected header bytes and the embedded payload, with empty external
+    /// data. The protected header is not interpreted: Falcon has no
+    /// registered COSE algorithm, so callers that pin one check it
+    /// themselves. Malformed CBOR, indefinite-length items, a detached
+    /// payload and trailing bytes all yield `false`. Only available with the
+    /// `std` feature.
+    pub fn verify_cose_falcon(cose_sign1: &[u8], pubkey: &[u8]) -> bool {
+        let Some((protected, payload, signature)) = parse_sign1(cose_sign1) else {
+            return false;
+        };
+        FalconVerifier::verify_512(pubkey, &sig_structure(protected, payload), signature)
+    }
+}
@@ -0,0 +1,231 @@
+//! Conformance runner for NIST-style KAT response files.
+//!
+//! Each record holds `count`, `mlen`, `msg`, `pk`, `smlen` and `sm`, where
+//! `sm = sig_len (2 bytes, big-endian) || nonce (40) || message || header || body`.
+//! Records with a 897-byte key are checked as Falcon-512, 1793-byte keys as
+//! Falcon-1024. The `sm` layout can also be built and verified on its own.
+
+extern crate std;
+
+use std::vec::Vec;
+
+use crate::{
+    decode_hex, FalconVerifier, VerifyError, FALCON_1024_PUBKEY_SIZE, FALCON_512_PUBKEY_SIZE,
+    NONCE_LEN,
+};
+
+/// Why a KAT vector did not pass.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KatFailureReason {
+    /// The named field is missing, not valid hex, or inconsistent with the others.
+    Malformed(&'static str),
+    /// The signature was rejected. Falcon-512 vectors carry the failed check;
+    /// Falcon-1024 verification only reports a boolean.
+    Rejected(Option<VerifyError>),
+}
+
+/// A KAT vector that did not pass.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KatFailure {
+    /// Position of the record in the file, starting at 0.
+    pub index: usize,
+    /// The record's `count` field, if it parsed.
+    pub count: Option<u32>,
+    pub reason: KatFailureReason,
+}
+
+/// Outcome of [`FalconVerifier::run_kat`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct KatReport {
+    pub passed: usize,
+    pub failed: usize,
+    /// One entry per failed vector, in file order.
+    pub failures: Vec<KatFailure>,
+}
+
+impl KatReport {
+    /// `true` if at least one vector ran and none failed.
+    pub fn all_passed(&self) -> bool {
+        self.failed == 0 && self.passed > 0
+    }
+}
+
+/// Raw fields of one `count = ...` record.
+#[derive(Default)]
+struct KatRecord<'a> {
+    count: Option<&'a str>,
+    mlen: Option<&'a str>,
+    msg: Option<&'a str>,
+    pk: Option<&'a str>,
+    sm: Option<&'a str>,
+}
+
+fn parse_records(content: &str) -> Vec<KatRecord<'_>> {
+    let mut records = Vec::new();
+    let mut current: Option<KatRecord> = None;
+
+    for line in content.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let Some((key, value)) = line.split_once(" = ") else {
+            continue;
+        };
+        if key == "count" {
+            records.extend(current.take());
+            current = Some(KatRecord {
+                count: Some(value),
+                ..KatRecord::default()
+            });
+            continue;
+        }
+        // Fields before the first count belong to no vector
+        let Some(record) = current.as_mut() else {
+            continue;
+        };
+        match key {
+            "mlen" => record.mlen = Some(value),
+            "msg" => record.msg = Some(value),
+            "pk" => record.pk = Some(value),
+            "sm" => record.sm = Some(value),
+            _ => {}
+        }
+    }
+
+    records.extend(current);
+    records
+}
+
+/// Splits `sm` into its nonce, message and `sig_data = header || body`.
+///
+/// The message length is whatever lies between the nonce and the `sig_len`
+/// bytes of signature data. `None` if `sm` is too short for its `sig_len` or
+/// the signature data lacks a header byte.
+fn split_sm(sm: &[u8]) -> Option<(&[u8], &[u8], &[u8])> {
+    let msg_start = 2 + NONCE_LEN;
+    let sig_len = u16::from_be_bytes(sm.get(..2)?.try_into().ok()?) as usize;
+    let msg_end = sm.len().checked_sub(sig_len)?;
+    if sig_len == 0 || msg_end < msg_start {
+        return None;
+    }
+    Some((&sm[2..msg_start], &sm[msg_start..msg_end], &sm[msg_end..]))
+}
+
+/// Standard encoding of an `sm` signature: header || nonce || body.
+fn sm_signature(nonce: &[u8], sig_data: &[u8]) -> Vec<u8> {
+    let mut signature = Vec::with_capacity(nonce.len() + sig_data.len());
+    signature.push(sig_data[0]);
+    signature.extend_from_slice(nonce);
+    signature.extend_from_slice(&sig_data[1..]);
+    signature
+}
+
+fn check_record(record: &KatRecord) -> Result<(), KatFailureReason> {
+    use KatFailureReason::{Malformed, Rejected};
+
+    let hex_field = |value: Option<&str>, name| {
+        decode_hex(value.ok_or(Malformed(name))?).ok_or(Malformed(name))
+    };
+    let mlen: usize = record
+        .mlen
+        .and_then(|v| v.parse().ok())
+        .ok_or(Malformed("mlen"))?;
+    let msg = hex_field(record.msg, "msg")?;
+    let pk = hex_field(record.pk, "pk")?;
+    let sm = hex_field(record.sm, "sm")?;
+    if msg.len() != mlen {
+        return Err(Malformed("mlen"));
+    }
+
+    let (nonce, sm_msg, sig_data) = split_sm(&sm).ok_or(Malformed("sm"))?;
+    if sm_msg != &msg[..] {
+        return Err(Malformed("sm"));
+    }
+    let signature = sm_signature(nonce, sig_data);
+
+    match pk.len() {
+        FALCON_512_PUBKEY_SIZE => FalconVerifier::verify_512_detailed(&pk, &msg, &signature)
+            .map_err(|e| Rejected(Some(e))),
+        FALCON_1024_PUBKEY_SIZE => match FalconVerifier::verify_1024(&pk, &msg, &signature) {
+            true => Ok(()),
+            false => Err(Rejected(None)),
+        },
+        _ => Err(Malformed("pk")),
+    }
+}
+
+impl FalconVerifier {
+    /// Verifies every vector of a NIST-style KAT response file.
+    ///
+    /// Lets conformance harnesses check arbitrary `.rsp` files against this
+    /// verifier without their own parser. Only available with the `std`
+    /// feature.
+    ///
+    /// # Example
+    /// ```
+    /// use soroban_falcon_verifier::FalconVerifier;
+    ///
+    /// let report = FalconVerifier::run_kat(include_str!("../tests/falcon1024-vectors.rsp"));
+    /// assert_eq!(report.passed + report.failed, 100);
+    /// // `strict_keys` rejects the vectors whose key is not invertible
+    /// assert_eq!(report.all_passed(), !cfg!(feature = "strict_keys"));
+    /// ```
+    pub fn run_kat(content: &str) -> KatReport {
+        let mut report = KatReport::default();
+        for (index, record) in parse_records(content).iter().enumerate() {
+            match check_record(record) {
+                Ok(()) => report.passed += 1,
+                Err(reason) => {
+                    report.failed += 1;
+                    report.failures.push(KatFailure {
+                        index,
+                        count: record.count.and_then(|v| v.parse().ok()),
+                        reason,
+                    });
+                }
+            }
+        }
+        report
+    }
+
+    /// Builds a NIST signed message `sig_len || nonce || message || sig_data`.
+    ///
+    /// `sig_data` is the header byte followed by the signature body, and
+    /// `sig_len` is its length as 2 big-endian bytes. This is the layout of
+    /// the `sm` field in KAT files and of the reference `crypto_sign` output,
+    /// for tools that produce reference-format signatures. The inverse of
+    /// [`verify_nist_sm`](Self::verify_nist_sm). Only available with the
+    /// `std` feature.
+    ///
+    /// # Panics
+    /// Panics if `sig_data` is longer than 65535 bytes.
+    pub fn build_nist_sm(nonce: &[u8; NONCE_LEN], message: &[u8], sig_data: &[u8]) -> Vec<u8> {
+        let sig_len = u16::try_from(sig_data.len()).expect("sig_data longer than 65535 bytes");
+        let mut sm = Vec::with_capacity(2 + NONCE_LEN + message.len() + sig_data.len());
+        sm.extend_from_slice(&sig_len.to_be_bytes());
+        sm.extend_from_slice(nonce);
+        sm.extend_from_slice(message);
+        sm.extend_from_slice(sig_data);
+        sm
+    }
+
+    /// Verifies a NIST signed message, as built by
+    /// [`build_nist_sm`](Self::build_nist_sm), against a Falcon-512 or
+    /// Falcon-1024 public key.
+    ///
+    /// The message is the part of `sm` between the nonce and the signature
+    /// data. A malformed `sm` or a key of any other size yields `false`. Only
+    /// available with the `std` feature.
+    pub fn verify_nist_sm(pubkey: &[u8], sm: &[u8]) -> bool {
+        let Some((nonce, message, sig_data)) = split_sm(sm) else {
+            return false;
+        };
+        let signature = sm_signature(nonce, sig_data);
+        match pubkey.len() {
+            FALCON_512_PUBKEY_SIZE => FalconVerifier::verify_512(pubkey, message, &signature),
+            FALCON_1024_PUBKEY_SIZE => FalconVerifier::verify_1024(pubkey, message, &signature),
+            _ => false,
+        }
+    }
+}
@@ -2,26 +2,107 @@
 
 //! # Falcon-512 Signature Verifier for Soroban
 
-use soroban_sdk::{contract, contractimpl, Bytes, Env};
+use sha3::{Digest, Sha3_256};
+use soroban_sdk::{contract, contractimpl, symbol_short, Bytes, BytesN, Env, Symbol, Vec};
 
+#[cfg(any(feature = "std", feature = "testutils"))]
+extern crate std;
+
+#[cfg(feature = "research")]
+mod batch;
+#[cfg(feature = "cabi")]
+mod cabi;
+#[cfg(feature = "std")]
+mod cose;
+#[cfg(feature = "std")]
+mod kat;
+mod merkle;
 mod ntt;
 mod verify;
+#[cfg(feature = "std")]
+mod witness;
+
+#[cfg(feature = "debug")]
+pub use verify::NormDiagnostic;
+#[cfg(feature = "testutils")]
+pub use verify::VerifyCost;
+pub use verify::{
+    falcon_l2_bound, sig_size, FalconSignature, FalconVerifier, FalconVerifyContext, HashMode,
+    Packing, SigFormat, VerifyError, VerifyOutcome, VerifyState,
+};
 
-pub use verify::FalconVerifier;
+#[cfg(feature = "research")]
+pub use batch::BatchItem;
+#[cfg(feature = "cabi")]
+pub use cabi::falcon512_verify;
+#[cfg(feature = "std")]
+pub use kat::{KatFailure, KatFailureReason, KatReport};
 
 pub const FALCON_512_LOGN: u32 = 9;
 pub const FALCON_512_N: usize = 512;
 pub const FALCON_512_PUBKEY_SIZE: usize = 897;
+// Header byte plus 512 coefficients packed at 14 bits each
+const _: () = assert!(FALCON_512_PUBKEY_SIZE == 1 + (FALCON_512_N * 14).div_ceil(8));
+/// Length of the signature nonce, the same for Falcon-512 and Falcon-1024
+pub const NONCE_LEN: usize = 40;
+/// Smallest Falcon-512 signature: header, nonce and a compressed body of at
+/// least 9 bits (sign, 7 low bits, unary stop bit) per coefficient
+pub const FALCON_SIG_MIN_SIZE: u32 = 1 + NONCE_LEN as u32 + (FALCON_512_N as u32 * 9).div_ceil(8);
+/// Largest Falcon-512 signature, in the constant-time format
+pub const FALCON_SIG_MAX_SIZE: u32 =
+    sig_size(SigFormat::ConstantTime, FALCON_512_LOGN).unwrap() as u32;
+// Largest body `verify_typed` accepts: the constant-time encoding, without
+// header and nonce
+const MAX_TYPED_BODY_LEN: usize = FALCON_SIG_MAX_SIZE as usize - 1 - NONCE_LEN;
 /// The prime modulus
 pub const Q: u32 = 12289;
 /// Squared L2 norm bound for Falcon-512 signatures.
-pub const L2_BOUND_512: u32 = 34034726;
+pub const L2_BOUND_512: u32 = falcon_l2_bound(FALCON_512_LOGN).unwrap() as u32;
+
+pub const FALCON_1024_LOGN: u32 = 10;
+pub const FALCON_1024_N: usize = 1024;
+pub const FALCON_1024_PUBKEY_SIZE: usize = 1793;
+// Header byte plus 1024 coefficients packed at 14 bits each
+const _: () = assert!(FALCON_1024_PUBKEY_SIZE == 1 + (FALCON_1024_N * 14).div_ceil(8));
+/// Squared L2 norm bound for Falcon-1024 signatures.
+pub const L2_BOUND_1024: u32 = falcon_l2_bound(FALCON_1024_LOGN).unwrap() as u32;
+
+/// Ledgers a verification record stays live after it is written (about 30
+/// days at 5 seconds per ledger).
+pub const RECORD_TTL_LEDGERS: u32 = 518_400;
+// Re-recording only extends a record whose TTL has dropped by a day or more
+const RECORD_TTL_THRESHOLD: u32 = RECORD_TTL_LEDGERS - 17_280;
+// Persistent storage namespace for verification records
+const RECORD_PREFIX: Symbol = symbol_short!("VERIFIED");
+
+/// Ledgers a memoized verification result stays in temporary storage (about
+/// 5 minutes at 5 seconds per ledger).
+pub const MEMO_TTL_LEDGERS: u32 = 60;
+// Temporary storage namespace for memoized results
+const MEMO_PREFIX: Symbol = symbol_short!("MEMO");
+// Instance storage key of the `verify` counters, present only when counting
+const STATS_KEY: Symbol = symbol_short!("STATS");
 
 #[contract]
 pub struct FalconVerifierContract;
 
 #[contractimpl]
 impl FalconVerifierContract {
+    /// Constructor - sets whether [`verify`](Self::verify) keeps usage
+    /// counters.
+    ///
+    /// # Arguments
+    /// * `count_stats` - If `true`, every `verify` call updates the counters
+    ///   returned by [`stats`](Self::stats), one instance storage write per
+    ///   call. If `false`, `verify` writes nothing. Fixed at deployment.
+    pub fn __constructor(env: Env, count_stats: bool) {
+        if count_stats {
+            env.storage()
+                .instance()
+                .set(&STATS_KEY, &(0u64, 0u64, 0u64));
+        }
+    }
+
     /// Verify a Falcon-512 signature (compressed format).
     ///
     /// # Arguments
@@ -31,38 +112,388 @@ impl FalconVerifierContract {
     ///
     /// # Returns
     /// * `true` if signature is valid, `false` otherwise
-    pub fn verify(_env: Env, public_key: Bytes, message: Bytes, signature: Bytes) -> bool {
+    pub fn verify(env: Env, public_key: Bytes, message: Bytes, signature: Bytes) -> bool {
+        let valid = verify_contract_args(&public_key, &message, &signature);
+
+        let storage = env.storage().instance();
+        if let Some((total, successes, failures)) = storage.get::<_, (u64, u64, u64)>(&STATS_KEY) {
+            let stats = if valid {
+                (total + 1, successes + 1, failures)
+            } else {
+                (total + 1, successes, failures + 1)
+            };
+            storage.set(&STATS_KEY, &stats);
+        }
+        valid
+    }
+
+    /// Usage counters of [`verify`](Self::verify): `(total, successes,
+    /// failures)`.
+    ///
+    /// All zero if the contract was deployed without `count_stats`. Only
+    /// `verify` is counted, not the other entry points.
+    pub fn stats(env: Env) -> (u64, u64, u64) {
+        env.storage()
+            .instance()
+            .get(&STATS_KEY)
+            .unwrap_or((0, 0, 0))
+    }
+
+    /// Verify a Falcon-512 signature over a message split into segments.
+    ///
+    /// The signed message is the concatenation of `segments` in order, for
+    /// structured payloads assembled from several pieces. Each segment is
+    /// hashed directly from the host, so there is no cap on the total length.
+    ///
+    /// # Arguments
+    /// * `public_key` - 897-byte Falcon-512 public key
+    /// * `segments` - Pieces of the signed message, in order
+    /// * `signature` - Falcon signature (any supported format)
+    ///
+    /// # Returns
+    /// * `true` if signature is valid, `false` otherwise
+    pub fn verify_segments(
+        _env: Env,
+        public_key: Bytes,
+        segments: Vec<Bytes>,
+        signature: Bytes,
+    ) -> bool {
+        FalconVerifier::verify_512_segments(&public_key, &segments, &signature)
+    }
+
+    /// Verify a Falcon-512 signature passed as a separate nonce and body.
+    ///
+    /// Equivalent to [`verify`](Self::verify) on `header || nonce || body`,
+    /// but the 40-byte nonce length is enforced by the argument type rather
+    /// than at run time. Bodies up to the constant-time size are accepted:
+    /// [`FALCON_SIG_MAX_SIZE`] less the header byte and the nonce.
+    ///
+    /// # Arguments
+    /// * `public_key` - 897-byte Falcon-512 public key
+    /// * `message` - Message that was signed (only the first 4096 bytes are used)
+    /// * `nonce` - The 40-byte signature nonce
+    /// * `body` - The encoded s2 polynomial that follows the nonce
+    /// * `format` - Header format nibble: `0x30` compressed, `0x20` padded, `0x50` CT
+    ///
+    /// # Returns
+    /// * `true` if signature is valid, `false` otherwise
+    pub fn verify_typed(
+        _env: Env,
+        public_key: Bytes,
+        message: Bytes,
+        nonce: BytesN<40>,
+        body: Bytes,
+        format: u32,
+    ) -> bool {
+        let format = match u8::try_from(format) {
+            Ok(nibble) => match SigFormat::from_header(nibble) {
+                Some(f) if f.header_nibble() == nibble => f,
+                _ => return false,
+            },
+            Err(_) => return false,
+        };
         if public_key.len() != FALCON_512_PUBKEY_SIZE as u32 {
             return false;
         }
-        if signature.len() < 42 || signature.len() > 700 {
+        let body_len = body.len() as usize;
+        if body_len > MAX_TYPED_BODY_LEN {
             return false;
         }
 
         let mut pk_bytes = [0u8; FALCON_512_PUBKEY_SIZE];
-        for i in 0..FALCON_512_PUBKEY_SIZE {
-            pk_bytes[i] = public_key.get(i as u32).unwrap();
-        }
+        public_key.copy_into_slice(&mut pk_bytes);
 
-        let sig_len = signature.len() as usize;
-        let mut sig_bytes = [0u8; 700];
-        for i in 0..sig_len {
-            sig_bytes[i] = signature.get(i as u32).unwrap();
-        }
+        // Standard encoding: header || nonce || body
+        let mut sig_bytes = [0u8; FALCON_SIG_MAX_SIZE as usize];
+        sig_bytes[0] = format.header_nibble() | FALCON_512_LOGN as u8;
+        let body_start = 1 + NONCE_LEN;
+        sig_bytes[1..body_start].copy_from_slice(&nonce.to_array());
+        body.copy_into_slice(&mut sig_bytes[body_start..body_start + body_len]);
 
-        let msg_len = message.len() as usize;
+        let msg_len = message.len().min(4096);
         let mut msg_bytes = [0u8; 4096];
-        let actual_msg_len = if msg_len > 4096 { 4096 } else { msg_len };
-        for i in 0..actual_msg_len {
-            msg_bytes[i] = message.get(i as u32).unwrap();
-        }
+        message
+            .slice(..msg_len)
+            .copy_into_slice(&mut msg_bytes[..msg_len as usize]);
 
         FalconVerifier::verify_512(
             &pk_bytes,
-            &msg_bytes[..actual_msg_len],
-            &sig_bytes[..sig_len],
+            &msg_bytes[..msg_len as usize],
+            &sig_bytes[..body_start + body_len],
         )
     }
+
+    /// Key under which [`verify_and_record`](Self::verify_and_record) stores
+    /// the result for `public_key` and `message`: SHA-256 of `public_key || message`.
+    pub fn record_key(env: Env, public_key: Bytes, message: Bytes) -> BytesN<32> {
+        let mut data = public_key;
+        data.append(&message);
+        env.crypto().sha256(&data).to_bytes()
+    }
+
+    /// Verify a Falcon-512 signature and, if it is valid, record that fact
+    /// on-chain so other contracts can check it with
+    /// [`was_verified`](Self::was_verified) instead of verifying again.
+    ///
+    /// `key` must equal [`record_key`](Self::record_key) for the public key
+    /// and message, so a record can only ever be created for the message it
+    /// names. The whole message is hashed, with no length cap. The record
+    /// lives for [`RECORD_TTL_LEDGERS`] ledgers; recording it again extends it.
+    ///
+    /// # Returns
+    /// * `true` if the signature is valid and the record was written
+    /// * `false` if the signature is invalid or `key` does not match
+    pub fn verify_and_record(
+        env: Env,
+        public_key: Bytes,
+        message: Bytes,
+        signature: Bytes,
+        key: BytesN<32>,
+    ) -> bool {
+        if key != Self::record_key(env.clone(), public_key.clone(), message.clone()) {
+            return false;
+        }
+        if !FalconVerifier::verify_512_streaming(&public_key, &message, &signature) {
+            return false;
+        }
+
+        let storage = env.storage().persistent();
+        let record = (RECORD_PREFIX, key);
+        storage.set(&record, &true);
+        storage.extend_ttl(&record, RECORD_TTL_THRESHOLD, RECORD_TTL_LEDGERS);
+        true
+    }
+
+    /// Whether a signature was recorded as verified under `key`.
+    ///
+    /// Returns `false` for keys that were never recorded. A record that was
+    /// not re-recorded within [`RECORD_TTL_LEDGERS`] is archived by the
+    /// network and must be restored before it can be read again.
+    pub fn was_verified(env: Env, key: BytesN<32>) -> bool {
+        env.storage()
+            .persistent()
+            .get(&(RECORD_PREFIX, key))
+            .unwrap_or(false)
+    }
+}
+
+impl FalconVerifier {
+    /// Verifies a Falcon-512 signature, reusing the result of an earlier call
+    /// on the same inputs while it is cached.
+    ///
+    /// The cache key is SHA3-256 over the length-prefixed public key, message
+    /// and signature. The result, valid or not, is kept in the calling
+    /// contract's temporary storage for [`MEMO_TTL_LEDGERS`] ledgers, so this
+    /// must run inside a contract. A hit still hashes every input but skips
+    /// decoding, the NTTs and the norm check.
+    ///
+    /// # Returns
+    /// `true` if the signature is valid, `false` otherwise.
+    pub fn verify_512_memo(env: &Env, pubkey: &Bytes, message: &Bytes, signature: &Bytes) -> bool {
+        let mut hasher = Sha3_256::new();
+        let mut chunk = [0u8; 256];
+        for input in [pubkey, message, signature] {
+            hasher.update(input.len().to_be_bytes());
+            let mut start = 0;
+            while start < input.len() {
+                let end = input.len().min(start + chunk.len() as u32);
+                let part = &mut chunk[..(end - start) as usize];
+                input.slice(start..end).copy_into_slice(part);
+                hasher.update(&*part);
+                start = end;
+            }
+        }
+        let key = (
+            MEMO_PREFIX,
+            BytesN::from_array(env, &hasher.finalize().into()),
+        );
+
+        let storage = env.storage().temporary();
+        if let Some(valid) = storage.get(&key) {
+            return valid;
+        }
+        let valid = Self::verify_512_streaming(pubkey, message, signature);
+        storage.set(&key, &valid);
+        storage.extend_ttl(&key, MEMO_TTL_LEDGERS, MEMO_TTL_LEDGERS);
+        valid
+    }
+}
+
+#[cfg(feature = "std")]
+impl FalconVerifier {
+    /// Verify a Falcon-512 signature given hex-encoded public key and signature.
+    ///
+    /// Off-chain convenience for pre-validating a signature before it is
+    /// submitted in a transaction. Inputs are normalized as copy-pasted hex
+    /// often needs: whitespace anywhere, including line breaks, and an
+    /// optional `0x` prefix are ignored. Any remaining hex decoding error
+    /// yields `false`. Only available with the `std` feature.
+    ///
+    /// # Example
+    /// ```
+    /// use soroban_falcon_verifier::FalconVerifier;
+    ///
+    /// let pubkey_hex = include_str!("../tests/fixtures/test_pubkey.hex");
+    /// let signature_hex = include_str!("../tests/fixtures/test_signature.hex");
+    ///
+    /// assert!(FalconVerifier::verify_512_hex(pubkey_hex, b"Hello, Falcon!", signature_hex));
+    /// assert!(!FalconVerifier::verify_512_hex(pubkey_hex, b"Wrong message", signature_hex));
+    /// ```
+    ///
+    /// Malformed hex is rejected rather than reported as an error:
+    /// ```
+    /// use soroban_falcon_verifier::FalconVerifier;
+    ///
+    /// assert!(!FalconVerifier::verify_512_hex("not hex", b"msg", "zz"));
+    /// ```
+    pub fn verify_512_hex(pubkey_hex: &str, message: &[u8], signature_hex: &str) -> bool {
+        let (Some(pubkey), Some(signature)) = (decode_hex(pubkey_hex), decode_hex(signature_hex))
+        else {
+            return false;
+        };
+        FalconVerifier::verify_512(&pubkey, message, &signature)
+    }
+
+    /// The exact bytes SHAKE256 absorbs to derive the challenge for
+    /// [`hash_to_point`](Self::hash_to_point): `nonce || message`.
+    ///
+    /// For debugging a signer that disagrees with the verifier: hash these
+    /// bytes on the signer's side and compare. Only available with the `std`
+    /// feature.
+    pub fn absorbed_input(nonce: &[u8], message: &[u8]) -> std::vec::Vec<u8> {
+        [nonce, message].concat()
+    }
+}
+
+/// The checks behind [`FalconVerifierContract::verify`]: fixed-size copies
+/// of the arguments, with the message cut at 4096 bytes.
+fn verify_contract_args(public_key: &Bytes, message: &Bytes, signature: &Bytes) -> bool {
+    if public_key.len() != FALCON_512_PUBKEY_SIZE as u32 {
+        return false;
+    }
+    if signature.len() < FALCON_SIG_MIN_SIZE || signature.len() > FALCON_SIG_MAX_SIZE {
+        return false;
+    }
+
+    let mut pk_bytes = [0u8; FALCON_512_PUBKEY_SIZE];
+    for i in 0..FALCON_512_PUBKEY_SIZE {
+        pk_bytes[i] = public_key.get(i as u32).unwrap();
+    }
+
+    let sig_len = signature.len() as usize;
+    let mut sig_bytes = [0u8; FALCON_SIG_MAX_SIZE as usize];
+    for i in 0..sig_len {
+        sig_bytes[i] = signature.get(i as u32).unwrap();
+    }
+
+    let msg_len = message.len() as usize;
+    let mut msg_bytes = [0u8; 4096];
+    let actual_msg_len = if msg_len > 4096 { 4096 } else { msg_len };
+    for i in 0..actual_msg_len {
+        msg_bytes[i] = message.get(i as u32).unwrap();
+    }
+
+    FalconVerifier::verify_512(
+        &pk_bytes,
+        &msg_bytes[..actual_msg_len],
+        &sig_bytes[..sig_len],
+    )
+}
+
+/// Decodes hand-pasted hex, ignoring whitespace anywhere and an optional
+/// `0x` or `0X` prefix.
+#[cfg(feature = "std")]
+fn decode_hex(input: &str) -> Option<std::vec::Vec<u8>> {
+    let digits: std::string::String = input.chars().filter(|c| !c.is_whitespace()).collect();
+    let digits = digits
+        .strip_prefix("0x")
+        .or_else(|| digits.strip_prefix("0X"))
+        .unwrap_or(&digits);
+    hex::decode(digits).ok()
+}
+
+#[cfg(any(feature = "std", feature = "testutils"))]
+impl FalconVerifier {
+    /// Builds the Falcon-512 signature bytes `header || nonce || body` that
+    /// [`verify_512`](Self::verify_512) expects.
+    ///
+    /// The header is the format nibble with logn = 9. `body` is used as
+    /// given, so a padded signature must already carry its zero padding.
+    /// The inverse of [`FalconSignature::from_bytes`] for tooling and tests
+    /// that hold the parts separately. Only available with the `std` or
+    /// `testutils` feature.
+    ///
+    /// # Example
+    /// ```
+    /// use soroban_falcon_verifier::{FalconSignature, FalconVerifier, SigFormat};
+    ///
+    /// let hex = include_str!("../tests/fixtures/test_signature.hex");
+    /// let sig = hex::decode(hex.trim()).unwrap();
+    /// let nonce: [u8; 40] = sig[1..41].try_into().unwrap();
+    ///
+    /// let parsed = FalconSignature::from_bytes(&sig).unwrap();
+    /// let assembled = FalconVerifier::assemble_signature(parsed.format(), &nonce, &sig[41..]);
+    /// assert_eq!(assembled, sig);
+    /// ```
+    pub fn assemble_signature(
+        format: SigFormat,
+        nonce: &[u8; NONCE_LEN],
+        body: &[u8],
+    ) -> std::vec::Vec<u8> {
+        let mut signature = std::vec::Vec::with_capacity(1 + NONCE_LEN + body.len());
+        signature.push(format.header_nibble() | FALCON_512_LOGN as u8);
+        signature.extend_from_slice(nonce);
+        signature.extend_from_slice(body);
+        signature
+    }
+}
+
+/// Cost model for [`FalconVerifierContract::verify`], fit to the release
+/// `wasm32v1-none` build by `benchmark_estimate_budget_matches_measured`.
+///
+/// The contract reads its arguments one byte per host call and the message
+/// copy stops at 4096 bytes. Each copied byte costs about 4,100 CPU
+/// instructions including hashing it, which dwarfs the differences between
+/// signature decoders; everything else, VM instantiation and the NTTs
+/// included, is a constant.
+#[cfg(feature = "testutils")]
+mod cost_model {
+    /// CPU charged apart from the copied bytes.
+    pub const BASE_CPU: u64 = 9_757_337;
+    /// CPU charged when the public key size check rejects the call.
+    pub const REJECT_CPU: u64 = 382_596;
+    /// CPU per byte read from the public key, signature or message.
+    pub const PER_BYTE_CPU: u64 = 4_117;
+    /// Memory charged per call, almost all of it VM instantiation; the
+    /// copies use fixed stack buffers.
+    pub const MEM_BYTES: u64 = 1_348_697;
+    /// Longest message prefix the contract copies.
+    pub const MSG_CAP: u64 = 4096;
+}
+
+#[cfg(feature = "testutils")]
+impl FalconVerifier {
+    /// Predict the `(cpu_instructions, memory_bytes)` charged by the
+    /// deployed contract's `verify` call, without running it.
+    ///
+    /// The model is linear in the bytes copied from the arguments and was fit
+    /// to the Wasm contract, so it includes the guest-side arithmetic; it
+    /// stays within 5% of measured runs. Compressed signatures are assumed
+    /// padded to 666 bytes, an upper bound in practice. `env` is the
+    /// environment the call would run in; the model does not depend on it.
+    pub fn estimate_budget(_env: &Env, pk: &Bytes, msg_len: u32, format: SigFormat) -> (u64, u64) {
+        use cost_model::*;
+
+        if pk.len() as usize != FALCON_512_PUBKEY_SIZE {
+            return (REJECT_CPU, MEM_BYTES);
+        }
+
+        let sig_len = sig_size(format, FALCON_512_LOGN)
+            .or(sig_size(SigFormat::Padded, FALCON_512_LOGN))
+            .unwrap() as u64;
+        let copied = pk.len() as u64 + sig_len + (msg_len as u64).min(MSG_CAP);
+        (BASE_CPU + PER_BYTE_CPU * copied, MEM_BYTES)
+    }
 }
 
 #[cfg(test)]
@@ -73,7 +504,7 @@ mod test {
     #[test]
     fn test_contract_compiles() {
         let env = Env::default();
-        let contract_id = env.register(FalconVerifierContract, ());
+        let contract_id = env.register(FalconVerifierContract, (false,));
         let _client = FalconVerifierContractClient::new(&env, &contract_id);
     }
 }
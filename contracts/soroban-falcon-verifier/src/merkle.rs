@@ -0,0 +1,71 @@
+//! One Falcon-512 signature over a Merkle root, authorizing every leaf of the
+//! tree, e.g. a batch of operations checked one at a time on-chain.
+//!
+//! The tree hashes with SHA3-256 and separates leaves from inner nodes as in
+//! RFC 6962, so an inner node can never be passed off as a leaf:
+//!
+//! ```text
+//! leaf hash = SHA3-256(0x00 || leaf)
+//! node hash = SHA3-256(0x01 || left || right)
+//! ```
+//!
+//! An inclusion proof is the path from the leaf up to the root, one 33-byte
+//! step per level: a side byte, `0x00` if the sibling is on the right and
+//! `0x01` if it is on the left, then the sibling's 32-byte hash. A tree with a
+//! single leaf has an empty proof and the leaf hash as its root.
+
+use sha3::{Digest, Sha3_256};
+
+use crate::FalconVerifier;
+
+const LEAF_PREFIX: u8 = 0x00;
+const NODE_PREFIX: u8 = 0x01;
+/// Side byte plus sibling hash.
+const PROOF_STEP_LEN: usize = 1 + 32;
+
+/// Whether `proof` leads from `leaf` to `root`.
+fn is_included(leaf: &[u8], proof: &[u8], root: &[u8; 32]) -> bool {
+    let steps = proof.chunks_exact(PROOF_STEP_LEN);
+    if !steps.remainder().is_empty() {
+        return false;
+    }
+    let mut node: [u8; 32] = Sha3_256::new()
+        .chain_update([LEAF_PREFIX])
+        .chain_update(leaf)
+        .finalize()
+        .into();
+    for step in steps {
+        let (side, sibling) = (step[0], &step[1..]);
+        let hasher = Sha3_256::new().chain_update([NODE_PREFIX]);
+        let hasher = match side {
+            0x00 => hasher.chain_update(node).chain_update(sibling),
+            0x01 => hasher.chain_update(sibling).chain_update(node),
+            _ => return false,
+        };
+        node = hasher.finalize().into();
+    }
+    node == *root
+}
+
+impl FalconVerifier {
+    /// Verifies that `leaf` is committed to by a Merkle `root` carrying a
+    /// valid Falcon-512 signature.
+    ///
+    /// The signature must be over the 32 root bytes, as for
+    /// [`verify_512`](Self::verify_512), and `proof` must be an inclusion
+    /// proof for `leaf` in the format of the module documentation. The
+    /// inclusion proof is checked first, since it costs a few hashes against
+    /// a full verification.
+    ///
+    /// # Returns
+    /// `true` only if both the signature and the inclusion proof hold.
+    pub fn verify_512_merkle(
+        pubkey: &[u8],
+        leaf: &[u8],
+        proof: &[u8],
+        root: &[u8; 32],
+        signature: &[u8],
+    ) -> bool {
+        is_included(leaf, proof, root) && Self::verify_512(pubkey, root, signature)
+    }
+}
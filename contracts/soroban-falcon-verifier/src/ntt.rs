@@ -1,6 +1,8 @@
-//! Number Theoretic Transform (NTT) for Falcon-512 Verification
+//! Number Theoretic Transform (NTT) for Falcon Verification
 //!
-use crate::{FALCON_512_N, Q};
+//! The transforms and polynomial helpers are generic over the degree N
+//! (512 or 1024); the twiddle tables cover the largest supported degree.
+use crate::Q;
 
 /// Montgomery reduction constant
 const Q0I: u32 = 12287;
@@ -9,8 +11,42 @@ const R: u32 = 4091;
 /// Montgomery conversion constant
 const R2: u32 = 10952;
 
-/// Forward NTT twiddle factors in Montgomery form.
-pub static GMB: [u16; 512] = [
+// R = 2^16 mod q, R2 = 2^32 mod q, and Q0I = -q^-1 mod 2^16
+const _: () = assert!(R == mod_pow(2, 16, Q));
+const _: () = assert!(R2 == mod_pow(2, 32, Q));
+const _: () = assert!((Q * Q0I) & 0xFFFF == 0xFFFF);
+// g = 7 and its inverse (used for IGMB) have order exactly 2048: g^1024 = -1
+const _: () = assert!(mod_pow(7, 1024, Q) == Q - 1);
+const _: () = assert!(mod_pow(mod_inv(7, Q), 1024, Q) == Q - 1);
+
+/// Computes base^exp mod m by square-and-multiply. `m` must be below 2^16.
+pub(crate) const fn mod_pow(base: u32, exp: u32, m: u32) -> u32 {
+    let mut result = 1 % m;
+    let mut b = base % m;
+    let mut e = exp;
+    while e > 0 {
+        if e & 1 == 1 {
+            result = result * b % m;
+        }
+        b = b * b % m;
+        e >>= 1;
+    }
+    result
+}
+
+/// Computes a^-1 mod q for prime q via Fermat's little theorem.
+///
+/// Returns 0 when a ≡ 0 mod q, which has no inverse.
+pub(crate) const fn mod_inv(a: u32, q: u32) -> u32 {
+    mod_pow(a, q - 2, q)
+}
+
+/// Forward NTT twiddle factors in Montgomery form, for degrees up to 1024.
+///
+/// Entry i is R·g^rev(i) mod q with g = 7 a primitive 2048th root of unity
+/// and rev the 10-bit bit reversal. A degree-n transform only reads the
+/// first n entries, so Falcon-512 and Falcon-1024 share this table.
+pub static GMB: [u16; 1024] = [
     4091, 7888, 11060, 11208, 6960, 4342, 6275, 9759, 1591, 6399, 9477, 5266, 586, 5825, 7538,
     9710, 1134, 6407, 1711, 965, 7099, 7674, 3743, 6442, 10414, 8100, 1885, 1688, 1364, 10329,
     10164, 9180, 12210, 6240, 997, 117, 4783, 4407, 1549, 7072, 2829, 6458, 4431, 8877, 7144, 2564,
@@ -44,11 +80,46 @@ pub static GMB: [u16; 512] = [
     10213, 12184, 7827, 11903, 5600, 9779, 1012, 721, 2784, 6676, 6552, 5348, 4424, 6816, 8405,
     9959, 5150, 2356, 5552, 5267, 1333, 8801, 9661, 7308, 5788, 4910, 909, 11613, 4395, 8238, 6686,
     4302, 3044, 2285, 12249, 1963, 9216, 4296, 11918, 695, 4371, 9793, 4884, 2411, 10230, 2650,
-    841, 3890, 10231, 7248, 8505, 11196, 6688,
+    841, 3890, 10231, 7248, 8505, 11196, 6688, 4059, 6060, 3686, 4722, 11853, 5816, 7058, 6868,
+    11137, 7926, 4894, 12284, 4102, 3908, 3610, 6525, 7938, 7982, 11977, 6755, 537, 4562, 1623,
+    8227, 11453, 7544, 906, 11816, 9548, 10858, 9703, 2815, 11736, 6813, 6979, 819, 8903, 6271,
+    10843, 348, 7514, 8339, 6439, 694, 852, 5659, 2781, 3716, 11589, 3024, 1523, 8659, 4114, 10738,
+    3303, 5885, 2978, 7289, 11884, 9123, 9323, 11830, 98, 2526, 2116, 4131, 11407, 1844, 3645,
+    3916, 8133, 2224, 10871, 8092, 9651, 5989, 7140, 8480, 1670, 159, 10923, 4918, 128, 7312, 725,
+    9157, 5006, 6393, 3494, 6043, 10972, 6181, 11838, 3423, 10514, 7668, 3693, 6658, 6905, 11953,
+    10212, 11922, 9101, 8365, 5110, 45, 2400, 1921, 4377, 2720, 1695, 51, 2808, 650, 1896, 9997,
+    9971, 11980, 8098, 4833, 4135, 4257, 5838, 4765, 10985, 11532, 590, 12198, 482, 12173, 2006,
+    7064, 10018, 3912, 12016, 10519, 11362, 6954, 2210, 284, 5413, 6601, 3865, 10339, 11188, 6231,
+    517, 9564, 11281, 3863, 1210, 4604, 8160, 11447, 153, 7204, 5763, 5089, 9248, 12154, 11748,
+    1354, 6672, 179, 5532, 2646, 5941, 12185, 862, 3158, 477, 7279, 5678, 7914, 4254, 302, 2893,
+    10114, 6890, 9560, 9647, 11905, 4098, 9824, 10269, 1353, 10715, 5325, 6254, 3951, 1807, 6449,
+    5159, 1308, 8315, 3404, 1877, 1231, 112, 6398, 11724, 12272, 7286, 1459, 12274, 9896, 3456,
+    800, 1397, 10678, 103, 7420, 7976, 936, 764, 632, 7996, 8223, 8445, 7758, 10870, 9571, 2508,
+    1946, 6524, 10158, 1044, 4338, 2457, 3641, 1659, 4139, 4688, 9733, 11148, 3946, 2082, 5261,
+    2036, 11850, 7636, 12236, 5366, 2380, 1399, 7720, 2100, 3217, 10912, 8898, 7578, 11995, 2791,
+    1215, 3355, 2711, 2267, 2004, 8568, 10176, 3214, 2337, 1750, 4729, 4997, 7415, 6315, 12044,
+    4374, 7157, 4844, 211, 8003, 10159, 9290, 11481, 1735, 2336, 5793, 9875, 8192, 986, 7527, 1401,
+    870, 3615, 8465, 2756, 9770, 2034, 10168, 3264, 6132, 54, 2880, 4763, 11805, 3074, 8286, 9428,
+    4881, 6933, 1090, 10038, 2567, 708, 893, 6465, 4962, 10024, 2090, 5718, 10743, 780, 4733, 4623,
+    2134, 2087, 4802, 884, 5372, 5795, 5938, 4333, 6559, 7549, 5269, 10664, 4252, 3260, 5917,
+    10814, 5768, 9983, 8096, 7791, 6800, 7491, 6272, 1907, 10947, 6289, 11803, 6032, 11449, 1171,
+    9201, 7933, 2479, 7970, 11337, 7062, 8911, 6728, 6542, 8114, 8828, 6595, 3545, 4348, 4610,
+    2205, 6999, 8106, 5560, 10390, 9321, 2499, 2413, 7272, 6881, 10582, 9308, 9437, 3554, 3326,
+    5991, 11969, 3415, 12283, 9838, 12063, 4332, 7830, 11329, 6605, 12271, 2044, 11611, 7353,
+    11201, 11582, 3733, 8943, 9978, 1627, 7168, 3935, 5050, 2762, 7496, 10383, 755, 1654, 12053,
+    4952, 10134, 4394, 6592, 7898, 7497, 8904, 12029, 3581, 10748, 5674, 10358, 4901, 7414, 8771,
+    710, 6764, 8462, 7193, 5371, 7274, 11084, 290, 7864, 6827, 11822, 2509, 6578, 4026, 5807, 1458,
+    5721, 5762, 4178, 2105, 11621, 4852, 8897, 2856, 11510, 9264, 2520, 8776, 7011, 2647, 1898,
+    7039, 5950, 11163, 5488, 6277, 9182, 11456, 633, 10046, 11554, 5633, 9587, 2333, 7008, 7084,
+    5047, 7199, 9865, 8997, 569, 6390, 10845, 9679, 8268, 11472, 4203, 1997, 2, 9331, 162, 6182,
+    2000, 3649, 9792, 6363, 7557, 6187, 8510, 9935, 5536, 9019, 3706, 12009, 1452, 3067, 5494,
+    9692, 4865, 6019, 7106, 9610, 4588, 10165, 6261, 5887, 2652, 10172, 1580, 10379, 4638, 9949,
 ];
 
-/// Inverse NTT twiddle factors in Montgomery form.
-pub static IGMB: [u16; 512] = [
+/// Inverse NTT twiddle factors in Montgomery form, for degrees up to 1024.
+///
+/// Entry i is R·g^-rev(i) mod q, laid out like [`GMB`].
+pub static IGMB: [u16; 1024] = [
     4091, 4401, 1081, 1229, 2530, 6014, 7947, 5329, 2579, 4751, 6464, 11703, 7023, 2812, 5890,
     10698, 3109, 2125, 1960, 10925, 10601, 10404, 4189, 1875, 5847, 8546, 4615, 5190, 11324, 10578,
     5882, 11155, 8417, 12275, 10599, 7446, 5719, 3569, 5981, 10108, 4426, 8306, 10755, 4679, 11052,
@@ -82,7 +153,40 @@ pub static IGMB: [u16; 512] = [
     10857, 8069, 832, 1628, 3410, 4900, 10855, 5111, 9543, 6325, 7431, 4083, 3072, 8847, 9853,
     10122, 5259, 11413, 6556, 303, 1465, 3871, 4873, 5813, 10017, 6898, 3311, 5947, 8637, 5852,
     3856, 928, 4933, 8530, 1871, 2184, 5571, 5879, 3481, 11597, 9511, 8153, 35, 2609, 5963, 8064,
-    1080, 12039, 8444, 3052, 3813, 11065, 6736, 8454,
+    1080, 12039, 8444, 3052, 3813, 11065, 6736, 8454, 2340, 7651, 1910, 10709, 2117, 9637, 6402,
+    6028, 2124, 7701, 2679, 5183, 6270, 7424, 2597, 6795, 9222, 10837, 280, 8583, 3270, 6753, 2354,
+    3779, 6102, 4732, 5926, 2497, 8640, 10289, 6107, 12127, 2958, 12287, 10292, 8086, 817, 4021,
+    2610, 1444, 5899, 11720, 3292, 2424, 5090, 7242, 5205, 5281, 9956, 2702, 6656, 735, 2243,
+    11656, 833, 3107, 6012, 6801, 1126, 6339, 5250, 10391, 9642, 5278, 3513, 9769, 3025, 779, 9433,
+    3392, 7437, 668, 10184, 8111, 6527, 6568, 10831, 6482, 8263, 5711, 9780, 467, 5462, 4425,
+    11999, 1205, 5015, 6918, 5096, 3827, 5525, 11579, 3518, 4875, 7388, 1931, 6615, 1541, 8708,
+    260, 3385, 4792, 4391, 5697, 7895, 2155, 7337, 236, 10635, 11534, 1906, 4793, 9527, 7239, 8354,
+    5121, 10662, 2311, 3346, 8556, 707, 1088, 4936, 678, 10245, 18, 5684, 960, 4459, 7957, 226,
+    2451, 6, 8874, 320, 6298, 8963, 8735, 2852, 2981, 1707, 5408, 5017, 9876, 9790, 2968, 1899,
+    6729, 4183, 5290, 10084, 7679, 7941, 8744, 5694, 3461, 4175, 5747, 5561, 3378, 5227, 952, 4319,
+    9810, 4356, 3088, 11118, 840, 6257, 486, 6000, 1342, 10382, 6017, 4798, 5489, 4498, 4193, 2306,
+    6521, 1475, 6372, 9029, 8037, 1625, 7020, 4740, 5730, 7956, 6351, 6494, 6917, 11405, 7487,
+    10202, 10155, 7666, 7556, 11509, 1546, 6571, 10199, 2265, 7327, 5824, 11396, 11581, 9722, 2251,
+    11199, 5356, 7408, 2861, 4003, 9215, 484, 7526, 9409, 12235, 6157, 9025, 2121, 10255, 2519,
+    9533, 3824, 8674, 11419, 10888, 4762, 11303, 4097, 2414, 6496, 9953, 10554, 808, 2999, 2130,
+    4286, 12078, 7445, 5132, 7915, 245, 5974, 4874, 7292, 7560, 10539, 9952, 9075, 2113, 3721,
+    10285, 10022, 9578, 8934, 11074, 9498, 294, 4711, 3391, 1377, 9072, 10189, 4569, 10890, 9909,
+    6923, 53, 4653, 439, 10253, 7028, 10207, 8343, 1141, 2556, 7601, 8150, 10630, 8648, 9832, 7951,
+    11245, 2131, 5765, 10343, 9781, 2718, 1419, 4531, 3844, 4066, 4293, 11657, 11525, 11353, 4313,
+    4869, 12186, 1611, 10892, 11489, 8833, 2393, 15, 10830, 5003, 17, 565, 5891, 12177, 11058,
+    10412, 8885, 3974, 10981, 7130, 5840, 10482, 8338, 6035, 6964, 1574, 10936, 2020, 2465, 8191,
+    384, 2642, 2729, 5399, 2175, 9396, 11987, 8035, 4375, 6611, 5010, 11812, 9131, 11427, 104,
+    6348, 9643, 6757, 12110, 5617, 10935, 541, 135, 3041, 7200, 6526, 5085, 12136, 842, 4129, 7685,
+    11079, 8426, 1008, 2725, 11772, 6058, 1101, 1950, 8424, 5688, 6876, 12005, 10079, 5335, 927,
+    1770, 273, 8377, 2271, 5225, 10283, 116, 11807, 91, 11699, 757, 1304, 7524, 6451, 8032, 8154,
+    7456, 4191, 309, 2318, 2292, 10393, 11639, 9481, 12238, 10594, 9569, 7912, 10368, 9889, 12244,
+    7179, 3924, 3188, 367, 2077, 336, 5384, 5631, 8596, 4621, 1775, 8866, 451, 6108, 1317, 6246,
+    8795, 5896, 7283, 3132, 11564, 4977, 12161, 7371, 1366, 12130, 10619, 3809, 5149, 6300, 2638,
+    4197, 1418, 10065, 4156, 8373, 8644, 10445, 882, 8158, 10173, 9763, 12191, 459, 2966, 3166,
+    405, 5000, 9311, 6404, 8986, 1551, 8175, 3630, 10766, 9265, 700, 8573, 9508, 6630, 11437,
+    11595, 5850, 3950, 4775, 11941, 1446, 6018, 3386, 11470, 5310, 5476, 553, 9474, 2586, 1431,
+    2741, 473, 11383, 4745, 836, 4062, 10666, 7727, 11752, 5534, 312, 4307, 4351, 5764, 8679, 8381,
+    8187, 5, 7395, 4363, 1152, 5421, 5231, 6473, 436, 7567, 8603, 6229, 8230,
 ];
 
 #[inline(always)]
@@ -103,6 +207,27 @@ pub fn field_halve(x: u32) -> u32 {
     x >> 1
 }
 
+/// Maps a signed value in [-(q-1), q-1] to its representative in [0, q-1].
+#[inline(always)]
+pub fn field_from_signed(w: i32) -> u32 {
+    if w < 0 {
+        (w + Q as i32) as u32
+    } else {
+        w as u32
+    }
+}
+
+/// Maps a value in [0, q-1] to its centered representative in [-q/2, q/2].
+#[inline(always)]
+pub fn field_center(x: u32) -> i32 {
+    let w = x as i32;
+    if w > (Q as i32 / 2) {
+        w - Q as i32
+    } else {
+        w
+    }
+}
+
 #[inline(always)]
 pub fn montgomery_mul(x: u32, y: u32) -> u32 {
     let z = x * y;
@@ -112,8 +237,30 @@ pub fn montgomery_mul(x: u32, y: u32) -> u32 {
     z.wrapping_add(Q & (0u32.wrapping_sub(z >> 31)))
 }
 
-pub fn ntt_forward(a: &mut [u16; FALCON_512_N]) {
-    let n = FALCON_512_N;
+/// Per-thread count of NTT butterflies, for correlating the verifier's cost
+/// with its arithmetic. Needs `std` for the thread-local.
+#[cfg(feature = "profile")]
+pub(crate) mod profile {
+    extern crate std;
+
+    use core::cell::Cell;
+
+    std::thread_local! {
+        static BUTTERFLIES: Cell<u64> = const { Cell::new(0) };
+    }
+
+    pub(crate) fn add_butterflies(count: usize) {
+        BUTTERFLIES.with(|c| c.set(c.get() + count as u64));
+    }
+
+    /// Butterflies run on this thread so far.
+    pub(crate) fn butterflies() -> u64 {
+        BUTTERFLIES.with(Cell::get)
+    }
+}
+
+pub fn ntt_forward<const N: usize>(a: &mut [u16; N]) {
+    let n = N;
     let mut t = n;
     let mut m = 1;
 
@@ -133,14 +280,16 @@ pub fn ntt_forward(a: &mut [u16; FALCON_512_N]) {
             }
             j1 += t;
         }
+        #[cfg(feature = "profile")]
+        profile::add_butterflies(n / 2);
         t = ht;
         m <<= 1;
     }
 }
 
-pub fn ntt_inverse(a: &mut [u16; FALCON_512_N]) {
-    let n = FALCON_512_N;
-    let logn = 9;
+pub fn ntt_inverse<const N: usize>(a: &mut [u16; N]) {
+    let n = N;
+    let logn = N.trailing_zeros();
     let mut t = 1;
     let mut m = n;
 
@@ -162,6 +311,8 @@ pub fn ntt_inverse(a: &mut [u16; FALCON_512_N]) {
             }
             j1 += dt;
         }
+        #[cfg(feature = "profile")]
+        profile::add_butterflies(n / 2);
         t = dt;
         m = hm;
     }
@@ -175,25 +326,212 @@ pub fn ntt_inverse(a: &mut [u16; FALCON_512_N]) {
     }
 }
 
-pub fn poly_to_montgomery(f: &mut [u16; FALCON_512_N]) {
-    for i in 0..FALCON_512_N {
+pub fn poly_to_montgomery<const N: usize>(f: &mut [u16; N]) {
+    for i in 0..N {
         f[i] = montgomery_mul(f[i] as u32, R2) as u16;
     }
 }
 
-pub fn poly_pointwise_mul(f: &mut [u16; FALCON_512_N], g: &[u16; FALCON_512_N]) {
-    for i in 0..FALCON_512_N {
+pub fn poly_pointwise_mul<const N: usize>(f: &mut [u16; N], g: &[u16; N]) {
+    for i in 0..N {
         f[i] = montgomery_mul(f[i] as u32, g[i] as u32) as u16;
     }
 }
 
-pub fn poly_sub(f: &mut [u16; FALCON_512_N], g: &[u16; FALCON_512_N]) {
-    for i in 0..FALCON_512_N {
+pub fn poly_sub<const N: usize>(f: &mut [u16; N], g: &[u16; N]) {
+    for i in 0..N {
         f[i] = field_sub(f[i] as u32, g[i] as u32) as u16;
     }
 }
 
-pub fn poly_prepare_for_mul(h: &mut [u16; FALCON_512_N]) {
+pub fn poly_prepare_for_mul<const N: usize>(h: &mut [u16; N]) {
     ntt_forward(h);
     poly_to_montgomery(h);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FALCON_1024_N, FALCON_512_N};
+
+    #[test]
+    fn test_field_sub_wraparound() {
+        assert_eq!(field_sub(0, 1), Q - 1);
+        assert_eq!(field_sub(0, Q - 1), 1);
+        assert_eq!(field_sub(Q - 1, Q - 1), 0);
+        assert_eq!(field_sub(5, 3), 2);
+    }
+
+    #[test]
+    fn test_field_add_wraparound() {
+        assert_eq!(field_add(Q - 1, 1), 0);
+        assert_eq!(field_add(Q - 1, Q - 1), Q - 2);
+        assert_eq!(field_add(0, 0), 0);
+    }
+
+    #[test]
+    fn test_poly_sub_boundaries() {
+        let mut f = [0u16; FALCON_512_N];
+        let mut g = [0u16; FALCON_512_N];
+        f[1] = (Q - 1) as u16;
+        f[2] = (Q - 1) as u16;
+        g[0] = 1;
+        g[1] = (Q - 1) as u16;
+        g[3] = (Q - 1) as u16;
+
+        poly_sub(&mut f, &g);
+
+        assert_eq!(f[0] as u32, Q - 1); // 0 - 1
+        assert_eq!(f[1], 0); // (q-1) - (q-1)
+        assert_eq!(f[2] as u32, Q - 1); // (q-1) - 0
+        assert_eq!(f[3], 1); // 0 - (q-1)
+        assert!(f.iter().all(|&c| (c as u32) < Q));
+    }
+
+    #[test]
+    fn test_field_center_half_q_boundary() {
+        let half = Q / 2; // 6144
+        assert_eq!(field_center(0), 0);
+        assert_eq!(field_center(half), half as i32);
+        assert_eq!(field_center(half + 1), -(half as i32));
+        assert_eq!(field_center(Q - 1), -1);
+    }
+
+    #[test]
+    fn test_field_from_signed_roundtrip() {
+        assert_eq!(field_from_signed(-1), Q - 1);
+        assert_eq!(field_from_signed(0), 0);
+        let half = (Q / 2) as i32;
+        for w in -half..=half {
+            let u = field_from_signed(w);
+            assert!(u < Q);
+            assert_eq!(field_center(u), w);
+        }
+    }
+
+    /// Schoolbook product in Z_q[X]/(X^N + 1), for checking the NTT.
+    fn negacyclic_mul<const N: usize>(f: &[u16; N], g: &[u16; N]) -> [u16; N] {
+        let mut out = [0u32; N];
+        for (i, &a) in f.iter().enumerate() {
+            for (j, &b) in g.iter().enumerate() {
+                let p = a as u32 * b as u32 % Q;
+                let k = (i + j) % N;
+                out[k] = if i + j < N {
+                    field_add(out[k], p)
+                } else {
+                    field_sub(out[k], p)
+                };
+            }
+        }
+        out.map(|c| c as u16)
+    }
+
+    /// Checks the NTT product of f and g against the schoolbook reference.
+    fn check_ntt_mul<const N: usize>(mut f: [u16; N], g: [u16; N]) {
+        let expected = negacyclic_mul(&f, &g);
+
+        let mut prepared = g;
+        poly_prepare_for_mul(&mut prepared);
+        ntt_forward(&mut f);
+        poly_pointwise_mul(&mut f, &prepared);
+        ntt_inverse(&mut f);
+        assert_eq!(f, expected);
+    }
+
+    /// Uniform-looking coefficients in [0, q) from a xorshift64 stream.
+    fn random_poly<const N: usize>(state: &mut u64) -> [u16; N] {
+        let mut f = [0u16; N];
+        for c in f.iter_mut() {
+            *state ^= *state << 13;
+            *state ^= *state >> 7;
+            *state ^= *state << 17;
+            *c = (*state % Q as u64) as u16;
+        }
+        f
+    }
+
+    #[test]
+    fn test_ntt_mul_matches_schoolbook() {
+        fn structured<const N: usize>() -> ([u16; N], [u16; N]) {
+            let mut f = [0u16; N];
+            let mut g = [0u16; N];
+            for (i, (a, b)) in f.iter_mut().zip(g.iter_mut()).enumerate() {
+                *a = ((i * 7919 + 1) as u32 % Q) as u16;
+                *b = ((i * i * 31 + 5) as u32 % Q) as u16;
+            }
+            (f, g)
+        }
+        let (f, g) = structured::<FALCON_512_N>();
+        check_ntt_mul(f, g);
+        let (f, g) = structured::<FALCON_1024_N>();
+        check_ntt_mul(f, g);
+    }
+
+    #[test]
+    fn test_ntt_mul_matches_schoolbook_random() {
+        let mut state = 0x9E37_79B9_7F4A_7C15;
+        for _ in 0..4 {
+            let f = random_poly::<FALCON_512_N>(&mut state);
+            let g = random_poly::<FALCON_512_N>(&mut state);
+            check_ntt_mul(f, g);
+        }
+        // Largest coefficients everywhere, and a product with zero
+        let max = [(Q - 1) as u16; FALCON_512_N];
+        check_ntt_mul(max, max);
+        check_ntt_mul(random_poly::<FALCON_512_N>(&mut state), [0; FALCON_512_N]);
+    }
+
+    #[cfg(feature = "profile")]
+    #[test]
+    fn test_butterfly_count() {
+        // N/2 butterflies in each of logn stages
+        fn check<const N: usize>() {
+            let mut a = [1u16; N];
+            let before = profile::butterflies();
+            ntt_forward(&mut a);
+            let forward = profile::butterflies() - before;
+            ntt_inverse(&mut a);
+            let inverse = profile::butterflies() - before - forward;
+            let expected = (N / 2) as u64 * N.trailing_zeros() as u64;
+            assert_eq!(forward, expected);
+            assert_eq!(inverse, expected);
+        }
+        check::<FALCON_512_N>();
+        check::<FALCON_1024_N>();
+        assert_eq!(FALCON_512_N / 2 * 9, 2304);
+    }
+
+    #[test]
+    fn test_mod_pow() {
+        assert_eq!(mod_pow(7, 0, Q), 1);
+        assert_eq!(mod_pow(7, 1, Q), 7);
+        assert_eq!(mod_pow(7, 2, Q), 49);
+        assert_eq!(mod_pow(0, 5, Q), 0);
+        // Fermat: a^(q-1) = 1 for a not divisible by q
+        for a in [1, 2, 7, 4091, Q - 1] {
+            assert_eq!(mod_pow(a, Q - 1, Q), 1);
+        }
+        assert_eq!(mod_pow(Q + 3, 2, Q), 9);
+    }
+
+    #[test]
+    fn test_mod_inv() {
+        for a in [1, 2, 3, 7, 4091, 10952, 12287, Q - 1] {
+            let inv = mod_inv(a, Q);
+            assert!(inv < Q);
+            assert_eq!(inv * a % Q, 1, "a = {a}");
+        }
+        assert_eq!(mod_inv(Q - 1, Q), Q - 1);
+        assert_eq!(mod_inv(0, Q), 0);
+    }
+
+    #[test]
+    fn test_twiddle_tables_from_generator() {
+        let g_inv = mod_inv(7, Q);
+        for i in 0..GMB.len() {
+            let rev = (i as u32).reverse_bits() >> 22;
+            assert_eq!(GMB[i] as u32, R * mod_pow(7, rev, Q) % Q, "GMB[{i}]");
+            assert_eq!(IGMB[i] as u32, R * mod_pow(g_inv, rev, Q) % Q, "IGMB[{i}]");
+        }
+    }
+}
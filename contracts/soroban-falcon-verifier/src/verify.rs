@@ -1,4 +1,4 @@
-//! Falcon-512 Signature Verification
+//! Falcon-512 and Falcon-1024 Signature Verification
 //!
 //! # Overview
 //!
@@ -31,8 +31,385 @@
 //! - Falcon specification: <https://falcon-sign.info/falcon.pdf>
 //! - NIST PQC: <https://csrc.nist.gov/projects/post-quantum-cryptography>
 
-use crate::ntt::{ntt_forward, ntt_inverse, poly_pointwise_mul, poly_prepare_for_mul, poly_sub};
-use crate::{FALCON_512_N, FALCON_512_PUBKEY_SIZE, L2_BOUND_512, Q};
+use core::ops::RangeInclusive;
+
+use crate::ntt::{
+    field_center, field_from_signed, field_sub, ntt_forward, ntt_inverse, poly_pointwise_mul,
+    poly_prepare_for_mul, poly_sub,
+};
+use crate::{
+    FALCON_1024_LOGN, FALCON_1024_N, FALCON_1024_PUBKEY_SIZE, FALCON_512_LOGN, FALCON_512_N,
+    FALCON_512_PUBKEY_SIZE, FALCON_SIG_MAX_SIZE, FALCON_SIG_MIN_SIZE, L2_BOUND_1024, L2_BOUND_512,
+    NONCE_LEN, Q,
+};
+use sha3::{
+    digest::{ExtendableOutput, Update, XofReader},
+    Digest, Sha3_256, Shake256, Shake256Reader,
+};
+use soroban_sdk::{Bytes, Vec};
+
+/// Largest Falcon-512 signature encoding (constant-time format).
+const MAX_SIG_LEN: usize = *signature_size_bounds(9).end();
+const _: () = assert!(MAX_SIG_LEN == FALCON_SIG_MAX_SIZE as usize);
+const _: () = assert!(*signature_size_bounds(9).start() == FALCON_SIG_MIN_SIZE as usize);
+/// Bytes copied out of a host `Bytes` object per call when streaming.
+const STREAM_CHUNK: u32 = 256;
+
+/// Total size of a Falcon signature, header and nonce included, in `format`
+/// for degree 2^logn; `None` for the variable-length compressed format, or
+/// for a degree other than the standardised logn 9 and 10.
+///
+/// | format     | logn 9 | logn 10 |
+/// |------------|--------|---------|
+/// | compressed | `None` | `None`  |
+/// | padded     | 666    | 1280    |
+/// | CT         | 809    | 1577    |
+pub const fn sig_size(format: SigFormat, logn: u32) -> Option<usize> {
+    if logn != 9 && logn != 10 {
+        return None;
+    }
+    match format {
+        SigFormat::Compressed => None,
+        // Fixed by the specification rather than derived from n
+        SigFormat::Padded => Some(if logn == 9 { 666 } else { 1280 }),
+        // Nonce plus n coefficients at 12 bits each
+        SigFormat::ConstantTime => Some(1 + NONCE_LEN + (12 << logn) / 8),
+    }
+}
+
+/// Total signature lengths allowed for a format.
+///
+/// The padded size from [`sig_size`] is only an upper bound: the NIST KAT
+/// files use the `0x2x` header for variable-length compressed bodies as well.
+const fn format_expects_length(format: SigFormat, logn: u8) -> RangeInclusive<usize> {
+    match (format, sig_size(format, logn as u32)) {
+        (SigFormat::Compressed, _) => 0..=usize::MAX,
+        (SigFormat::Padded, Some(len)) => 0..=len,
+        (_, Some(len)) => len..=len,
+        // A compile error in a `const` context
+        (_, None) => panic!("Falcon is only defined for logn 9 and 10"),
+    }
+}
+
+/// Total signature lengths accepted for degree 2^logn in any format.
+///
+/// The minimum is a header, the nonce and the shortest compressed body, 9 bits
+/// per coefficient; the maximum is the largest format-specific size, which is
+/// always the constant-time encoding.
+const fn signature_size_bounds(logn: u8) -> RangeInclusive<usize> {
+    let padded_max = *format_expects_length(SigFormat::Padded, logn).end();
+    let ct_max = *format_expects_length(SigFormat::ConstantTime, logn).end();
+    assert!(padded_max <= ct_max);
+    1 + NONCE_LEN + (9usize << logn).div_ceil(8)..=ct_max
+}
+
+/// Squared L2 norm bound for degree 2^logn, for logn 1 to 10; `None` for any
+/// other degree.
+///
+/// The values are the reference implementation's `l2bound` table: β² =
+/// ⌊(1.1 · σ)² · 2n⌋, where σ is the standard deviation of the signing
+/// sampler for that degree (165.736617183 for Falcon-512, 168.388571447 for
+/// Falcon-1024). Only logn 9 and 10 are standardised; the smaller degrees
+/// are the reference's test parameters.
+pub const fn falcon_l2_bound(logn: u32) -> Option<u64> {
+    const L2_BOUNDS: [u64; 10] = [
+        101_498, 208_714, 428_865, 892_039, 1_852_696, 3_842_630, 7_959_734, 16_468_416,
+        34_034_726, 70_265_242,
+    ];
+    match logn {
+        1..=10 => Some(L2_BOUNDS[logn as usize - 1]),
+        _ => None,
+    }
+}
+
+/// Squared L2 norm bound for degree `n` (512 or 1024).
+const fn l2_bound(n: usize) -> u32 {
+    if n == FALCON_1024_N {
+        L2_BOUND_1024
+    } else {
+        L2_BOUND_512
+    }
+}
+
+/// Most unary high bits a compressed body of degree `n` can carry and still
+/// pass the norm check.
+///
+/// A coefficient with k unary zeros has magnitude at least 128·k, so by
+/// Cauchy–Schwarz (Σk)² ≤ n·Σk² ≤ n·β²/128². That is 1031 for Falcon-512
+/// and 2095 for Falcon-1024.
+const fn max_unary_bits(n: usize) -> u32 {
+    let square = n as u64 * l2_bound(n) as u64 / (128 * 128);
+    let mut root = 0u64;
+    while (root + 1) * (root + 1) <= square {
+        root += 1;
+    }
+    root as u32
+}
+
+const MAX_UNARY_BITS_512: u32 = max_unary_bits(FALCON_512_N);
+const MAX_UNARY_BITS_1024: u32 = max_unary_bits(FALCON_1024_N);
+
+/// Trailing coefficients that may not all be zero in a public key.
+///
+/// A key cut short and zero-padded back to full size decodes to an h whose
+/// last coefficients are all zero. For an honest key, whose coefficients are
+/// uniform mod q, 8 trailing zeros happen with probability q^-8 < 2^-108, so
+/// the decoders reject them as a truncated key. That catches any truncation
+/// of 14 bytes or more; a shorter one leaves too few zero coefficients to
+/// tell apart from a real key and fails at verification instead.
+const ZERO_TAIL_COEFFS: usize = 8;
+
+/// Whether the last [`ZERO_TAIL_COEFFS`] coefficients of `h` are all zero.
+fn has_zero_tail<const N: usize>(h: &[u16; N]) -> bool {
+    h[N - ZERO_TAIL_COEFFS..].iter().all(|&c| c == 0)
+}
+
+/// Whether every coefficient of `s2` is zero.
+///
+/// A zero s2 makes s1 = c0, so the norm check alone would accept it for any
+/// key once c0 is short, e.g. the all-zero challenge. A uniformly random c0
+/// is never that short, but a genuine signature is never all-zero either: its
+/// 512 coefficients are sampled with σ ≈ 165. Verification rejects it
+/// outright as defense in depth against a faulty challenge.
+fn is_zero_poly<const N: usize>(s2: &[i16; N]) -> bool {
+    s2.iter().fold(0, |acc, &z| acc | z) == 0
+}
+
+/// Constant-time `a <= b`: all ones if it holds, zero otherwise.
+///
+/// The borrow of `b - a` in 64 bits is 1 exactly when `b < a`, so the result
+/// is derived without a comparison the compiler could turn into a branch.
+fn ct_le(a: u32, b: u32) -> u32 {
+    let borrow = ((b as u64).wrapping_sub(a as u64) >> 63) as u32;
+    borrow.wrapping_sub(1)
+}
+
+/// Signature encoding, selected by the high nibble of the header byte.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SigFormat {
+    /// Variable-length compressed encoding (header `0x3_`).
+    Compressed,
+    /// Compressed encoding zero-padded to a fixed length (header `0x2_`).
+    Padded,
+    /// Fixed-width 12-bit encoding (header `0x5_`).
+    ConstantTime,
+}
+
+impl SigFormat {
+    /// Returns the format encoded in a signature header byte, if any.
+    pub fn from_header(header: u8) -> Option<Self> {
+        match header & 0xF0 {
+            0x30 => Some(SigFormat::Compressed),
+            0x20 => Some(SigFormat::Padded),
+            0x50 => Some(SigFormat::ConstantTime),
+            _ => None,
+        }
+    }
+
+    /// High nibble of the header byte for this format.
+    pub fn header_nibble(self) -> u8 {
+        match self {
+            SigFormat::Compressed => 0x30,
+            SigFormat::Padded => 0x20,
+            SigFormat::ConstantTime => 0x50,
+        }
+    }
+}
+
+/// Bit order used to pack the 14-bit public key coefficients.
+///
+/// The reference implementation (and the NIST test vectors) use MSB-first
+/// packing. A key packed in one order and decoded in the other yields a
+/// different h, so verification simply fails rather than erroring.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Packing {
+    /// Reference packing: each coefficient starts at the most significant free bit.
+    #[default]
+    MsbFirst,
+    /// Each coefficient starts at the least significant free bit.
+    LsbFirst,
+}
+
+/// How the message is hashed to the challenge polynomial c0, for
+/// [`FalconVerifier::verify_512_with_hash`].
+///
+/// Both modes end in the same HashToPoint: SHAKE256 output read as
+/// big-endian 16-bit samples, kept if below 5q and reduced mod q. They
+/// differ only in what SHAKE256 absorbs.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum HashMode {
+    /// Falcon specification: SHAKE256(nonce || message).
+    #[default]
+    Shake256,
+    /// SHAKE256(nonce || SHA3-256(message)): the message is first hashed to
+    /// 32 bytes, which are signed in its place. Used by tools that sign a
+    /// digest of large messages.
+    Sha3Prehash,
+}
+
+/// Reasons a signature can be rejected.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VerifyError {
+    /// Signature is too short to hold a header, nonce and body.
+    InvalidSignatureSize,
+    /// Header byte has an unknown format nibble or the wrong logn.
+    BadFormat,
+    /// Signature body does not decode to a valid s2 polynomial.
+    SignatureDecodeFailed,
+    /// Public key has the wrong size or header, or does not decode.
+    InvalidPublicKey,
+    /// Signature decoded but ||(s1, s2)||² exceeds the bound.
+    NormTooLarge,
+    /// Message is longer than the caller's limit (see
+    /// [`FalconVerifier::verify_512_bounded`]).
+    MessageTooLarge,
+    /// Rejection sampling of the challenge hit its sample cap. Never happens
+    /// for honest inputs; see [`FalconVerifier::hash_to_point`].
+    ChallengeExhausted,
+    /// The public key has a signature's length, or the signature a public
+    /// key's: the two arguments were most likely passed the wrong way round.
+    /// Only reported by [`FalconVerifier::verify_512_detailed`].
+    LikelyArgumentSwap,
+}
+
+/// Result of [`FalconVerifier::verify_512_with_margin`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VerifyOutcome {
+    /// Whether the signature is valid, as [`FalconVerifier::verify_512`]
+    /// would report.
+    pub valid: bool,
+    /// The Falcon-512 bound minus ||(s1, s2)||²: negative when the norm is
+    /// over the bound, and close to 0 for a signature that barely passed.
+    pub margin: i64,
+}
+
+/// A Falcon-512 signature split into its header, nonce and decoded s2.
+///
+/// Parsing is independent of the public key and message, so the fields can
+/// be inspected without running the verification itself.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FalconSignature {
+    header: u8,
+    nonce: [u8; NONCE_LEN],
+    format: SigFormat,
+    s2: [i16; FALCON_512_N],
+}
+
+impl FalconSignature {
+    /// Parses a signature of the form `header || nonce (40 bytes) || body`.
+    pub fn from_bytes(signature: &[u8]) -> Result<Self, VerifyError> {
+        let mut s2 = [0i16; FALCON_512_N];
+        let (header, nonce, format) = parse_signature(signature, FALCON_512_LOGN as u8, &mut s2)?;
+        Ok(FalconSignature {
+            header,
+            nonce,
+            format,
+            s2,
+        })
+    }
+
+    /// The raw header byte.
+    pub fn header(&self) -> u8 {
+        self.header
+    }
+
+    /// The degree parameter logn from the low nibble of the header.
+    pub fn logn(&self) -> u8 {
+        self.header & 0x0F
+    }
+
+    /// The 40-byte nonce hashed together with the message.
+    pub fn nonce(&self) -> &[u8; NONCE_LEN] {
+        &self.nonce
+    }
+
+    /// The encoding the signature body was parsed from.
+    pub fn format(&self) -> SigFormat {
+        self.format
+    }
+
+    /// The decoded s2 polynomial, with coefficients in [-2047, 2047].
+    pub fn s2(&self) -> &[i16; FALCON_512_N] {
+        &self.s2
+    }
+}
+
+/// Splits a degree-N signature into header, nonce and format, decoding s2 into `s2`.
+///
+/// `logn` is the expected low nibble of the header (9 for N = 512, 10 for N = 1024).
+/// The contents of `s2` are only meaningful when this returns `Ok`.
+fn parse_signature<const N: usize>(
+    signature: &[u8],
+    logn: u8,
+    s2: &mut [i16; N],
+) -> Result<(u8, [u8; NONCE_LEN], SigFormat), VerifyError> {
+    // The minimum is well past header and nonce, so the slices below are
+    // in bounds and the decoder never sees an empty body
+    if !signature_size_bounds(logn).contains(&signature.len()) {
+        return Err(VerifyError::InvalidSignatureSize);
+    }
+    let header = signature[0];
+    // Low nibble must be logn
+    if (header & 0x0F) != logn {
+        return Err(VerifyError::BadFormat);
+    }
+    // High nibble indicates format:
+    // 0x50 = CT (constant-time), 0x30 = compressed, 0x20 = padded
+    let format = SigFormat::from_header(header).ok_or(VerifyError::BadFormat)?;
+    // Checked before decoding so a mismatch never reaches the body decoder
+    if !format_expects_length(format, logn).contains(&signature.len()) {
+        return Err(VerifyError::InvalidSignatureSize);
+    }
+
+    // The nonce follows the header; its length does not depend on the degree
+    let mut nonce = [0u8; NONCE_LEN];
+    nonce.copy_from_slice(&signature[1..1 + NONCE_LEN]);
+
+    let sig_data = &signature[1 + NONCE_LEN..];
+    let decoded_len = match format {
+        SigFormat::ConstantTime => FalconVerifier::decode_sig_ct(sig_data, s2),
+        // Both compressed and padded use the same decoding algorithm
+        SigFormat::Compressed | SigFormat::Padded => {
+            FalconVerifier::decode_sig_compressed(sig_data, s2)
+        }
+    };
+
+    check_decoded_len(format, logn, sig_data, decoded_len)?;
+
+    Ok((header, nonce, format))
+}
+
+/// Checks the byte count a body decoder reported against the body it was given.
+///
+/// 0 means the decoder failed. A count past the end of `sig_data` can only
+/// come from a decoder bug, and is rejected rather than trusted.
+/// Trailing bytes are only allowed as zero padding up to exactly the padded
+/// size. Reference signers emit padded signatures under the compressed
+/// header too, so the rule applies to both nibbles; any other length with
+/// trailing bytes would give one signature many accepted encodings. The CT
+/// body has a fixed size and allows no trailing bytes at all.
+fn check_decoded_len(
+    format: SigFormat,
+    logn: u8,
+    sig_data: &[u8],
+    decoded_len: usize,
+) -> Result<(), VerifyError> {
+    if decoded_len == 0 || decoded_len > sig_data.len() {
+        return Err(VerifyError::SignatureDecodeFailed);
+    }
+    let trailing = &sig_data[decoded_len..];
+    if trailing.is_empty() {
+        return Ok(());
+    }
+    let padded_len = *format_expects_length(SigFormat::Padded, logn).end();
+    if format == SigFormat::ConstantTime
+        || 1 + NONCE_LEN + sig_data.len() != padded_len
+        || trailing.iter().any(|&b| b != 0)
+    {
+        return Err(VerifyError::SignatureDecodeFailed);
+    }
+    Ok(())
+}
 
 /// Falcon-512 signature verifier.
 ///
@@ -51,170 +428,1032 @@ impl FalconVerifier {
     /// # Returns
     /// `true` if the signature is valid, `false` otherwise.
     pub fn verify_512(pubkey: &[u8], message: &[u8], signature: &[u8]) -> bool {
-        // Step 1: Validate public key format
-        if pubkey.len() != FALCON_512_PUBKEY_SIZE {
-            return false;
+        Self::verify_512_chunks(pubkey, core::iter::once(message), signature)
+    }
+
+    /// Verifies a Falcon-512 signature over a message split into chunks.
+    ///
+    /// The chunks are absorbed in order, so the result is the same as
+    /// [`verify_512`](Self::verify_512) on their concatenation. Lets callers
+    /// holding fragmented data (e.g. a ring buffer) verify without copying it
+    /// into one slice; [`verify_512_segments`](Self::verify_512_segments) is
+    /// the host-object counterpart.
+    ///
+    /// # Returns
+    /// `true` if the signature is valid, `false` otherwise.
+    pub fn verify_512_chunks<'a>(
+        pubkey: &[u8],
+        chunks: impl Iterator<Item = &'a [u8]>,
+        signature: &[u8],
+    ) -> bool {
+        let sig = match FalconSignature::from_bytes(signature) {
+            Ok(sig) => sig,
+            Err(_) => return false,
+        };
+
+        let mut hasher = Shake256::default();
+        hasher.update(&sig.nonce);
+        for chunk in chunks {
+            hasher.update(chunk);
         }
-        // Header byte encodes logn; for Falcon-512, logn = 9 (since n = 2^9 = 512)
-        const FALCON_512_LOGN: u8 = 9;
-        if pubkey[0] != FALCON_512_LOGN {
-            return false;
+
+        let mut challenge = ChallengeStream::from_hasher(hasher);
+        let mut s1 = [0i16; FALCON_512_N];
+        Self::verify_with_challenge(
+            pubkey,
+            &sig,
+            |c0| challenge.fill(c0),
+            Packing::MsbFirst,
+            &mut s1,
+        )
+        .is_ok()
+    }
+
+    /// Checks that a signature parses and its body decodes, without a public
+    /// key or message.
+    ///
+    /// Runs the same header, length and body checks as verification, for
+    /// Falcon-512 or Falcon-1024 by the header's logn, but skips hashing and
+    /// the NTT. Lets a relayer drop structurally broken signatures before
+    /// paying for a full verification; `true` says nothing about validity.
+    pub fn can_decode_signature(signature: &[u8]) -> bool {
+        match signature.first().map(|&header| (header & 0x0F) as u32) {
+            Some(FALCON_512_LOGN) => {
+                let mut s2 = [0i16; FALCON_512_N];
+                parse_signature(signature, FALCON_512_LOGN as u8, &mut s2).is_ok()
+            }
+            Some(FALCON_1024_LOGN) => {
+                let mut s2 = [0i16; FALCON_1024_N];
+                parse_signature(signature, FALCON_1024_LOGN as u8, &mut s2).is_ok()
+            }
+            _ => false,
+        }
+    }
+
+    /// The fixed-size format a signature of `len` bytes, header and nonce
+    /// included, would be in for degree 2^logn.
+    ///
+    /// Only the padded and CT formats have a fixed size (see [`sig_size`]);
+    /// any other length gives `None`. The compressed format is variable and
+    /// cannot be inferred from the length, and a compressed signature can
+    /// happen to have either fixed size, so this is a hint for callers that
+    /// only have a length. The header byte is authoritative. A logn other
+    /// than 9 or 10 also gives `None`.
+    pub const fn format_from_length(len: usize, logn: u32) -> Option<SigFormat> {
+        match (
+            sig_size(SigFormat::Padded, logn),
+            sig_size(SigFormat::ConstantTime, logn),
+        ) {
+            (Some(padded), _) if len == padded => Some(SigFormat::Padded),
+            (_, Some(ct)) if len == ct => Some(SigFormat::ConstantTime),
+            _ => None,
         }
+    }
+
+    /// Verifies a Falcon-1024 signature.
+    ///
+    /// Same algorithm and signature formats as [`verify_512`](Self::verify_512),
+    /// with n = 1024, a 1793-byte public key (header `0x0A`) and the
+    /// Falcon-1024 norm bound. With the `strict_keys` feature, keys that are
+    /// not invertible are rejected as for Falcon-512.
+    ///
+    /// # Returns
+    /// `true` if the signature is valid, `false` otherwise.
+    pub fn verify_1024(pubkey: &[u8], message: &[u8], signature: &[u8]) -> bool {
+        let mut s2 = [0i16; FALCON_1024_N];
+        let nonce = match parse_signature(signature, FALCON_1024_LOGN as u8, &mut s2) {
+            Ok((_, nonce, _)) => nonce,
+            Err(_) => return false,
+        };
 
-        // Step 2: Parse signature header and determine format
-        // Minimum signature size: 1 (header) + 40 (nonce) + 1 (at least one byte)
-        if signature.len() < 42 {
+        if pubkey.len() != FALCON_1024_PUBKEY_SIZE || pubkey[0] != FALCON_1024_LOGN as u8 {
             return false;
         }
-        let sig_header = signature[0];
-        // Low nibble must be logn = 9
-        if (sig_header & 0x0F) != FALCON_512_LOGN {
+        let mut h = [0u16; FALCON_1024_N];
+        if !Self::decode_pubkey_coeffs(&pubkey[1..], &mut h) {
             return false;
         }
-        // High nibble indicates format:
-        // 0x50 = CT (constant-time), 0x30 = compressed, 0x20 = padded
-        let is_ct = (sig_header & 0xF0) == 0x50;
-        let is_compressed = (sig_header & 0xF0) == 0x30;
-        let is_padded = (sig_header & 0xF0) == 0x20;
-        if !is_ct && !is_compressed && !is_padded {
+        #[cfg(feature = "strict_keys")]
+        if Self::ntt_has_zero(&h) {
             return false;
         }
 
-        // Step 3: Decode public key polynomial h
-        let mut h = [0u16; FALCON_512_N];
-        if !Self::decode_pubkey(pubkey, &mut h) {
+        let mut c0 = [0u16; FALCON_1024_N];
+        if !ChallengeStream::new(&nonce, message).fill(&mut c0) {
             return false;
         }
 
-        // Step 4: Extract nonce (bytes 1-40)
-        let nonce = &signature[1..41];
+        poly_prepare_for_mul(&mut h);
+        let mut s1 = [0i16; FALCON_1024_N];
+        Self::verify_raw_into(&c0, &s2, &h, &mut s1)
+    }
 
-        // Step 5: Decode signature polynomial s2
-        let mut s2 = [0i16; FALCON_512_N];
-        let sig_data = &signature[41..];
-        let decoded_len = if is_ct {
-            Self::decode_sig_ct(sig_data, &mut s2)
-        } else {
-            // Both compressed and padded use the same decoding algorithm
-            Self::decode_sig_compressed(sig_data, &mut s2)
+    /// Verifies a Falcon-512 signature and writes the recovered `s1` polynomial.
+    ///
+    /// On success, `out_s1` holds s1 = c - s2·h mod q in centered form
+    /// (coefficients in [-q/2, q/2]). The contents of `out_s1` are only
+    /// meaningful when this returns `true`.
+    ///
+    /// # Returns
+    /// `true` if the signature is valid, `false` otherwise.
+    pub fn verify_512_into(
+        pubkey: &[u8],
+        message: &[u8],
+        signature: &[u8],
+        out_s1: &mut [i16; FALCON_512_N],
+    ) -> bool {
+        // Parse header, nonce and s2 up front; any malformed input is rejected
+        let sig = match FalconSignature::from_bytes(signature) {
+            Ok(sig) => sig,
+            Err(_) => return false,
         };
+        Self::verify_parsed_into(pubkey, message, &sig, Packing::MsbFirst, out_s1).is_ok()
+    }
 
-        if decoded_len == 0 {
-            return false;
-        }
+    /// Verifies a Falcon-512 signature and returns a cheap fingerprint of it.
+    ///
+    /// The fingerprint is the first 4 bytes of the nonce, read big-endian, and
+    /// lets bundlers deduplicate identical signatures in a batch without
+    /// hashing them. It is not cryptographically binding: distinct signatures
+    /// may share an id, so it must only be used for deduplication, never for
+    /// authorization. Returns an id of 0 if the signature is too short to
+    /// contain a nonce.
+    ///
+    /// # Returns
+    /// `(valid, id)` where `valid` is the result of [`verify_512`](Self::verify_512).
+    pub fn verify_512_with_id(pubkey: &[u8], message: &[u8], signature: &[u8]) -> (bool, u32) {
+        let id = match signature.get(1..5) {
+            Some(prefix) => u32::from_be_bytes([prefix[0], prefix[1], prefix[2], prefix[3]]),
+            None => 0,
+        };
+        (Self::verify_512(pubkey, message, signature), id)
+    }
 
-        // Padded format: remaining bytes after encoded data must be zero
-        if !is_ct && decoded_len < sig_data.len() {
-            for i in decoded_len..sig_data.len() {
-                if sig_data[i] != 0 {
-                    return false;
-                }
-            }
+    /// Verifies a Falcon-512 signature and returns the SHA3-256 digest of the
+    /// message on success.
+    ///
+    /// Contracts that log which message was authorized can store the 32-byte
+    /// digest instead of the full message.
+    ///
+    /// # Returns
+    /// `Some(digest)` if the signature is valid, `None` otherwise.
+    pub fn verify_512_with_digest(
+        pubkey: &[u8],
+        message: &[u8],
+        signature: &[u8],
+    ) -> Option<[u8; 32]> {
+        if !Self::verify_512(pubkey, message, signature) {
+            return None;
         }
+        Some(Sha3_256::digest(message).into())
+    }
 
-        // Step 6: Hash message to challenge polynomial c0
-        let mut c0 = [0u16; FALCON_512_N];
-        Self::hash_to_point(nonce, message, &mut c0);
-
-        // Step 7: Prepare public key and verify
-        // Convert h to NTT domain and Montgomery form for efficient multiplication
-        poly_prepare_for_mul(&mut h);
-
-        Self::verify_raw_512(&c0, &s2, &h)
+    /// Verifies a Falcon-512 signature bound to associated data.
+    ///
+    /// `aad` is caller-supplied context, such as a network passphrase or a
+    /// contract address, that the signer committed to without it being part
+    /// of `message`. SHAKE256 absorbs exactly
+    ///
+    /// ```text
+    /// nonce (40 bytes) || aad || message
+    /// ```
+    ///
+    /// so a signer produces a valid signature by signing `aad || message` as
+    /// one message. Nothing separates the two, and only the concatenation is
+    /// authenticated: the same signature verifies for any split of those
+    /// bytes into `aad` and `message`. Use fixed-length `aad`, or prefix its
+    /// length, where that matters. An empty `aad` is
+    /// [`verify_512`](Self::verify_512).
+    ///
+    /// # Returns
+    /// `true` if the signature is valid, `false` otherwise.
+    pub fn verify_512_aad(pubkey: &[u8], message: &[u8], aad: &[u8], signature: &[u8]) -> bool {
+        Self::verify_512_chunks(pubkey, [aad, message].into_iter(), signature)
     }
 
-    pub fn verify_raw_512(
-        c0: &[u16; FALCON_512_N],
-        s2: &[i16; FALCON_512_N],
-        h: &[u16; FALCON_512_N],
+    /// Verifies a Falcon-512 signature with the message hashed to the
+    /// challenge as `hash` specifies.
+    ///
+    /// [`HashMode::Shake256`] is exactly [`verify_512`](Self::verify_512).
+    /// [`HashMode::Sha3Prehash`] is [`verify_512`](Self::verify_512) over the
+    /// 32-byte SHA3-256 digest of `message`, for interop with signers that
+    /// sign digests. A signature is valid in at most one mode unless the
+    /// message is its own digest.
+    ///
+    /// # Returns
+    /// `true` if the signature is valid, `false` otherwise.
+    pub fn verify_512_with_hash(
+        pubkey: &[u8],
+        message: &[u8],
+        signature: &[u8],
+        hash: HashMode,
     ) -> bool {
-        let mut tt = [0u16; FALCON_512_N];
-
-        // Step 1: Convert s2 from signed to unsigned representation mod q
-        // s2 values are in range [-q/2, q/2], convert to [0, q-1]
-        for i in 0..FALCON_512_N {
-            let w = s2[i] as i32;
-            // If negative, add q to get equivalent positive value mod q
-            let w = if w < 0 {
-                (w + Q as i32) as u32
-            } else {
-                w as u32
-            };
-            tt[i] = w as u16;
+        match hash {
+            HashMode::Shake256 => Self::verify_512(pubkey, message, signature),
+            HashMode::Sha3Prehash => {
+                let digest: [u8; 32] = Sha3_256::digest(message).into();
+                Self::verify_512(pubkey, &digest, signature)
+            }
         }
+    }
 
-        // Step 2: Compute s2·h in the ring Z_q[X]/(X^n + 1)
-        // Using NTT: multiply(a, b) = INTT(NTT(a) ⊙ NTT(b))
-        // Since h is already in NTT form, we only need to transform tt
-        ntt_forward(&mut tt); // tt = NTT(s2)
-        poly_pointwise_mul(&mut tt, h); // tt = NTT(s2) ⊙ NTT(h) = NTT(s2·h)
-        ntt_inverse(&mut tt); // tt = s2·h
-
-        // Step 3: Compute s1 = c0 - s2·h  (equivalently, -s1 = s2·h - c0)
-        // Note: we compute tt = tt - c0, which gives us -s1
-        poly_sub(&mut tt, c0);
-
-        // Step 4: Convert -s1 back to signed representation for norm computation
-        // Values in [0, q-1] are converted to [-q/2, q/2] (centered representation)
-        // Then negate to get s1 (but for norm, sign doesn't matter)
+    /// Verifies an already-parsed Falcon-512 signature.
+    ///
+    /// # Arguments
+    /// * `pubkey` - 897-byte Falcon-512 public key
+    /// * `message` - The message that was signed (arbitrary length)
+    /// * `signature` - Signature parsed with [`FalconSignature::from_bytes`]
+    ///
+    /// # Returns
+    /// `true` if the signature is valid, `false` otherwise.
+    pub fn verify_512_parsed(pubkey: &[u8], message: &[u8], signature: &FalconSignature) -> bool {
         let mut s1 = [0i16; FALCON_512_N];
-        for i in 0..FALCON_512_N {
-            let w = tt[i] as i32;
-            // Center: if w > q/2, interpret as negative (w - q)
-            let w = if w > (Q as i32 / 2) { w - Q as i32 } else { w };
-            // The result is -s1, but ||s1|| = ||-s1||, so this is fine for norm check
-            s1[i] = w as i16;
+        Self::verify_parsed_into(pubkey, message, signature, Packing::MsbFirst, &mut s1).is_ok()
+    }
+
+    /// Verifies a Falcon-512 signature and reports why it was rejected.
+    ///
+    /// Performs the same checks as [`verify_512`](Self::verify_512), so
+    /// `verify_512_detailed(..).is_ok() == verify_512(..)`.
+    ///
+    /// Before parsing, the lengths are checked for a swapped public key and
+    /// signature: a public key that is not 897 bytes but has a Falcon-512
+    /// signature length, or an 897-byte signature, gives
+    /// [`VerifyError::LikelyArgumentSwap`]. Neither can verify, so this only
+    /// changes the reported error.
+    ///
+    /// # Returns
+    /// `Ok(())` if the signature is valid, otherwise the first check that failed.
+    pub fn verify_512_detailed(
+        pubkey: &[u8],
+        message: &[u8],
+        signature: &[u8],
+    ) -> Result<(), VerifyError> {
+        if pubkey.len() != FALCON_512_PUBKEY_SIZE
+            && (signature_size_bounds(FALCON_512_LOGN as u8).contains(&pubkey.len())
+                || signature.len() == FALCON_512_PUBKEY_SIZE)
+        {
+            return Err(VerifyError::LikelyArgumentSwap);
         }
+        let sig = FalconSignature::from_bytes(signature)?;
+        let mut s1 = [0i16; FALCON_512_N];
+        Self::verify_parsed_into(pubkey, message, &sig, Packing::MsbFirst, &mut s1)
+    }
 
-        // Step 5: Verify that the signature vector (s1, s2) is short enough
-        Self::is_short(&s1, s2)
+    /// Verifies a Falcon-512 signature and reports how far its norm is from
+    /// the bound.
+    ///
+    /// Honest signatures have norms well below the bound, so a signer whose
+    /// margins are consistently tiny may have a broken sampler. The margin
+    /// is computed from the same s1 as verification, in 64 bits so it cannot
+    /// wrap. An all-zero s2 is invalid whatever its margin.
+    ///
+    /// # Returns
+    /// `None` if verification stopped before the norm check: the signature
+    /// does not parse, the public key does not decode, or the challenge
+    /// could not be sampled.
+    pub fn verify_512_with_margin(
+        pubkey: &[u8],
+        message: &[u8],
+        signature: &[u8],
+    ) -> Option<VerifyOutcome> {
+        let sig = FalconSignature::from_bytes(signature).ok()?;
+        let mut challenge = ChallengeStream::new(&sig.nonce, message);
+        Self::margin_with_challenge(pubkey, &sig, |c0| challenge.fill(c0))
     }
 
-    /// Verifies that ||(s1, s2)||² ≤ L2_BOUND_512.
-    fn is_short(s1: &[i16; FALCON_512_N], s2: &[i16; FALCON_512_N]) -> bool {
-        let mut s: u32 = 0; // Running sum of squared coefficients
-        let mut ng: u32 = 0; // Overflow detector (accumulates sign bits)
+    fn margin_with_challenge(
+        pubkey: &[u8],
+        signature: &FalconSignature,
+        challenge: impl FnOnce(&mut [u16; FALCON_512_N]) -> bool,
+    ) -> Option<VerifyOutcome> {
+        let mut s1 = [0i16; FALCON_512_N];
+        let valid = match Self::verify_with_challenge(
+            pubkey,
+            signature,
+            challenge,
+            Packing::MsbFirst,
+            &mut s1,
+        ) {
+            Ok(()) => true,
+            // s1 is complete once the norm has been checked
+            Err(VerifyError::NormTooLarge) => false,
+            Err(_) => return None,
+        };
+        let norm: i64 = s1
+            .iter()
+            .chain(signature.s2.iter())
+            .map(|&c| c as i64 * c as i64)
+            .sum();
+        Some(VerifyOutcome {
+            valid,
+            margin: L2_BOUND_512 as i64 - norm,
+        })
+    }
 
-        for i in 0..FALCON_512_N {
-            // Add s1[i]² to the sum
-            let z1 = s1[i] as i32;
-            s = s.wrapping_add((z1 * z1) as u32);
-            ng |= s; // Capture if sum went negative (overflow)
+    /// Finds which of several candidate messages a Falcon-512 signature is valid for.
+    ///
+    /// For commit-reveal schemes that reveal one of several precommitted
+    /// messages under a single signature. The signature and public key are
+    /// decoded once, and s2·h is computed once since it does not depend on
+    /// the message. Each candidate then costs one hash-to-point and one norm
+    /// check, so the cost grows as O(number of messages) on top of a single
+    /// verification. Candidates are tried in order.
+    ///
+    /// # Returns
+    /// The index of the first message the signature is valid for, or `None`.
+    pub fn verify_512_any_message(
+        pubkey: &[u8],
+        messages: &[&[u8]],
+        signature: &[u8],
+    ) -> Option<usize> {
+        let sig = FalconSignature::from_bytes(signature).ok()?;
+        // decode_pubkey checks the size and the logn = 9 header
+        let mut h = [0u16; FALCON_512_N];
+        if !Self::decode_pubkey(pubkey, &mut h) {
+            return None;
+        }
+        poly_prepare_for_mul(&mut h);
+        let s2h = Self::compute_s2h_ntt(&sig.s2, &h);
 
-            // Add s2[i]² to the sum
-            let z2 = s2[i] as i32;
-            s = s.wrapping_add((z2 * z2) as u32);
-            ng |= s; // Capture if sum went negative (overflow)
+        let mut c0 = [0u16; FALCON_512_N];
+        let mut s1 = [0i16; FALCON_512_N];
+        messages.iter().position(|message| {
+            let mut tt = s2h;
+            ChallengeStream::new(&sig.nonce, message).fill(&mut c0)
+                && Self::check_s1_into(&c0, &sig.s2, &mut tt, &mut s1)
+        })
+    }
+
+    /// Verifies a Falcon-512 signature whose nonce must also satisfy `pred`.
+    ///
+    /// For deployments that embed structured data in the 40-byte nonce, such
+    /// as a chain id or an epoch. The predicate runs on the parsed nonce
+    /// before any hashing, so a failing convention is rejected cheaply. This
+    /// is a policy check only: the signature binds the nonce exactly as in
+    /// [`verify_512`](Self::verify_512), and the predicate adds no
+    /// cryptographic guarantee beyond it.
+    ///
+    /// # Returns
+    /// `true` if `pred(nonce)` holds and the signature is valid, `false` otherwise.
+    pub fn verify_512_nonce_predicate(
+        pubkey: &[u8],
+        message: &[u8],
+        signature: &[u8],
+        pred: impl Fn(&[u8; NONCE_LEN]) -> bool,
+    ) -> bool {
+        let sig = match FalconSignature::from_bytes(signature) {
+            Ok(sig) => sig,
+            Err(_) => return false,
+        };
+        if !pred(sig.nonce()) {
+            return false;
         }
+        Self::verify_512_parsed(pubkey, message, &sig)
+    }
 
-        // If overflow occurred (ng has sign bit set), force s to max value
-        // This ensures we reject potentially forged signatures that overflowed
-        // Expression: if ng bit 31 is set, OR s with 0xFFFFFFFF; else OR with 0
-        s |= 0u32.wrapping_sub(ng >> 31);
+    /// Verifies a Falcon-512 signature, treating the constant-time format as
+    /// invalid.
+    ///
+    /// For protocols that accept only compressed or padded signatures: any
+    /// `0x5x` header is rejected before the body is decoded. Otherwise this
+    /// is [`verify_512`](Self::verify_512).
+    ///
+    /// # Returns
+    /// `true` if the signature is not in CT format and is valid.
+    pub fn verify_512_no_ct(pubkey: &[u8], message: &[u8], signature: &[u8]) -> bool {
+        match signature.first() {
+            Some(&header) if header & 0xF0 == SigFormat::ConstantTime.header_nibble() => false,
+            _ => Self::verify_512(pubkey, message, signature),
+        }
+    }
 
-        // The squared L2 norm must not exceed the bound
-        // ||(s1, s2)||² = Σ(s1[i]² + s2[i]²) ≤ L2_BOUND_512
-        s <= L2_BOUND_512
+    /// Verifies a Falcon-512 signature over a message of at most `max_len` bytes.
+    ///
+    /// Longer messages are rejected with [`VerifyError::MessageTooLarge`]
+    /// before anything is hashed, never truncated, so callers that want a
+    /// size limit state it explicitly instead of relying on a fixed clamp.
+    ///
+    /// # Returns
+    /// `Ok(())` if the message fits and the signature is valid, otherwise the
+    /// first check that failed.
+    pub fn verify_512_bounded(
+        pubkey: &[u8],
+        message: &[u8],
+        signature: &[u8],
+        max_len: usize,
+    ) -> Result<(), VerifyError> {
+        if message.len() > max_len {
+            return Err(VerifyError::MessageTooLarge);
+        }
+        Self::verify_512_detailed(pubkey, message, signature)
     }
 
-    /// Decodes a Falcon-512 public key from its packed binary format (14 bits per coefficient, MSB-first).
-    pub fn decode_pubkey(pubkey: &[u8], h: &mut [u16; FALCON_512_N]) -> bool {
-        if pubkey.len() != FALCON_512_PUBKEY_SIZE {
+    /// Verifies a Falcon-512 signature preceded by its length as a 4-byte
+    /// big-endian prefix, as sent by length-delimited transports.
+    ///
+    /// The frame must hold exactly the declared number of signature bytes: a
+    /// length that overruns the buffer, or bytes left after the signature,
+    /// reject the frame.
+    ///
+    /// # Returns
+    /// `true` if the frame is well-formed and the signature is valid.
+    pub fn verify_512_len_prefixed(pubkey: &[u8], message: &[u8], framed: &[u8]) -> bool {
+        if framed.len() < 4 {
             return false;
         }
-        if pubkey[0] != 9 {
-            // logn = 9 for Falcon-512
+        let (prefix, signature) = framed.split_at(4);
+        let declared = u32::from_be_bytes([prefix[0], prefix[1], prefix[2], prefix[3]]);
+        if declared as usize != signature.len() {
+            return false;
+        }
+        Self::verify_512(pubkey, message, signature)
+    }
+
+    /// Verifies a Falcon-512 signature against a public key in the given packing.
+    ///
+    /// Use [`Packing::LsbFirst`] only for keys produced by tools that pack
+    /// coefficients LSB-first; [`verify_512`](Self::verify_512) is equivalent
+    /// to passing [`Packing::MsbFirst`].
+    ///
+    /// # Returns
+    /// `true` if the signature is valid, `false` otherwise.
+    pub fn verify_512_with_packing(
+        pubkey: &[u8],
+        message: &[u8],
+        signature: &[u8],
+        packing: Packing,
+    ) -> bool {
+        let sig = match FalconSignature::from_bytes(signature) {
+            Ok(sig) => sig,
+            Err(_) => return false,
+        };
+        let mut s1 = [0i16; FALCON_512_N];
+        Self::verify_parsed_into(pubkey, message, &sig, packing, &mut s1).is_ok()
+    }
+
+    /// Verifies a Falcon-512 signature whose message is a Soroban `Bytes`.
+    ///
+    /// The message is absorbed into SHAKE256 in small chunks read directly
+    /// from the host object, so it is never copied into a contiguous buffer
+    /// and there is no cap on its length. The public key and signature are
+    /// small and copied onto the stack as usual.
+    ///
+    /// # Returns
+    /// `true` if the signature is valid, `false` otherwise.
+    pub fn verify_512_streaming(pubkey: &Bytes, message: &Bytes, signature: &Bytes) -> bool {
+        Self::verify_512_absorbing(pubkey, signature, |hasher| absorb_bytes(hasher, message))
+    }
+
+    /// Verifies a Falcon-512 signature over the concatenation of `segments`.
+    ///
+    /// The segments are absorbed in order, chunk by chunk, exactly as
+    /// [`verify_512_streaming`](Self::verify_512_streaming) absorbs a single
+    /// message, so the result equals verifying the concatenated message
+    /// without ever building it.
+    ///
+    /// # Returns
+    /// `true` if the signature is valid, `false` otherwise.
+    pub fn verify_512_segments(pubkey: &Bytes, segments: &Vec<Bytes>, signature: &Bytes) -> bool {
+        Self::verify_512_absorbing(pubkey, signature, |hasher| {
+            for segment in segments.iter() {
+                absorb_bytes(hasher, &segment);
+            }
+        })
+    }
+
+    /// Shared body of the host-object entry points: `absorb_message` feeds the
+    /// message to a hasher that has already absorbed the nonce.
+    fn verify_512_absorbing(
+        pubkey: &Bytes,
+        signature: &Bytes,
+        absorb_message: impl FnOnce(&mut Shake256),
+    ) -> bool {
+        let mut pk_bytes = [0u8; FALCON_512_PUBKEY_SIZE];
+        if pubkey.len() as usize != FALCON_512_PUBKEY_SIZE {
+            return false;
+        }
+        pubkey.copy_into_slice(&mut pk_bytes);
+
+        let sig_len = signature.len() as usize;
+        let mut sig_bytes = [0u8; MAX_SIG_LEN];
+        if sig_len > MAX_SIG_LEN {
+            return false;
+        }
+        signature.copy_into_slice(&mut sig_bytes[..sig_len]);
+        let sig = match FalconSignature::from_bytes(&sig_bytes[..sig_len]) {
+            Ok(sig) => sig,
+            Err(_) => return false,
+        };
+
+        let mut hasher = Shake256::default();
+        hasher.update(&sig.nonce);
+        absorb_message(&mut hasher);
+
+        let mut challenge = ChallengeStream::from_hasher(hasher);
+        let mut s1 = [0i16; FALCON_512_N];
+        Self::verify_with_challenge(
+            &pk_bytes,
+            &sig,
+            |c0| challenge.fill(c0),
+            Packing::MsbFirst,
+            &mut s1,
+        )
+        .is_ok()
+    }
+
+    /// Verifies a Falcon-512 signature against a challenge computed by the caller.
+    ///
+    /// Skips hashing the message: `c0` must be the challenge polynomial of
+    /// the signature's own nonce and the message, as produced by
+    /// [`hash_to_point`](Self::hash_to_point). The signature is still decoded
+    /// and the public key prepared here. A `c0` derived from anything else
+    /// (another message, or another signature's nonce) makes the signature
+    /// fail verification.
+    ///
+    /// # Returns
+    /// `true` if the signature is valid for the challenge, `false` otherwise.
+    pub fn verify_512_with_challenge(
+        pubkey: &[u8],
+        c0: &[u16; FALCON_512_N],
+        signature: &[u8],
+    ) -> bool {
+        let sig = match FalconSignature::from_bytes(signature) {
+            Ok(sig) => sig,
+            Err(_) => return false,
+        };
+        let mut s1 = [0i16; FALCON_512_N];
+        Self::verify_with_challenge(
+            pubkey,
+            &sig,
+            |out| {
+                out.copy_from_slice(c0);
+                true
+            },
+            Packing::MsbFirst,
+            &mut s1,
+        )
+        .is_ok()
+    }
+
+    /// Verifies a Falcon-512 signature against a public key given as decoded
+    /// coefficients, in the form [`decode_pubkey`](Self::decode_pubkey)
+    /// produces.
+    ///
+    /// For callers that obtain h without its 897-byte packed encoding, e.g. a
+    /// derived or aggregate key, so it need not be re-encoded first. `h` is
+    /// prepared for multiplication here. Every coefficient must be below q
+    /// and the last 8 must not all be zero: a key failing either is rejected,
+    /// as its packed form would be. With the `strict_keys` feature
+    /// non-invertible keys are rejected too.
+    ///
+    /// # Returns
+    /// `true` if the signature is valid, `false` otherwise.
+    pub fn verify_512_from_coeffs(
+        h: &[u16; FALCON_512_N],
+        message: &[u8],
+        signature: &[u8],
+    ) -> bool {
+        if h.iter().any(|&c| c as u32 >= Q) || has_zero_tail(h) {
+            return false;
+        }
+        #[cfg(feature = "strict_keys")]
+        if Self::ntt_has_zero(h) {
+            return false;
+        }
+        let sig = match FalconSignature::from_bytes(signature) {
+            Ok(sig) => sig,
+            Err(_) => return false,
+        };
+        let mut c0 = [0u16; FALCON_512_N];
+        if !ChallengeStream::new(&sig.nonce, message).fill(&mut c0) {
+            return false;
+        }
+
+        let mut h = *h;
+        poly_prepare_for_mul(&mut h);
+        Self::verify_raw_512(&c0, &sig.s2, &h)
+    }
+
+    /// Hashes nonce || message to the Falcon-512 challenge polynomial.
+    ///
+    /// Uses SHAKE256 with rejection sampling, exactly as verification does,
+    /// so the result can be passed to
+    /// [`verify_512_with_challenge`](Self::verify_512_with_challenge).
+    ///
+    /// Each 2-byte sample is accepted with probability 5q/65536 ≈ 0.94, so
+    /// 512 coefficients take about 546 samples. Sampling gives up after
+    /// `MAX_CHALLENGE_SAMPLES` (2048) samples to bound the worst-case cost;
+    /// the chance of that for a given nonce and message is below 2^-4500
+    /// (2^-2100 for the 1024 coefficients of Falcon-1024), and verification
+    /// then fails.
+    ///
+    /// # Returns
+    /// `false` if sampling hit the cap, in which case `c0` is incomplete.
+    pub fn hash_to_point(nonce: &[u8], message: &[u8], c0: &mut [u16; FALCON_512_N]) -> bool {
+        ChallengeStream::new(nonce, message).fill(c0)
+    }
+
+    /// [`hash_to_point`](Self::hash_to_point) with the modulus and the
+    /// rejection-sampling accept threshold as parameters, for experiments
+    /// with non-standard values of q.
+    ///
+    /// Each 2-byte sample w is accepted if w < `accept_threshold` and gives
+    /// the coefficient w mod `q`; the output is uniform only if the threshold
+    /// is a multiple of q. With q = [`Q`] and `accept_threshold` = 5q this is
+    /// exactly [`hash_to_point`](Self::hash_to_point), which verification
+    /// always uses. The same `MAX_CHALLENGE_SAMPLES` cap applies, so a low
+    /// threshold makes sampling fail far more often.
+    ///
+    /// # Panics
+    /// If `q` is 0 or `accept_threshold` is not in [q, 2^16].
+    ///
+    /// # Returns
+    /// `false` if sampling hit the cap, in which case `c0` is incomplete.
+    pub fn hash_to_point_with_params(
+        nonce: &[u8],
+        message: &[u8],
+        q: u32,
+        accept_threshold: u32,
+        c0: &mut [u16; FALCON_512_N],
+    ) -> bool {
+        assert!(
+            q > 0 && (q..=1 << 16).contains(&accept_threshold),
+            "accept threshold must be in [q, 2^16]"
+        );
+        ChallengeStream::new(nonce, message).fill_with(q, accept_threshold, c0)
+    }
+
+    pub(crate) fn verify_parsed_into(
+        pubkey: &[u8],
+        message: &[u8],
+        signature: &FalconSignature,
+        packing: Packing,
+        out_s1: &mut [i16; FALCON_512_N],
+    ) -> Result<(), VerifyError> {
+        let mut challenge = ChallengeStream::new(&signature.nonce, message);
+        Self::verify_with_challenge(pubkey, signature, |c0| challenge.fill(c0), packing, out_s1)
+    }
+
+    /// Runs verification with `challenge` writing c0 once the public key has
+    /// decoded; `challenge` returns `false` if it could not produce c0.
+    fn verify_with_challenge(
+        pubkey: &[u8],
+        signature: &FalconSignature,
+        challenge: impl FnOnce(&mut [u16; FALCON_512_N]) -> bool,
+        packing: Packing,
+        out_s1: &mut [i16; FALCON_512_N],
+    ) -> Result<(), VerifyError> {
+        // Step 1: Validate public key format
+        if pubkey.len() != FALCON_512_PUBKEY_SIZE {
+            return Err(VerifyError::InvalidPublicKey);
+        }
+        // Key and signature must be for the same degree. Checked before the
+        // degree itself so a mismatch is never accepted once more degrees exist.
+        if pubkey[0] != signature.logn() {
+            return Err(VerifyError::InvalidPublicKey);
+        }
+        // Header byte encodes logn; for Falcon-512, logn = 9 (since n = 2^9 = 512)
+        if pubkey[0] != FALCON_512_LOGN as u8 {
+            return Err(VerifyError::InvalidPublicKey);
+        }
+
+        // Step 2: Decode public key polynomial h
+        let mut h = [0u16; FALCON_512_N];
+        let decoded = match packing {
+            Packing::MsbFirst => Self::decode_pubkey(pubkey, &mut h),
+            Packing::LsbFirst => Self::decode_pubkey_lsb(pubkey, &mut h),
+        };
+        if !decoded {
+            return Err(VerifyError::InvalidPublicKey);
+        }
+
+        // Step 3: Obtain the challenge polynomial c0 for nonce || message
+        let mut c0 = [0u16; FALCON_512_N];
+        if !challenge(&mut c0) {
+            return Err(VerifyError::ChallengeExhausted);
+        }
+
+        // Step 4: Prepare public key and verify
+        // Convert h to NTT domain and Montgomery form for efficient multiplication
+        poly_prepare_for_mul(&mut h);
+
+        if Self::verify_raw_into(&c0, &signature.s2, &h, out_s1) {
+            Ok(())
+        } else {
+            Err(VerifyError::NormTooLarge)
+        }
+    }
+
+    /// Verifies the core Falcon-512 equation from already-decoded inputs.
+    ///
+    /// * `c0` - Challenge polynomial from [`hash_to_point`](Self::hash_to_point)
+    /// * `s2` - Decoded signature polynomial, e.g. [`FalconSignature::s2`]
+    /// * `h` - Public key from [`decode_pubkey`](Self::decode_pubkey), converted
+    ///   with [`prepare_pubkey`](Self::prepare_pubkey)
+    ///
+    /// # Returns
+    /// `true` if ||(c0 - s2·h, s2)||² is within the Falcon-512 bound.
+    pub fn verify_raw_512(
+        c0: &[u16; FALCON_512_N],
+        s2: &[i16; FALCON_512_N],
+        h: &[u16; FALCON_512_N],
+    ) -> bool {
+        let mut s1 = [0i16; FALCON_512_N];
+        Self::verify_raw_into(c0, s2, h, &mut s1)
+    }
+
+    /// Verifies the core Falcon-512 equation with s2 already in the NTT
+    /// domain, for pipelines that compute NTTs in batch on the host.
+    ///
+    /// * `c0` - Challenge polynomial, as for [`verify_raw_512`](Self::verify_raw_512)
+    /// * `s2_ntt` - NTT(s2 mod q): s2 mapped to [0, q-1] and transformed
+    ///   with the same bit-reversed-order forward NTT as the public key,
+    ///   without Montgomery scaling. Coefficients must be below q.
+    /// * `h_ntt` - Public key in NTT domain and Montgomery form, from
+    ///   [`prepare_pubkey`](Self::prepare_pubkey)
+    ///
+    /// Only the pointwise product, the inverse NTTs, the subtraction and the
+    /// norm check run here. The norm needs s2 itself, which is recovered
+    /// from `s2_ntt` with a second inverse NTT rather than taken as a
+    /// separate argument: a caller-supplied s2 that did not match `s2_ntt`
+    /// would let any s1 pass.
+    ///
+    /// # Returns
+    /// `true` if ||(c0 - s2·h, s2)||² is within the Falcon-512 bound, and
+    /// `false` if any coefficient of `s2_ntt` is not below q.
+    pub fn verify_raw_512_ntt(
+        c0: &[u16; FALCON_512_N],
+        s2_ntt: &[u16; FALCON_512_N],
+        h_ntt: &[u16; FALCON_512_N],
+    ) -> bool {
+        if s2_ntt.iter().any(|&w| w as u32 >= Q) {
+            return false;
+        }
+
+        let mut s2_field = *s2_ntt;
+        ntt_inverse(&mut s2_field);
+        let mut s2 = [0i16; FALCON_512_N];
+        for (z, &w) in s2.iter_mut().zip(s2_field.iter()) {
+            *z = field_center(w as u32) as i16;
+        }
+
+        // tt = s2·h = INTT(NTT(s2) ⊙ NTT(h))
+        let mut tt = *s2_ntt;
+        poly_pointwise_mul(&mut tt, h_ntt);
+        ntt_inverse(&mut tt);
+        let mut s1 = [0i16; FALCON_512_N];
+        Self::check_s1_into(c0, &s2, &mut tt, &mut s1)
+    }
+
+    /// Computes s2·h in the ring Z_q[X]/(X^n + 1), with coefficients in [0, q-1].
+    ///
+    /// `h` must already be in NTT domain and Montgomery form, as produced by
+    /// `poly_prepare_for_mul`. This is the only ring multiplication in
+    /// verification, kept separate so it can be replaced by a host function
+    /// should one become available.
+    fn compute_s2h_ntt<const N: usize>(s2: &[i16; N], h: &[u16; N]) -> [u16; N] {
+        let mut tt = [0u16; N];
+        Self::compute_s2h_ntt_into(s2, h, &mut tt);
+        tt
+    }
+
+    /// [`compute_s2h_ntt`](Self::compute_s2h_ntt) writing into a caller-provided buffer.
+    fn compute_s2h_ntt_into<const N: usize>(s2: &[i16; N], h: &[u16; N], tt: &mut [u16; N]) {
+        // Step 1: Convert s2 from signed to unsigned representation mod q
+        // s2 values are in range [-q/2, q/2], convert to [0, q-1]. Folding
+        // this pass into the first NTT stage was measured and gained nothing
+        // above run-to-run noise: it is N cheap operations next to the
+        // 3 · N/2 · logn butterflies and the hashing.
+        for i in 0..N {
+            // If negative, add q to get equivalent positive value mod q
+            tt[i] = field_from_signed(s2[i] as i32) as u16;
+        }
+
+        // Step 2: Compute s2·h using NTT: multiply(a, b) = INTT(NTT(a) ⊙ NTT(b))
+        // Since h is already in NTT form, we only need to transform tt
+        ntt_forward(tt); // tt = NTT(s2)
+        poly_pointwise_mul(tt, h); // tt = NTT(s2) ⊙ NTT(h) = NTT(s2·h)
+        ntt_inverse(tt); // tt = s2·h
+    }
+
+    fn verify_raw_into<const N: usize>(
+        c0: &[u16; N],
+        s2: &[i16; N],
+        h: &[u16; N],
+        s1: &mut [i16; N],
+    ) -> bool {
+        // Steps 1-2: tt = s2·h
+        let mut tt = Self::compute_s2h_ntt(s2, h);
+        Self::check_s1_into(c0, s2, &mut tt, s1)
+    }
+
+    /// Steps 3-5 of verification: given tt = s2·h, writes s1 = c0 - s2·h and
+    /// checks the norm of (s1, s2). `tt` is overwritten.
+    fn check_s1_into<const N: usize>(
+        c0: &[u16; N],
+        s2: &[i16; N],
+        tt: &mut [u16; N],
+        s1: &mut [i16; N],
+    ) -> bool {
+        // Step 3: Compute s1 = c0 - s2·h  (equivalently, -s1 = s2·h - c0)
+        // Note: we compute tt = tt - c0, which gives us -s1
+        poly_sub(tt, c0);
+
+        // Step 4: Convert -s1 back to signed representation for norm computation
+        // Values in [0, q-1] are converted to [-q/2, q/2] (centered representation)
+        // Then negate to get s1
+        for i in 0..N {
+            // Center: if w > q/2, interpret as negative (w - q)
+            let w = field_center(tt[i] as u32);
+            // Negation doesn't affect the norm, but callers of verify_512_into
+            // expect s1 itself rather than -s1
+            s1[i] = -w as i16;
+        }
+
+        // Step 5: Verify that the signature vector (s1, s2) is short enough
+        Self::is_short(s1, s2) && !is_zero_poly(s2)
+    }
+
+    /// Verifies a Falcon-512 signature using a single caller-provided scratch buffer.
+    ///
+    /// Produces the same result as [`verify_512`](Self::verify_512) but keeps a
+    /// smaller working set: s2·h is computed in `scratch` (forward NTT,
+    /// pointwise multiply and inverse NTT all run in place), and the challenge
+    /// c0 is streamed from SHAKE256 into the norm computation instead of being
+    /// materialized alongside a separate s1 array.
+    ///
+    /// `scratch` holds no meaningful data on return and can be reused across calls.
+    pub fn verify_512_with_scratch(
+        pubkey: &[u8],
+        message: &[u8],
+        signature: &[u8],
+        scratch: &mut [u16; FALCON_512_N],
+    ) -> bool {
+        let sig = match FalconSignature::from_bytes(signature) {
+            Ok(sig) => sig,
+            Err(_) => return false,
+        };
+
+        if pubkey.first() != Some(&sig.logn()) {
+            return false;
+        }
+        let mut h = [0u16; FALCON_512_N];
+        if !Self::decode_pubkey(pubkey, &mut h) {
+            return false;
+        }
+        poly_prepare_for_mul(&mut h);
+
+        // tt = s2·h, computed entirely inside the scratch buffer
+        let tt = scratch;
+        for (t, &z) in tt.iter_mut().zip(sig.s2.iter()) {
+            *t = field_from_signed(z as i32) as u16;
+        }
+        ntt_forward(tt);
+        poly_pointwise_mul(tt, &h);
+        ntt_inverse(tt);
+
+        // s1 = c0 - s2·h, with c0 drawn coefficient by coefficient.
+        // Accumulation order and overflow handling match is_short().
+        let mut challenge = ChallengeStream::new(&sig.nonce, message);
+        let mut s: u32 = 0;
+        let mut ng: u32 = 0;
+        for (&t, &z) in tt.iter().zip(sig.s2.iter()) {
+            let Some(c) = challenge.next_coeff() else {
+                return false;
+            };
+            let c = c as u32;
+            let z1 = field_center(field_sub(c, t as u32));
+            s = s.wrapping_add((z1 * z1) as u32);
+            ng |= s;
+
+            let z2 = z as i32;
+            s = s.wrapping_add((z2 * z2) as u32);
+            ng |= s;
+
+            #[cfg(feature = "fast_reject")]
+            if s > L2_BOUND_512 {
+                return false;
+            }
+        }
+        s |= 0u32.wrapping_sub(ng >> 31);
+
+        ct_le(s, L2_BOUND_512) != 0 && !is_zero_poly(&sig.s2)
+    }
+
+    /// Verifies that ||(s1, s2)||² is within the bound for degree N.
+    ///
+    /// Always sums all 2N squared coefficients unless the `fast_reject`
+    /// feature is enabled, in which case it returns as soon as the running
+    /// sum exceeds the bound. That saves work on clearly invalid signatures
+    /// but makes the running time depend on the signature.
+    fn is_short<const N: usize>(s1: &[i16; N], s2: &[i16; N]) -> bool {
+        let bound = l2_bound(N);
+        let mut s: u32 = 0; // Running sum of squared coefficients
+        let mut ng: u32 = 0; // Overflow detector (accumulates sign bits)
+
+        for i in 0..N {
+            // Add s1[i]² to the sum
+            let z1 = s1[i] as i32;
+            s = s.wrapping_add((z1 * z1) as u32);
+            ng |= s; // Capture if sum went negative (overflow)
+
+            // Add s2[i]² to the sum
+            let z2 = s2[i] as i32;
+            s = s.wrapping_add((z2 * z2) as u32);
+            ng |= s; // Capture if sum went negative (overflow)
+
+            // Variable-time early exit. One pair adds less than 2^26, so the
+            // sum cannot wrap before it first exceeds the bound.
+            #[cfg(feature = "fast_reject")]
+            if s > bound {
+                return false;
+            }
+        }
+
+        // If overflow occurred (ng has sign bit set), force s to max value
+        // This ensures we reject potentially forged signatures that overflowed
+        // Expression: if ng bit 31 is set, OR s with 0xFFFFFFFF; else OR with 0
+        s |= 0u32.wrapping_sub(ng >> 31);
+
+        // The squared L2 norm must not exceed the bound
+        // ||(s1, s2)||² = Σ(s1[i]² + s2[i]²) ≤ bound
+        ct_le(s, bound) != 0
+    }
+
+    /// Decodes a Falcon-512 public key from its packed binary format (14 bits per coefficient, MSB-first).
+    ///
+    /// A key whose last 8 coefficients are all zero is rejected as truncated
+    /// and zero-padded; see [`ZERO_TAIL_COEFFS`].
+    pub fn decode_pubkey(pubkey: &[u8], h: &mut [u16; FALCON_512_N]) -> bool {
+        if pubkey.len() != FALCON_512_PUBKEY_SIZE {
+            return false;
+        }
+        if pubkey[0] != 9 {
+            // logn = 9 for Falcon-512
+            return false;
+        }
+
+        if !Self::decode_pubkey_coeffs(&pubkey[1..], h) {
+            return false;
+        }
+        #[cfg(feature = "strict_keys")]
+        if Self::ntt_has_zero(h) {
+            return false;
+        }
+        true
+    }
+
+    /// Whether h has a zero coefficient in the NTT domain, i.e. is not
+    /// invertible mod (q, X^n + 1).
+    ///
+    /// Falcon does not require h to be invertible, and about n/q of honestly
+    /// generated keys (4% for Falcon-512, 8% for Falcon-1024) are not, so rejecting them is a
+    /// deployment policy. Costs one extra forward NTT per key decode.
+    #[cfg(feature = "strict_keys")]
+    fn ntt_has_zero<const N: usize>(h: &[u16; N]) -> bool {
+        let mut t = *h;
+        ntt_forward(&mut t);
+        t.contains(&0)
+    }
+
+    /// Converts a decoded public key to the NTT/Montgomery form that
+    /// [`verify_raw_512`](Self::verify_raw_512) expects.
+    pub fn prepare_pubkey(h: &mut [u16; FALCON_512_N]) {
+        poly_prepare_for_mul(h);
+    }
+
+    /// Decodes a batch of Falcon-512 public keys, e.g. when onboarding accounts.
+    ///
+    /// Keys are decoded in order into the matching slot of `out`, stopping at
+    /// the first key that fails [`decode_pubkey`](Self::decode_pubkey) or
+    /// when `out` is full. The return value is the number of keys decoded, so
+    /// `keys[..n]` are valid and, if `n < keys.len()` and `out` had room,
+    /// `keys[n]` is the first invalid key. Slots past `n` are unspecified.
+    pub fn decode_pubkeys(keys: &[&[u8]], out: &mut [[u16; FALCON_512_N]]) -> usize {
+        let mut decoded = 0;
+        for (key, h) in keys.iter().zip(out.iter_mut()) {
+            if !Self::decode_pubkey(key, h) {
+                break;
+            }
+            decoded += 1;
+        }
+        decoded
+    }
+
+    /// Decodes N coefficients packed MSB-first at 14 bits each (no header byte).
+    fn decode_pubkey_coeffs<const N: usize>(data: &[u8], h: &mut [u16; N]) -> bool {
+        if data.len() != (N * 14).div_ceil(8) {
             return false;
         }
 
-        let data = &pubkey[1..]; // Skip header byte
         let mut acc: u32 = 0; // Bit accumulator (up to 32 bits)
         let mut acc_len = 0; // Number of valid bits in accumulator
         let mut u = 0; // Output coefficient index
         let mut buf_idx = 0; // Input byte index
 
-        // Extract 512 coefficients, each 14 bits
-        while u < FALCON_512_N {
+        // Extract N coefficients, each 14 bits
+        while u < N {
             // Load next byte into accumulator (MSB-first)
             acc = (acc << 8) | (data[buf_idx] as u32);
             buf_idx += 1;
@@ -234,254 +1473,2854 @@ impl FalconVerifier {
             }
         }
 
-        // Any leftover bits must be zero (proper padding)
-        if (acc & ((1u32 << acc_len) - 1)) != 0 {
-            return false;
+        // Any leftover bits must be zero (proper padding)
+        if (acc & ((1u32 << acc_len) - 1)) != 0 {
+            return false;
+        }
+
+        !has_zero_tail(h)
+    }
+
+    /// Decodes a Falcon-512 public key packed LSB-first (14 bits per coefficient).
+    ///
+    /// Same layout and validation as [`decode_pubkey`](Self::decode_pubkey),
+    /// but each coefficient is read starting from the least significant bit.
+    pub fn decode_pubkey_lsb(pubkey: &[u8], h: &mut [u16; FALCON_512_N]) -> bool {
+        if pubkey.len() != FALCON_512_PUBKEY_SIZE || pubkey[0] != FALCON_512_LOGN as u8 {
+            return false;
+        }
+
+        let mut acc: u32 = 0;
+        let mut acc_len = 0;
+        let mut coeffs = h.iter_mut();
+        for &byte in &pubkey[1..] {
+            // New bits go above the ones already buffered
+            acc |= (byte as u32) << acc_len;
+            acc_len += 8;
+            if acc_len >= 14 {
+                let w = acc & 0x3FFF;
+                if w >= Q {
+                    return false;
+                }
+                if let Some(c) = coeffs.next() {
+                    *c = w as u16;
+                }
+                acc >>= 14;
+                acc_len -= 14;
+            }
+        }
+
+        // 512 · 14 bits fill the 896 data bytes exactly, leaving nothing over
+        if acc_len != 0 || coeffs.next().is_some() || has_zero_tail(h) {
+            return false;
+        }
+        #[cfg(feature = "strict_keys")]
+        if Self::ntt_has_zero(h) {
+            return false;
+        }
+        true
+    }
+
+    /// Returns true if two public key blobs decode to the same polynomial h.
+    ///
+    /// Comparison is on the decoded coefficients rather than the raw bytes, so
+    /// key-rotation flows can reject a "new" key that merely re-encodes the
+    /// current one. The NTT is a bijection, so equal coefficients are equivalent
+    /// to equal NTT representations. Returns false if either key fails to decode.
+    pub fn pubkeys_equivalent(pk_a: &[u8], pk_b: &[u8]) -> bool {
+        let mut h_a = [0u16; FALCON_512_N];
+        let mut h_b = [0u16; FALCON_512_N];
+        if !Self::decode_pubkey(pk_a, &mut h_a) || !Self::decode_pubkey(pk_b, &mut h_b) {
+            return false;
+        }
+        h_a == h_b
+    }
+
+    /// Decodes a signature from compressed format. Returns bytes consumed, or 0 on error.
+    ///
+    /// Every coefficient vector has exactly one accepted encoding: the unary
+    /// high part stops at the first 1 bit, so an extra zero changes the value
+    /// rather than padding it, and "-0", magnitudes above 2047 and non-zero
+    /// trailing bits are all rejected.
+    ///
+    /// Unlike the CT format, the work here grows with the coefficient
+    /// magnitudes. Bodies whose unary parts add up to more than
+    /// [`max_unary_bits`] cannot be short enough to verify, so they are
+    /// rejected as soon as the total is exceeded rather than decoded in full.
+    fn decode_sig_compressed<const N: usize>(data: &[u8], s2: &mut [i16; N]) -> usize {
+        let mut acc: u32 = 0; // Bit accumulator
+        let mut acc_len: u32 = 0; // Valid bits in accumulator
+        let mut v = 0; // Input byte index
+        let mut unary_left = if N == FALCON_1024_N {
+            MAX_UNARY_BITS_1024
+        } else {
+            MAX_UNARY_BITS_512
+        };
+
+        for u in 0..N {
+            // Read next byte containing sign bit and low 7 bits
+            if v >= data.len() {
+                return 0;
+            }
+            acc = (acc << 8) | (data[v] as u32);
+            v += 1;
+
+            // Extract the 8 bits we just added
+            let b = acc >> acc_len;
+            let sign = b & 128; // Bit 7: sign (1 = negative)
+            let mut m = (b & 127) as u32; // Bits 0-6: low 7 bits of |value|
+
+            // Decode unary high part: count zeros until we hit a 1
+            loop {
+                if acc_len == 0 {
+                    // Need more bits
+                    if v >= data.len() {
+                        return 0;
+                    }
+                    acc = (acc << 8) | (data[v] as u32);
+                    v += 1;
+                    acc_len = 8;
+                }
+                acc_len -= 1;
+
+                if ((acc >> acc_len) & 1) != 0 {
+                    // Hit the terminating 1 bit
+                    break;
+                }
+                // Each 0 bit adds 128 to the magnitude
+                m += 128;
+                if m > 2047 {
+                    // Maximum allowed magnitude exceeded
+                    return 0;
+                }
+                if unary_left == 0 {
+                    // Too large in total to pass the norm bound
+                    return 0;
+                }
+                unary_left -= 1;
+            }
+
+            // "-0" is forbidden (use "+0" instead)
+            if sign != 0 && m == 0 {
+                return 0;
+            }
+
+            // Apply sign and store
+            s2[u] = if sign != 0 { -(m as i16) } else { m as i16 };
+        }
+
+        // Any leftover bits in accumulator must be zero
+        if (acc & ((1u32 << acc_len) - 1)) != 0 {
+            return 0;
+        }
+
+        v
+    }
+
+    /// Decodes a signature from constant-time (CT) format (12 bits per coefficient). Returns bytes consumed, or 0 on error.
+    fn decode_sig_ct<const N: usize>(data: &[u8], s2: &mut [i16; N]) -> usize {
+        const BITS: u32 = 12; // Bits per coefficient for Falcon-512 and Falcon-1024
+        let n = N;
+        let in_len = ((n as u32 * BITS) + 7) / 8; // Total bytes needed
+
+        if data.len() < in_len as usize {
+            return 0;
+        }
+
+        let mut acc: u32 = 0; // Bit accumulator
+        let mut acc_len: u32 = 0; // Valid bits in accumulator
+        let mask1 = (1u32 << BITS) - 1; // 0xFFF: mask for 12 bits
+        let mask2 = 1u32 << (BITS - 1); // 0x800: sign bit position
+        let mut buf_idx = 0;
+        let mut u = 0;
+
+        while u < n {
+            // Load next byte (MSB-first packing)
+            acc = (acc << 8) | (data[buf_idx] as u32);
+            buf_idx += 1;
+            acc_len += 8;
+
+            // Extract coefficients while we have enough bits
+            while acc_len >= BITS && u < n {
+                acc_len -= BITS;
+                let mut w = (acc >> acc_len) & mask1;
+
+                // Sign extension for negative values (two's complement)
+                // If bit 11 is set, the value is negative; extend sign to full u32
+                if (w & mask2) != 0 {
+                    w |= !mask1; // Set all upper bits to 1
+                }
+
+                // -2048 has no positive counterpart and is forbidden. w is
+                // already sign-extended, so compare against the full-width value.
+                if w == 0u32.wrapping_sub(mask2) {
+                    return 0;
+                }
+
+                s2[u] = w as i16;
+                u += 1;
+            }
+        }
+
+        // Any leftover bits in accumulator must be zero
+        if (acc & ((1u32 << acc_len) - 1)) != 0 {
+            return 0;
+        }
+
+        in_len as usize
+    }
+}
+
+#[cfg(feature = "profile")]
+impl FalconVerifier {
+    /// Verifies like [`verify_512`](Self::verify_512) and also returns the
+    /// number of NTT butterflies the call ran.
+    ///
+    /// A valid signature takes three transforms of N/2 · logn butterflies
+    /// each (four with `strict_keys`); rejected inputs may stop earlier.
+    /// Only available with the `profile` feature, which needs `std`.
+    pub fn verify_512_profiled(pubkey: &[u8], message: &[u8], signature: &[u8]) -> (bool, u64) {
+        let before = crate::ntt::profile::butterflies();
+        let valid = Self::verify_512(pubkey, message, signature);
+        (valid, crate::ntt::profile::butterflies() - before)
+    }
+}
+
+/// Budget charged by one call measured with [`FalconVerifier::measure`].
+#[cfg(feature = "testutils")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VerifyCost {
+    /// CPU instructions charged to the budget.
+    pub cpu: u64,
+    /// Memory bytes charged to the budget.
+    pub mem: u64,
+    /// What the measured call returned.
+    pub result: bool,
+}
+
+#[cfg(feature = "testutils")]
+impl FalconVerifier {
+    /// Runs `verify` against a freshly reset budget and reports what it
+    /// charged.
+    ///
+    /// The native budget meters host calls only, so verifying plain slices
+    /// charges next to nothing; measure calls that go through the contract
+    /// or read `Bytes` arguments. Only available with the `testutils`
+    /// feature.
+    pub fn measure(env: &soroban_sdk::Env, verify: impl FnOnce() -> bool) -> VerifyCost {
+        let mut budget = env.cost_estimate().budget();
+        budget.reset_default();
+        let result = verify();
+        VerifyCost {
+            cpu: budget.cpu_instruction_cost(),
+            mem: budget.memory_bytes_cost(),
+            result,
+        }
+    }
+}
+
+/// The largest coefficients of a signature that failed the norm check,
+/// reported by [`FalconVerifier::verify_512_debug`].
+#[cfg(feature = "debug")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NormDiagnostic {
+    /// ||(s1, s2)||², computed in 64 bits so it cannot wrap.
+    pub norm: u64,
+    /// Index and value of the largest-magnitude s1 coefficient, the lowest
+    /// index on ties.
+    pub s1_max: (usize, i16),
+    /// Index and value of the largest-magnitude s2 coefficient, the lowest
+    /// index on ties.
+    pub s2_max: (usize, i16),
+}
+
+#[cfg(feature = "debug")]
+impl NormDiagnostic {
+    fn new(s1: &[i16; FALCON_512_N], s2: &[i16; FALCON_512_N]) -> Self {
+        let largest = |poly: &[i16; FALCON_512_N]| {
+            let mut max = (0, poly[0]);
+            for (i, &c) in poly.iter().enumerate() {
+                if c.unsigned_abs() > max.1.unsigned_abs() {
+                    max = (i, c);
+                }
+            }
+            max
+        };
+        NormDiagnostic {
+            norm: s1
+                .iter()
+                .chain(s2.iter())
+                .map(|&c| (c as i64 * c as i64) as u64)
+                .sum(),
+            s1_max: largest(s1),
+            s2_max: largest(s2),
+        }
+    }
+}
+
+#[cfg(feature = "debug")]
+impl FalconVerifier {
+    /// Verifies like [`verify_512_detailed`](Self::verify_512_detailed) and,
+    /// when the norm check fails, reports the largest s1 and s2 coefficients
+    /// so signer developers can see which ones broke the bound.
+    ///
+    /// The diagnostic is present exactly when the error is
+    /// [`VerifyError::NormTooLarge`]. Only available with the `debug`
+    /// feature, which contract builds leave off.
+    pub fn verify_512_debug(
+        pubkey: &[u8],
+        message: &[u8],
+        signature: &[u8],
+    ) -> Result<(), (VerifyError, Option<NormDiagnostic>)> {
+        let sig = FalconSignature::from_bytes(signature).map_err(|e| (e, None))?;
+        let mut challenge = ChallengeStream::new(&sig.nonce, message);
+        Self::debug_with_challenge(pubkey, &sig, |c0| challenge.fill(c0))
+    }
+
+    fn debug_with_challenge(
+        pubkey: &[u8],
+        signature: &FalconSignature,
+        challenge: impl FnOnce(&mut [u16; FALCON_512_N]) -> bool,
+    ) -> Result<(), (VerifyError, Option<NormDiagnostic>)> {
+        let mut s1 = [0i16; FALCON_512_N];
+        match Self::verify_with_challenge(pubkey, signature, challenge, Packing::MsbFirst, &mut s1)
+        {
+            Err(VerifyError::NormTooLarge) => Err((
+                VerifyError::NormTooLarge,
+                Some(NormDiagnostic::new(&s1, &signature.s2)),
+            )),
+            result => result.map_err(|e| (e, None)),
+        }
+    }
+}
+
+/// Reusable working memory for Falcon-512 verification.
+///
+/// Holds every polynomial the verifier needs (about 5 KB) so that repeated
+/// verifications neither allocate nor zero fresh arrays on the stack. The
+/// context can live wherever the caller chooses, including a `static`, since
+/// [`new`](Self::new) is `const`. Results match [`FalconVerifier::verify_512`].
+pub struct FalconVerifyContext {
+    h: [u16; FALCON_512_N],
+    c0: [u16; FALCON_512_N],
+    tt: [u16; FALCON_512_N],
+    s1: [i16; FALCON_512_N],
+    s2: [i16; FALCON_512_N],
+}
+
+impl FalconVerifyContext {
+    /// Creates a context with zeroed buffers.
+    pub const fn new() -> Self {
+        FalconVerifyContext {
+            h: [0; FALCON_512_N],
+            c0: [0; FALCON_512_N],
+            tt: [0; FALCON_512_N],
+            s1: [0; FALCON_512_N],
+            s2: [0; FALCON_512_N],
+        }
+    }
+
+    /// Verifies a Falcon-512 signature using this context's buffers.
+    ///
+    /// The buffers carry no state between calls; every one is fully
+    /// overwritten before it is read.
+    ///
+    /// # Returns
+    /// `true` if the signature is valid, `false` otherwise.
+    pub fn verify_512(&mut self, pubkey: &[u8], message: &[u8], signature: &[u8]) -> bool {
+        let nonce = match parse_signature(signature, FALCON_512_LOGN as u8, &mut self.s2) {
+            Ok((_, nonce, _)) => nonce,
+            Err(_) => return false,
+        };
+
+        // decode_pubkey checks the size and the logn = 9 header
+        if !FalconVerifier::decode_pubkey(pubkey, &mut self.h) {
+            return false;
+        }
+        poly_prepare_for_mul(&mut self.h);
+
+        if !ChallengeStream::new(&nonce, message).fill(&mut self.c0) {
+            return false;
+        }
+
+        FalconVerifier::compute_s2h_ntt_into(&self.s2, &self.h, &mut self.tt);
+        FalconVerifier::check_s1_into(&self.c0, &self.s2, &mut self.tt, &mut self.s1)
+    }
+}
+
+impl Default for FalconVerifyContext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Falcon-512 verification split into resumable steps.
+///
+/// Only meant for budget-split scenarios: when a single call would exceed
+/// its budget, a host can run one [`step`](Self::step) per invocation and
+/// persist the state in between with [`to_bytes`](Self::to_bytes) and
+/// [`from_bytes`](Self::from_bytes). Each step runs one stage of
+/// [`FalconVerifier::verify_512`] (decode, hash, NTT, norm) and the final
+/// result is the same. Everywhere else, call `verify_512` directly.
+///
+/// The state owns only what the remaining stages need, not the inputs, so
+/// every step must be given the same public key, message and signature.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum VerifyState {
+    /// Nothing done yet; the next step decodes the key and signature.
+    Decode,
+    /// Key and signature decoded; the next step hashes the message to c0.
+    Hash {
+        nonce: [u8; NONCE_LEN],
+        s2: [i16; FALCON_512_N],
+        h: [u16; FALCON_512_N],
+    },
+    /// c0 computed; the next step computes s2·h with the NTT.
+    Ntt {
+        c0: [u16; FALCON_512_N],
+        s2: [i16; FALCON_512_N],
+        h: [u16; FALCON_512_N],
+    },
+    /// s2·h computed; the next step checks the norm of (c0 - s2·h, s2).
+    Norm {
+        c0: [u16; FALCON_512_N],
+        s2: [i16; FALCON_512_N],
+        tt: [u16; FALCON_512_N],
+    },
+    /// Verification finished with the given result.
+    Done(bool),
+}
+
+impl Default for VerifyState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl VerifyState {
+    /// Length of [`to_bytes`](Self::to_bytes): a tag byte and room for three
+    /// polynomials of 2-byte coefficients.
+    pub const ENCODED_LEN: usize = 1 + 3 * 2 * FALCON_512_N;
+
+    /// Starts a verification.
+    pub const fn new() -> Self {
+        VerifyState::Decode
+    }
+
+    /// Runs the next stage on `signature` over `message` under `pubkey`. A
+    /// finished state stays [`Done`](Self::Done).
+    pub fn step(self, pubkey: &[u8], message: &[u8], signature: &[u8]) -> Self {
+        match self {
+            VerifyState::Decode => {
+                let Ok(signature) = FalconSignature::from_bytes(signature) else {
+                    return VerifyState::Done(false);
+                };
+                // decode_pubkey checks the size and the logn = 9 header
+                let mut h = [0u16; FALCON_512_N];
+                if pubkey.first() != Some(&signature.logn())
+                    || !FalconVerifier::decode_pubkey(pubkey, &mut h)
+                {
+                    return VerifyState::Done(false);
+                }
+                VerifyState::Hash {
+                    nonce: signature.nonce,
+                    s2: signature.s2,
+                    h,
+                }
+            }
+            VerifyState::Hash { nonce, s2, h } => {
+                let mut c0 = [0u16; FALCON_512_N];
+                if !ChallengeStream::new(&nonce, message).fill(&mut c0) {
+                    return VerifyState::Done(false);
+                }
+                VerifyState::Ntt { c0, s2, h }
+            }
+            VerifyState::Ntt { c0, s2, mut h } => {
+                poly_prepare_for_mul(&mut h);
+                let mut tt = [0u16; FALCON_512_N];
+                FalconVerifier::compute_s2h_ntt_into(&s2, &h, &mut tt);
+                VerifyState::Norm { c0, s2, tt }
+            }
+            VerifyState::Norm { c0, s2, mut tt } => {
+                let mut s1 = [0i16; FALCON_512_N];
+                VerifyState::Done(FalconVerifier::check_s1_into(&c0, &s2, &mut tt, &mut s1))
+            }
+            done @ VerifyState::Done(_) => done,
+        }
+    }
+
+    /// The verification result once [`Done`](Self::Done), `None` before.
+    pub fn result(&self) -> Option<bool> {
+        match self {
+            VerifyState::Done(valid) => Some(*valid),
+            _ => None,
+        }
+    }
+
+    /// Encodes the state for storage between invocations.
+    ///
+    /// A tag byte (0 to 4, in variant order) is followed by the variant's
+    /// fields in declaration order: the nonce as is, coefficients as 2-byte
+    /// little-endian words, and the result as 0 or 1. The rest is zero.
+    pub fn to_bytes(&self) -> [u8; Self::ENCODED_LEN] {
+        let mut out = [0u8; Self::ENCODED_LEN];
+        let mut w = StateWriter {
+            out: &mut out,
+            pos: 1,
+        };
+        match self {
+            VerifyState::Decode => {}
+            VerifyState::Hash { nonce, s2, h } => {
+                w.out[0] = 1;
+                w.bytes(nonce);
+                w.signed(s2);
+                w.unsigned(h);
+            }
+            VerifyState::Ntt { c0, s2, h } => {
+                w.out[0] = 2;
+                w.unsigned(c0);
+                w.signed(s2);
+                w.unsigned(h);
+            }
+            VerifyState::Norm { c0, s2, tt } => {
+                w.out[0] = 3;
+                w.unsigned(c0);
+                w.signed(s2);
+                w.unsigned(tt);
+            }
+            VerifyState::Done(valid) => {
+                w.out[0] = 4;
+                w.bytes(&[*valid as u8]);
+            }
+        }
+        out
+    }
+
+    /// Decodes a state written by [`to_bytes`](Self::to_bytes).
+    ///
+    /// # Returns
+    /// `None` if `bytes` is not [`ENCODED_LEN`](Self::ENCODED_LEN) long, the
+    /// tag is unknown, a field is out of range (a coefficient of c0, h or
+    /// s2·h not below q, an s2 coefficient outside [-2047, 2047], a result
+    /// other than 0 or 1), or the unused tail is not zero.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != Self::ENCODED_LEN {
+            return None;
+        }
+        let mut r = StateReader { bytes, pos: 1 };
+        let state = match bytes[0] {
+            0 => VerifyState::Decode,
+            1 => VerifyState::Hash {
+                nonce: r.bytes()?,
+                s2: r.signed()?,
+                h: r.unsigned()?,
+            },
+            2 => VerifyState::Ntt {
+                c0: r.unsigned()?,
+                s2: r.signed()?,
+                h: r.unsigned()?,
+            },
+            3 => VerifyState::Norm {
+                c0: r.unsigned()?,
+                s2: r.signed()?,
+                tt: r.unsigned()?,
+            },
+            4 => match r.bytes::<1>()? {
+                [0] => VerifyState::Done(false),
+                [1] => VerifyState::Done(true),
+                _ => return None,
+            },
+            _ => return None,
+        };
+        bytes[r.pos..].iter().all(|&b| b == 0).then_some(state)
+    }
+}
+
+/// Appends [`VerifyState`] fields to its encoding.
+struct StateWriter<'a> {
+    out: &'a mut [u8; VerifyState::ENCODED_LEN],
+    pos: usize,
+}
+
+impl StateWriter<'_> {
+    fn bytes(&mut self, b: &[u8]) {
+        self.out[self.pos..self.pos + b.len()].copy_from_slice(b);
+        self.pos += b.len();
+    }
+
+    fn unsigned(&mut self, poly: &[u16; FALCON_512_N]) {
+        for &c in poly {
+            self.bytes(&c.to_le_bytes());
+        }
+    }
+
+    fn signed(&mut self, poly: &[i16; FALCON_512_N]) {
+        for &c in poly {
+            self.bytes(&c.to_le_bytes());
+        }
+    }
+}
+
+/// Reads [`VerifyState`] fields back, checking each coefficient's range.
+struct StateReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl StateReader<'_> {
+    fn bytes<const L: usize>(&mut self) -> Option<[u8; L]> {
+        let b = self.bytes.get(self.pos..self.pos + L)?.try_into().ok()?;
+        self.pos += L;
+        Some(b)
+    }
+
+    fn unsigned(&mut self) -> Option<[u16; FALCON_512_N]> {
+        let mut poly = [0u16; FALCON_512_N];
+        for c in poly.iter_mut() {
+            *c = u16::from_le_bytes(self.bytes()?);
+            if *c as u32 >= Q {
+                return None;
+            }
+        }
+        Some(poly)
+    }
+
+    fn signed(&mut self) -> Option<[i16; FALCON_512_N]> {
+        let mut poly = [0i16; FALCON_512_N];
+        for c in poly.iter_mut() {
+            *c = i16::from_le_bytes(self.bytes()?);
+            if !(-2047..=2047).contains(c) {
+                return None;
+            }
+        }
+        Some(poly)
+    }
+}
+
+/// Feeds a host `Bytes` object to the hasher in `STREAM_CHUNK`-sized pieces.
+fn absorb_bytes(hasher: &mut Shake256, data: &Bytes) {
+    let mut chunk = [0u8; STREAM_CHUNK as usize];
+    let len = data.len();
+    let mut start = 0;
+    while start < len {
+        let end = len.min(start + STREAM_CHUNK);
+        let part = &mut chunk[..(end - start) as usize];
+        data.slice(start..end).copy_into_slice(part);
+        hasher.update(part);
+        start = end;
+    }
+}
+
+/// Bytes read from the SHAKE256 output per call when sampling the challenge.
+const XOF_BLOCK: usize = 64;
+/// Most 2-byte samples one challenge stream draws before giving up, twice
+/// the coefficient count of the largest degree.
+const MAX_CHALLENGE_SAMPLES: u32 = 2 * FALCON_1024_N as u32;
+// Samples are 2 bytes and never straddle a block
+const _: () = assert!(XOF_BLOCK & 1 == 0);
+/// Challenge samples below this are accepted: the largest multiple of q
+/// below 2^16, so that every coefficient is equally likely.
+const ACCEPT_THRESHOLD: u32 = 5 * Q;
+
+/// Produces the challenge coefficients of SHAKE256(nonce || message) one at a time.
+///
+/// The XOF output is read `XOF_BLOCK` bytes at a time and consumed in 2-byte
+/// samples, which yields the same sequence as reading 2 bytes per sample.
+///
+/// SHAKE256 always runs in the guest: the host crypto API only exposes
+/// fixed-output `sha256` and `keccak256` (Keccak padding, 32 bytes), neither
+/// of which can produce this stream.
+struct ChallengeStream {
+    xof: Shake256Reader,
+    buf: [u8; XOF_BLOCK],
+    pos: usize,
+    samples_left: u32,
+}
+
+impl ChallengeStream {
+    fn new(nonce: &[u8], message: &[u8]) -> Self {
+        let mut hasher = Shake256::default();
+        hasher.update(nonce);
+        hasher.update(message);
+        Self::from_hasher(hasher)
+    }
+
+    /// Starts sampling from a hasher that has absorbed nonce || message.
+    fn from_hasher(hasher: Shake256) -> Self {
+        ChallengeStream {
+            xof: hasher.finalize_xof(),
+            buf: [0; XOF_BLOCK],
+            pos: XOF_BLOCK,
+            samples_left: MAX_CHALLENGE_SAMPLES,
+        }
+    }
+
+    /// Fills `c0` with the next N coefficients, or returns `false` if the
+    /// stream runs out of samples first.
+    fn fill<const N: usize>(&mut self, c0: &mut [u16; N]) -> bool {
+        self.fill_with(Q, ACCEPT_THRESHOLD, c0)
+    }
+
+    /// [`fill`](Self::fill) for modulus `q` and samples below
+    /// `accept_threshold`.
+    #[inline(always)]
+    fn fill_with<const N: usize>(
+        &mut self,
+        q: u32,
+        accept_threshold: u32,
+        c0: &mut [u16; N],
+    ) -> bool {
+        for c in c0.iter_mut() {
+            match self.next_coeff_with(q, accept_threshold) {
+                Some(v) => *c = v,
+                None => return false,
+            }
+        }
+        true
+    }
+
+    /// Returns the next coefficient in [0, q-1], skipping rejected samples,
+    /// or `None` once `MAX_CHALLENGE_SAMPLES` samples have been drawn.
+    fn next_coeff(&mut self) -> Option<u16> {
+        self.next_coeff_with(Q, ACCEPT_THRESHOLD)
+    }
+
+    /// [`next_coeff`](Self::next_coeff) for modulus `q` and samples below
+    /// `accept_threshold`.
+    #[inline(always)]
+    fn next_coeff_with(&mut self, q: u32, accept_threshold: u32) -> Option<u16> {
+        loop {
+            if self.samples_left == 0 {
+                return None;
+            }
+            self.samples_left -= 1;
+            if self.pos == XOF_BLOCK {
+                self.xof.read(&mut self.buf);
+                self.pos = 0;
+            }
+            let w = sample_word([self.buf[self.pos], self.buf[self.pos + 1]]);
+            self.pos += 2;
+
+            if w < accept_threshold {
+                let mut v = w;
+                while v >= q {
+                    v -= q;
+                }
+                return Some(v as u16);
+            }
+        }
+    }
+}
+
+/// Assembles a 16-bit challenge sample from two XOF bytes. Falcon reads
+/// them big-endian; flipping this breaks every signature.
+#[inline(always)]
+fn sample_word(bytes: [u8; 2]) -> u32 {
+    u16::from_be_bytes(bytes) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Generated with seed: 2a31383f464d545b626970777e858c939aa1a8afb6bdc4cbd2d9e0e7eef5fc030a11181f262d343b424950575e656c73
+    const TEST_PUBKEY_HEX: &str = "0902c671f64d92df6c446a63f5061d73fab61be667e74db66752251102a105922a6fe56a7b3a48196bafc22de2275600dfd8b4149842bf0a5f3b7df4e1f6608f5394aae63e918a7bc492426a62e64d1873fb72c020a3c6be3a9295bc29aaf1c351267c6b00ffc2aa003f64fa9133628b2996b4327b7ee6366b9acb4067e30715fcf68273e04880a453eb468eff0a8d563af3235c6cae44984e8ed8911a34222ed6ec3274f8c491893a9f74ab6b1d67daa0083eb666c098acd4745aa208362a8e14b906437c2cc1ca044a5b903724c9066cd662a622cc38165a4d91322e193c48d12b5e20977bdb4816d6c1aa6a8a4118705029de6fd8723d3ca408ea0c296ceba31e903fbbc9dd60b0c1ca74a1a995d3cf449518815ab29f227d257491f758630484e3a6e36c83008069e538e3e65272f0a5440d8e6998e516e1a5390045b986c24975567c8ce8eae5b29916797516c04f69085a0112e9295b8d96e878410e12507ff9ba012c1f352a84be660a467a95321c8947b07440d58ac215b9cc2ee3d2e5c5af1e9044aed41e94305390c5110c27e5ee3a620c898f90671911e58f75c1085551618b5b4443e3e3527955357007d8696bb59e0d625f248f513de19916a093b43ef00b8d8211a3801874c9687b792e9588a59622b748ae5adc1ff98d0040506cd7c720e64123631bdd70628fa2534bf1094d92b82f2d5fb586d715dee362ac6cd33268a3249669c853fde1643222968b072d07be36764962d3c6a0550038bce88219585357616fb63e701f923ae986247850c7c5ad74bd3e8cf342623cabb8e467fe55a1103975f9af1235995ca30bfe8ea9af0619a2995a283e5cd49bae9a9737201d152d253f50e526d55c59ae8675eeca051bbf44f4c9e530cdfca2c0b192cf8f779a85de921e06a48b71ac1170af6c50c16d3328149c5a682ceb18a01f1de6207319d54a5f205ff82d8ae5536a924721e68c83b82d47dbc0854db1d392e055e2702e8a9401e200616d43aa8c25075712b1f0274f097cf51423685a051d35afb9a9d3217e365e95d95bff5a31e8320bc423bc5052d1ec04739005090a8e6f95b53014129aa30b937cf157c6d0bfa77263e3a2d435954e30f790a4ca062e7d17aa2d52a5a4aec83108c12e24fcf97a9119554eadf26b5447b1d0d7e0484b58122a1b68aa15bd3e5db8927b4240785966f5cba8784b752d723a86c13c005ec57fe22bb18afd43d1093d232ac8b09f920d2a8cbec54e56f93edd6dd235a1ef";
+
+    // falcon512_0: "Hello, Falcon!" (0x48656c6c6f2c2046616c636f6e21)
+    const TEST_SIG_HELLO_HEX: &str = "399e11dbc7c5328dbdd260d989a2e58c18e698b7ee2c94235312fabbae38c24058d1dd43fe030b3f2583c4e2dcc445a1c76624aa2e2a0527fd6a6398a521b5c6d6391c9caf0729893d087fd672d38c0232e9ff98e313bebbe069e93a371de31f7e6c2905544a210fa3363aa23ce2418803d6b1fee2a275f3e8f2d6585ffa30ac2bf639345d78b1da59a2c1187a3f79190b3b788537993873fb9755bc8dd7723fbbefeaa5fd89a25298609f4f7ec5988292c4a976f833d6f312eaea792e53d9b49b31bd5bd20ee4bef5a887359d5c71e86e4d14c56848d23d65f2dd65775d2a0f47549d6289b1ab4897142aa12d7424ac17c4ce1ba84ea6094f448e0e57c53ea64521596220cdef215ad311b6d57723de37438ebae27d38fae24e81eefc98a88e9ea39d5418a53b9fd4912624ae4f81e219759ecb1759b6bee72de06285432f3c7c310c0b867b5afdff29658f45610854fbdecb1b04524cc0b6d16edccb37dace29db3becd6779ded4caa6f5a277b852d11ad2a46b8d731c6ef694c39bb3772532bc0f99757ab4ce76ae25d646c7dd8eecdee84b3b3040797975ff39782a11b8eb65507fe415c5a39b6862949f6eeb1c53c996f14be765154c9b239230990621e52513b5da72bcfc6a48433cefcb843a1127a2335d559161f9db54eb798bb15c65d4ad073f0d9f52cc6cba122ed824726758226cbe41d340bd495c131f891eecb1837b9df7e66e8695355fd5853e736d4bedc224063f08ac33b6e9bd5e21ad8ec52a2b14e225299399a26287f28c4d8a3567f3a685fa5dfa2f94ac8476b38793b7d4fd711bafb5ebeac3f65e70466a51455cba3946a6688e6cb14ef1386143efc7638f655910f751bd4ecc5168a142495937fb5afb5e84698a35d829ef83a387336c622f1b8b3bab64d9eca1a0000000000000000000000000000";
+
+    #[test]
+    fn test_is_short_zero() {
+        let s1 = [0i16; FALCON_512_N];
+        let s2 = [0i16; FALCON_512_N];
+        assert!(FalconVerifier::is_short(&s1, &s2));
+    }
+
+    #[test]
+    fn test_ct_le_matches_le() {
+        let values = [
+            0,
+            1,
+            L2_BOUND_512 - 1,
+            L2_BOUND_512,
+            L2_BOUND_512 + 1,
+            L2_BOUND_1024,
+            1 << 31,
+            u32::MAX - 1,
+            u32::MAX,
+        ];
+        for a in values {
+            for b in values {
+                let expected = if a <= b { u32::MAX } else { 0 };
+                assert_eq!(ct_le(a, b), expected, "{a} <= {b}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_is_short_small() {
+        let mut s1 = [0i16; FALCON_512_N];
+        let mut s2 = [0i16; FALCON_512_N];
+
+        // Small values should pass
+        for i in 0..FALCON_512_N {
+            s1[i] = ((i % 10) as i16) - 5;
+            s2[i] = ((i % 10) as i16) - 5;
+        }
+        assert!(FalconVerifier::is_short(&s1, &s2));
+    }
+
+    #[test]
+    fn test_pubkey_decode_header() {
+        let mut h = [0u16; FALCON_512_N];
+
+        // Wrong header should fail
+        let bad_pk = [8u8; FALCON_512_PUBKEY_SIZE]; // Wrong logn
+        assert!(!FalconVerifier::decode_pubkey(&bad_pk, &mut h));
+
+        // Too short should fail
+        let short_pk = [9u8; 100];
+        assert!(!FalconVerifier::decode_pubkey(&short_pk, &mut h));
+    }
+
+    #[test]
+    fn test_cross_verify_with_c_bindings() {
+        let pubkey = hex::decode(TEST_PUBKEY_HEX).expect("Invalid pubkey hex");
+
+        // falcon512_0: "Hello, Falcon!"
+        let sig_0_bytes = hex::decode(TEST_SIG_HELLO_HEX).expect("Invalid sig hex");
+        assert!(
+            FalconVerifier::verify_512(&pubkey, b"Hello, Falcon!", &sig_0_bytes),
+            "falcon512_0: 'Hello, Falcon!' verification failed"
+        );
+
+        // falcon512_1: "Test message for cross-implementation verification"
+        let sig_1 = "399e11dbc7c5328dbdd260d989a2e58c18e698b7ee2c94235312fabbae38c24058d1dd43fe030b3f2591405b77214f2bd4393a17b9c35ef79a4ce21eda5ef85a912da064952654634e34294e4b2dcfdc11257ad245db17ca89445ec4c3871ada38484bf8a2a96829c16edf3d335bb7e96438bbb42b81a490e849d0fc72b2d13eaa152e2549375acd1f1cd6fa9c934987574ac9d2f58cd4e6e5ce5ad91ecc9218d210f5cf173235b9b8e775fae71fc41560c7ab344fc489803372ea7da65b01b5678655d55e1465f218a0344831e968b78dfa696838c50731346792e306b54d64d28675d2c2c65f46caee820edf265bd8dff8fca8e10a4755797751fd643db8b35e68f7546e1292640af0daab7b641ea1f364e98ada0d85441b4ecdd7c947da6d965bb3e7c9bf469ac4c19c3a3cd949385d38aee31bacb0ed3bd65caad0a6dae9ead699b3bef43b4f33aaf34375d7be1f813ac11b26ca7f8179db36cb587a13e4a4f5382cbe65264d99be82daf8d9e4b6149a49d6c5eb14a76642db163912c7e4c7140d07073995d920eddc667f538ed9ed3851cc8cdda11c7d8d9bbcfe4e62f7d35fa561f2f2522850b9fe6a02a4b046596c8a710580b5843f971edac9547ca3aea815393669b6d952082f6be3245f19a8e3c2b97664c8e919ae9972c59acf6d2d5e6e28cf11654f4a32e764de3b295c372101cafbfaf1bf76651c4e99e1096e12f9747635bfa94098c529a36d85b664e7cfd319170a2ff1641a78ba79497970be9fe47ea3a2e660499e73273378377417f6327359b430d1b7ed38aab2bdea3fab0b9d281e8df529b8cf286cf18c506e6ca1b229f8a81c873486cf23f58105d7ec4aa4a2d255b16d9ac0bc7ad7caa7f53c26edd7d99848b34a360cc25eae5bb1cb8b150731216f742bbc2fe9bd6421b9c4000000000000000000";
+        let sig_1_bytes = hex::decode(sig_1).expect("Invalid sig hex");
+        assert!(
+            FalconVerifier::verify_512(
+                &pubkey,
+                b"Test message for cross-implementation verification",
+                &sig_1_bytes
+            ),
+            "falcon512_1: cross-impl verification failed"
+        );
+
+        // falcon512_2: empty message
+        let sig_2 = "399e11dbc7c5328dbdd260d989a2e58c18e698b7ee2c94235312fabbae38c24058d1dd43fe030b3f25bbafe2f1767a33929d6cbe92c46e1666c9e36c314cec389f476cf63a639a984e46fd63e4ec65fae59abb3e4570d016d67b6f52bdff6eef1d24d0a20869518d31667dabbd77b3063317b8ce5fa7b94eab750a929066395fbe54fd8897bfe517e12826813c94d2ad9e384391992d8da2851430ba8c0e9d8d547a7525827f0382a13c4e1aab19e98957810975a0d822992439fc03dcd5f9bcb1971e30d87234ec67462dc6d75b5e9a0db6f53f675e5c522951640d675ed096bdfe8889a4b2686829b21eeec48c35662bac39b8e723edaf71920519dbe357366c3c2a7272f192d21315fc7c7749e993aae132cb29dcd41b197e7997f7652c971824438351984c151d06192177319f9da62be786966e495695c4e82d99cb9fcd66e86a3e84d25c56a2c8ea4fddf2ab9c2c1c53acd597aee372867db08fb4f3b92e569027115a475dfed273599a51ed460d35ca7be3f99c22018da0b9c976e20fce8714d71687dfce50588336aeb6d48f926e81b8e9a5aaa9f2702c3bd5baf3b3a9e28956a2118fab99e8ff2e16b44856c83953e6273ce46655a3460ae996ba4520a7a722be6b1a0628802f9c4822b7a27ee529a419fa9d6a767d643fd1a9eea66bf68efd4f92a5f005d48323150b2e5d9379147218a0bb7853067af0faac2cbd3a879d3f87850935b0056bc703bdc3ae33fb2cff849d4e59af2b44ee76316a572d45155d7aaecaf2b3fbe271de6cb8e7063c9ad53ca428fa6f60b3a510a260fd091c810a605ef652e542c633deb1c0b31a662b61a2c3a00a6f8bbcc8582db5861e45998f6b60142ab4fa6ade67497c6d8f65f5c604e7efab1cc9ca79e38ddaa7b72b01ddd9ef1318f61e00000000000000000000000000000000";
+        let sig_2_bytes = hex::decode(sig_2).expect("Invalid sig hex");
+        let empty_msg: &[u8] = b"";
+        assert!(
+            FalconVerifier::verify_512(&pubkey, empty_msg, &sig_2_bytes),
+            "falcon512_2: empty message verification failed"
+        );
+
+        // falcon512_3: binary data 0x00..0x63 (100 bytes)
+        let sig_3 = "399e11dbc7c5328dbdd260d989a2e58c18e698b7ee2c94235312fabbae38c24058d1dd43fe030b3f2584574f5f13cef9416249e48bd1e249b63af2728c4871e45b21a271d0432b256616b63300cce2dce131833da501e2c7eb7455dd03875579e2c89b553ebd2b9274d19a56f2c4093875b8924ebb1e6b13b61d0868dc5e2aa9a0dfbf0a9f8fa915e238586dc289068d3d32d8269a8e715f99e99072b2d3f306dea87cbcca090353a12dcb3672b3ecda9a9fc6dbdae9e8a5254357384fa8cf6b052084d67fae0479d187e3a3e85a24deb948ecfa8ace45f88d7ed2f50aa4b43a4d65d5c161556bc507debbe9fe9a9c85074688658f84e943e5ffa259af6d5e999dc3f369345d82957f1f6dab8f2d8316c48d21628cd61341313124133291c563892262dca51a95a18f6e77c503d78984dc180617694c49e96b0a95b3a9eee16ab89cae13fb5fa62c824bf776a55f9bd8fff777ba24817d9eca896569077aa416fa16f5ba64ef542429d55cfe3b6410a9525e8fe4655774b3648620b7315cb6cd232a15b358beca70e40e01df74a5bcc74f3066a1ad1cf39eb972fa0bec360beeae2a7913ea4e94033369c9264a7259677aa51c23fd0ec617fe96370cff654541a3a2fc51335f2ebe65f1373a2479fb23066bcf9e6b1d2acf0fdd114c5249560e58311c698c03abefa12d570466286b9ca993837e5d6bfcadb14f7498736b5d22f86ed25ddeab3509a1aa39442f51ae9faeac4a81a573abff6b66253cad32dd774244c62ab74e13226f91b314e5b39daa0237bed0ba0a0ecb356cf27f2ac9b483e0f4c4e3a605ee4f7aba4e567674e7fca18e6a268944a82cdaeaec73bde42b9adab7ac5ad2b294778e8da0ba34e97555ce69bbbffbd640a025d5ba449e98286c4350c7346e4f2935adf00e9628f7c00000000000000000000000";
+        let sig_3_bytes = hex::decode(sig_3).expect("Invalid sig hex");
+        let binary_msg: [u8; 100] = core::array::from_fn(|i| i as u8);
+        assert!(
+            FalconVerifier::verify_512(&pubkey, &binary_msg, &sig_3_bytes),
+            "falcon512_3: binary data verification failed"
+        );
+
+        // Negative tests - wrong messages should fail
+        assert!(
+            !FalconVerifier::verify_512(&pubkey, b"Wrong message", &sig_0_bytes),
+            "Wrong message should fail"
+        );
+        assert!(
+            !FalconVerifier::verify_512(&pubkey, b"Hello, Falcon!", &sig_1_bytes),
+            "Mismatched signature should fail"
+        );
+        assert!(
+            !FalconVerifier::verify_512(&pubkey, b"Hello, Falcon!", &sig_2_bytes),
+            "Empty msg signature with 'Hello, Falcon!' should fail"
+        );
+    }
+
+    #[test]
+    fn test_verify_512_into_recovers_short_s1() {
+        let pubkey = hex::decode(TEST_PUBKEY_HEX).unwrap();
+        let sig = hex::decode(TEST_SIG_HELLO_HEX).unwrap();
+
+        let mut s1 = [0i16; FALCON_512_N];
+        assert!(FalconVerifier::verify_512_into(
+            &pubkey,
+            b"Hello, Falcon!",
+            &sig,
+            &mut s1
+        ));
+        assert!(s1.iter().any(|&c| c != 0), "s1 should have been written");
+
+        let mut s2 = [0i16; FALCON_512_N];
+        assert_ne!(
+            FalconVerifier::decode_sig_compressed(&sig[41..], &mut s2),
+            0
+        );
+
+        let norm: u64 = s1
+            .iter()
+            .chain(s2.iter())
+            .map(|&c| (c as i64 * c as i64) as u64)
+            .sum();
+        assert!(norm <= L2_BOUND_512 as u64);
+        assert!(FalconVerifier::is_short(&s1, &s2));
+    }
+
+    /// Packs s2 into the 12-bit two's complement CT body (MSB-first).
+    fn encode_ct_body(s2: &[i16; FALCON_512_N]) -> [u8; 768] {
+        let mut out = [0u8; 768];
+        let mut acc: u32 = 0;
+        let mut acc_len = 0;
+        let mut v = 0;
+        for &c in s2.iter() {
+            acc = (acc << 12) | ((c as u32) & 0xFFF);
+            acc_len += 12;
+            while acc_len >= 8 {
+                acc_len -= 8;
+                out[v] = (acc >> acc_len) as u8;
+                v += 1;
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn test_check_decoded_len_bounds() {
+        use SigFormat::{Compressed, ConstantTime, Padded};
+        let check = check_decoded_len;
+        let err = Err(VerifyError::SignatureDecodeFailed);
+
+        // A 666-byte padded signature whose body decodes from its first 600 bytes
+        let mut body = [0u8; 666 - 41];
+        body[..600].fill(0x55);
+        for format in [Compressed, Padded] {
+            assert_eq!(check(format, 9, &body, 600), Ok(()));
+            assert_eq!(check(format, 9, &body, body.len()), Ok(()));
+            // Counts the decoder could only report by mistake
+            for bad in [0, body.len() + 1, usize::MAX] {
+                assert_eq!(check(format, 9, &body, bad), err, "{format:?} {bad}");
+            }
+            // Decoded bytes left in the "padding"
+            assert_eq!(check(format, 9, &body, 599), err);
+            // Padding only at exactly the padded size
+            assert_eq!(check(format, 9, &body[..body.len() - 1], 600), err);
+            assert_eq!(check(format, 10, &body, 600), err);
+        }
+
+        let ct = [0u8; 768];
+        assert_eq!(check(ConstantTime, 9, &ct, 768), Ok(()));
+        for bad in [0, 767, 769, usize::MAX] {
+            assert_eq!(check(ConstantTime, 9, &ct, bad), err, "{bad}");
+        }
+    }
+
+    #[test]
+    fn test_decode_ct_sign_extension_boundaries() {
+        let mut s2 = [0i16; FALCON_512_N];
+
+        // Coefficients 0 and 1 share three bytes: 0x7FF (+2047) then 0x801 (-2047)
+        let mut body = [0u8; 768];
+        body[..3].copy_from_slice(&[0x7F, 0xF8, 0x01]);
+        // Coefficients 2 and 3 swapped: 0x801 then 0x7FF
+        body[3..6].copy_from_slice(&[0x80, 0x17, 0xFF]);
+        // Coefficient 511 ends the body: 0xFFF (-1)
+        body[766..].copy_from_slice(&[0x0F, 0xFF]);
+        assert_eq!(FalconVerifier::decode_sig_ct(&body, &mut s2), 768);
+        assert_eq!(s2[..4], [2047, -2047, -2047, 2047]);
+        assert!(s2[4..511].iter().all(|&c| c == 0));
+        assert_eq!(s2[511], -1);
+        assert_eq!(encode_ct_body(&s2), body);
+
+        // 0x800 (-2048) is rejected in either half of a byte triple
+        for (at, bytes) in [(0, [0x80, 0x00, 0x00]), (3, [0x00, 0x08, 0x00])] {
+            let mut bad = body;
+            bad[at..at + 3].copy_from_slice(&bytes);
+            assert_eq!(FalconVerifier::decode_sig_ct(&bad, &mut s2), 0, "{at}");
+        }
+        let mut bad = body;
+        bad[766] = 0x08;
+        bad[767] = 0x00;
+        assert_eq!(FalconVerifier::decode_sig_ct(&bad, &mut s2), 0);
+
+        // Exactly 768 bytes are consumed, however many are passed
+        let mut long = [0u8; 800];
+        long[..768].copy_from_slice(&body);
+        long[768..].fill(0xAA);
+        assert_eq!(FalconVerifier::decode_sig_ct(&long, &mut s2), 768);
+        assert_eq!(FalconVerifier::decode_sig_ct(&body[..767], &mut s2), 0);
+        let mut s2_1024 = [0i16; FALCON_1024_N];
+        assert_eq!(
+            FalconVerifier::decode_sig_ct(&[0u8; 1536], &mut s2_1024),
+            1536
+        );
+        assert_eq!(FalconVerifier::decode_sig_ct(&[0u8; 1535], &mut s2_1024), 0);
+    }
+
+    #[test]
+    fn test_parse_signature_formats() {
+        let padded = hex::decode(TEST_SIG_HELLO_HEX).unwrap();
+        let parsed = FalconSignature::from_bytes(&padded).unwrap();
+        assert_eq!(parsed.header(), 0x39);
+        assert_eq!(parsed.format(), SigFormat::Compressed);
+        assert_eq!(&parsed.nonce()[..], &padded[1..41]);
+
+        // Compressed: same body with the zero padding stripped
+        let mut s2 = [0i16; FALCON_512_N];
+        let body_len = FalconVerifier::decode_sig_compressed(&padded[41..], &mut s2);
+        let compressed = &padded[..41 + body_len];
+        let parsed_c = FalconSignature::from_bytes(compressed).unwrap();
+        assert_eq!(parsed_c.format(), SigFormat::Compressed);
+        assert_eq!(parsed_c.s2(), parsed.s2());
+
+        // Padded header (0x29) with the zero-padded body
+        let mut padded_hdr = padded.clone();
+        padded_hdr[0] = 0x29;
+        let parsed_p = FalconSignature::from_bytes(&padded_hdr).unwrap();
+        assert_eq!(parsed_p.format(), SigFormat::Padded);
+        assert_eq!(parsed_p.s2(), parsed.s2());
+
+        // CT: same s2 re-encoded as 12-bit coefficients
+        let mut ct = [0u8; 41 + 768];
+        ct[0] = 0x59;
+        ct[1..41].copy_from_slice(&padded[1..41]);
+        ct[41..].copy_from_slice(&encode_ct_body(parsed.s2()));
+        let parsed_ct = FalconSignature::from_bytes(&ct).unwrap();
+        assert_eq!(parsed_ct.format(), SigFormat::ConstantTime);
+        assert_eq!(parsed_ct.s2(), parsed.s2());
+
+        let pubkey = hex::decode(TEST_PUBKEY_HEX).unwrap();
+        for sig in [&parsed, &parsed_c, &parsed_p, &parsed_ct] {
+            assert!(FalconVerifier::verify_512_parsed(
+                &pubkey,
+                b"Hello, Falcon!",
+                sig
+            ));
+        }
+        assert!(FalconVerifier::verify_512(&pubkey, b"Hello, Falcon!", &ct));
+    }
+
+    #[test]
+    fn test_parse_signature_errors() {
+        let sig = hex::decode(TEST_SIG_HELLO_HEX).unwrap();
+
+        assert_eq!(
+            FalconSignature::from_bytes(&sig[..41]),
+            Err(VerifyError::InvalidSignatureSize)
+        );
+
+        let mut bad = sig.clone();
+        bad[0] = 0x49; // Unknown format nibble
+        assert_eq!(
+            FalconSignature::from_bytes(&bad),
+            Err(VerifyError::BadFormat)
+        );
+        bad[0] = 0x3A; // logn = 10
+        assert_eq!(
+            FalconSignature::from_bytes(&bad),
+            Err(VerifyError::BadFormat)
+        );
+
+        let mut bad = sig.clone();
+        *bad.last_mut().unwrap() = 1; // Non-zero padding
+        assert_eq!(
+            FalconSignature::from_bytes(&bad),
+            Err(VerifyError::SignatureDecodeFailed)
+        );
+    }
+
+    #[test]
+    fn test_verify_512_with_digest() {
+        let pubkey = hex::decode(TEST_PUBKEY_HEX).unwrap();
+        let sig = hex::decode(TEST_SIG_HELLO_HEX).unwrap();
+        let msg = b"Hello, Falcon!";
+
+        let digest = FalconVerifier::verify_512_with_digest(&pubkey, msg, &sig).unwrap();
+        assert_eq!(
+            digest,
+            FalconVerifier::verify_512_with_digest(&pubkey, msg, &sig).unwrap()
+        );
+        assert_eq!(digest, <[u8; 32]>::from(Sha3_256::digest(msg)));
+        assert_ne!(
+            digest,
+            <[u8; 32]>::from(Sha3_256::digest(b"Hello, Falcon?"))
+        );
+
+        assert_eq!(
+            FalconVerifier::verify_512_with_digest(&pubkey, b"Wrong message", &sig),
+            None
+        );
+        assert_eq!(
+            FalconVerifier::verify_512_with_digest(&pubkey, msg, &sig[..41]),
+            None
+        );
+    }
+
+    #[cfg(feature = "profile")]
+    #[test]
+    fn test_verify_512_profiled() {
+        let pubkey = hex::decode(TEST_PUBKEY_HEX).unwrap();
+        let sig = hex::decode(TEST_SIG_HELLO_HEX).unwrap();
+        let per_ntt = (FALCON_512_N / 2) as u64 * FALCON_512_LOGN as u64;
+        let transforms = if cfg!(feature = "strict_keys") { 4 } else { 3 };
+
+        assert_eq!(
+            FalconVerifier::verify_512_profiled(&pubkey, b"Hello, Falcon!", &sig),
+            (true, transforms * per_ntt)
+        );
+        // The norm check comes after all transforms
+        assert_eq!(
+            FalconVerifier::verify_512_profiled(&pubkey, b"Wrong message", &sig),
+            (false, transforms * per_ntt)
+        );
+        // Malformed signatures are rejected before any transform
+        assert_eq!(
+            FalconVerifier::verify_512_profiled(&pubkey, b"Hello, Falcon!", &sig[..41]),
+            (false, 0)
+        );
+    }
+
+    #[cfg(feature = "profile")]
+    #[test]
+    fn test_ntt_work_scales_n_log_n() {
+        use crate::ntt::profile::butterflies;
+
+        let pubkey = hex::decode(TEST_PUBKEY_HEX).unwrap();
+        let sig = hex::decode(TEST_SIG_HELLO_HEX).unwrap();
+        let (valid, work_512) =
+            FalconVerifier::verify_512_profiled(&pubkey, b"Hello, Falcon!", &sig);
+        assert!(valid);
+
+        // Falcon-1024 with h = 1 + x^1023 and s2 = 1: well formed, so every
+        // transform runs before the norm check rejects it
+        let mut pubkey = [0u8; FALCON_1024_PUBKEY_SIZE];
+        pubkey[0] = FALCON_1024_LOGN as u8;
+        pubkey[2] = 0x04;
+        pubkey[FALCON_1024_PUBKEY_SIZE - 1] = 0x01;
+        let mut sig = [0u8; 1 + NONCE_LEN + 9 * FALCON_1024_N / 8];
+        sig[0] = 0x3A;
+        sig[1 + NONCE_LEN] = 0x01;
+        for i in 0..FALCON_1024_N {
+            let bit = 9 * i + 8;
+            sig[1 + NONCE_LEN + bit / 8] |= 0x80 >> (bit % 8);
+        }
+        let before = butterflies();
+        assert!(!FalconVerifier::verify_1024(
+            &pubkey,
+            b"Hello, Falcon!",
+            &sig
+        ));
+        let work_1024 = butterflies() - before;
+
+        // Both run three transforms, plus the key check with strict_keys.
+        // Doubling n costs 2·10/9 ≈ 2.2x per transform with n log n
+        // butterflies; a quadratic step would push this towards 4x
+        let ratio = work_1024 as f64 / work_512 as f64;
+        assert!((2.0..2.5).contains(&ratio), "ratio {ratio}");
+    }
+
+    #[test]
+    fn test_signature_without_body_rejected() {
+        let pubkey = hex::decode(TEST_PUBKEY_HEX).unwrap();
+        let sig = hex::decode(TEST_SIG_HELLO_HEX).unwrap();
+        let msg = b"Hello, Falcon!";
+
+        // Header and nonce only, then a single body byte
+        for len in [1 + NONCE_LEN, 2 + NONCE_LEN] {
+            let truncated = &sig[..len];
+            assert_eq!(
+                FalconSignature::from_bytes(truncated),
+                Err(VerifyError::InvalidSignatureSize)
+            );
+            assert_eq!(
+                FalconVerifier::verify_512_detailed(&pubkey, msg, truncated),
+                Err(VerifyError::InvalidSignatureSize)
+            );
+            assert!(!FalconVerifier::verify_512(&pubkey, msg, truncated));
+            assert!(!FalconVerifier::can_decode_signature(truncated));
+            let mut s2 = [0i16; FALCON_1024_N];
+            assert_eq!(
+                parse_signature(truncated, 10, &mut s2),
+                Err(VerifyError::InvalidSignatureSize)
+            );
+        }
+    }
+
+    #[test]
+    fn test_signature_size_boundaries() {
+        let pubkey = hex::decode(TEST_PUBKEY_HEX).unwrap();
+        let sig = hex::decode(TEST_SIG_HELLO_HEX).unwrap();
+        let msg = b"Hello, Falcon!";
+        let parsed = FalconSignature::from_bytes(&sig).unwrap();
+        let (min, max) = (FALCON_SIG_MIN_SIZE as usize, FALCON_SIG_MAX_SIZE as usize);
+
+        let mut ct = [0u8; 41 + 768];
+        ct[0] = 0x59;
+        ct[1..41].copy_from_slice(&sig[1..41]);
+        ct[41..].copy_from_slice(&encode_ct_body(parsed.s2()));
+        assert_eq!(ct.len(), max);
+        let mut ct_long = ct.to_vec();
+        ct_long.push(0);
+        let mut compressed_long = sig.clone();
+        compressed_long.resize(max + 1, 0);
+
+        assert_eq!(
+            FalconVerifier::verify_512_detailed(&pubkey, msg, &sig[..min - 1]),
+            Err(VerifyError::InvalidSignatureSize)
+        );
+        // The shortest body is 512 zero coefficients; it decodes but is not short
+        let (zero_body, zero_len) = encode_compressed_body(&[0; FALCON_512_N]);
+        assert_eq!(41 + zero_len, min);
+        let mut zero = sig[..41].to_vec();
+        zero.extend_from_slice(&zero_body[..zero_len]);
+        assert_eq!(
+            FalconVerifier::verify_512_detailed(&pubkey, msg, &zero),
+            Err(VerifyError::NormTooLarge)
+        );
+        // A minimum-size prefix of a longer body passes the size check only
+        assert_eq!(
+            FalconVerifier::verify_512_detailed(&pubkey, msg, &sig[..min]),
+            Err(VerifyError::SignatureDecodeFailed)
+        );
+        assert_eq!(
+            FalconVerifier::verify_512_detailed(&pubkey, msg, &ct),
+            Ok(())
+        );
+        for bad in [&ct_long, &compressed_long] {
+            assert_eq!(
+                FalconVerifier::verify_512_detailed(&pubkey, msg, bad),
+                Err(VerifyError::InvalidSignatureSize)
+            );
+        }
+    }
+
+    #[test]
+    fn test_format_length_mismatch_rejected() {
+        let pubkey = hex::decode(TEST_PUBKEY_HEX).unwrap();
+        let sig = hex::decode(TEST_SIG_HELLO_HEX).unwrap();
+        let parsed = FalconSignature::from_bytes(&sig).unwrap();
+        let mut s2 = [0i16; FALCON_512_N];
+        let compressed_len = 41 + FalconVerifier::decode_sig_compressed(&sig[41..], &mut s2);
+
+        let mut ct = [0u8; 41 + 768];
+        ct[0] = 0x59;
+        ct[1..41].copy_from_slice(&sig[1..41]);
+        ct[41..].copy_from_slice(&encode_ct_body(parsed.s2()));
+
+        let mut padded = sig.clone();
+        padded[0] = 0x29;
+        let mut padded_long = padded.clone();
+        padded_long.push(0);
+        let mut ct_header = sig.clone();
+        ct_header[0] = 0x59;
+        let mut ct_long = ct.to_vec();
+        ct_long.push(0);
+
+        let cases: [(&str, &[u8]); 5] = [
+            ("padded, longer than 666", &padded_long),
+            ("CT, padded length", &ct_header),
+            ("CT, compressed length", &ct_header[..compressed_len]),
+            ("CT, truncated", &ct[..ct.len() - 1]),
+            ("CT, trailing byte", &ct_long),
+        ];
+        for (name, bad) in cases {
+            assert_eq!(
+                FalconSignature::from_bytes(bad),
+                Err(VerifyError::InvalidSignatureSize),
+                "{name}"
+            );
+            assert!(
+                !FalconVerifier::verify_512(&pubkey, b"Hello, Falcon!", bad),
+                "{name}"
+            );
+        }
+
+        // Lengths that fit are still accepted. The NIST KAT uses the padded
+        // header on unpadded bodies, so shorter padded signatures stay valid.
+        for ok in [
+            &padded[..],
+            &padded[..compressed_len],
+            &ct[..],
+            &sig[..compressed_len],
+        ] {
+            assert!(FalconVerifier::verify_512(&pubkey, b"Hello, Falcon!", ok));
+        }
+    }
+
+    #[test]
+    fn test_verify_with_scratch_matches_verify_512() {
+        let pubkey = hex::decode(TEST_PUBKEY_HEX).unwrap();
+        let sig = hex::decode(TEST_SIG_HELLO_HEX).unwrap();
+        let mut scratch = [0u16; FALCON_512_N];
+
+        for msg in [&b"Hello, Falcon!"[..], b"Wrong message", b""] {
+            assert_eq!(
+                FalconVerifier::verify_512_with_scratch(&pubkey, msg, &sig, &mut scratch),
+                FalconVerifier::verify_512(&pubkey, msg, &sig)
+            );
+        }
+        assert!(FalconVerifier::verify_512_with_scratch(
+            &pubkey,
+            b"Hello, Falcon!",
+            &sig,
+            &mut scratch
+        ));
+    }
+
+    #[test]
+    fn test_compute_s2h_ntt_matches_inline() {
+        let pubkey = hex::decode(TEST_PUBKEY_HEX).unwrap();
+        let sig = hex::decode(TEST_SIG_HELLO_HEX).unwrap();
+        let sig = FalconSignature::from_bytes(&sig).unwrap();
+
+        let mut h = [0u16; FALCON_512_N];
+        assert!(FalconVerifier::decode_pubkey(&pubkey, &mut h));
+        poly_prepare_for_mul(&mut h);
+
+        // The multiplication as verify_512_with_scratch performs it in place
+        let mut inline = [0u16; FALCON_512_N];
+        for (t, &z) in inline.iter_mut().zip(sig.s2().iter()) {
+            *t = field_from_signed(z as i32) as u16;
+        }
+        ntt_forward(&mut inline);
+        poly_pointwise_mul(&mut inline, &h);
+        ntt_inverse(&mut inline);
+
+        let s2h = FalconVerifier::compute_s2h_ntt(sig.s2(), &h);
+        assert_eq!(s2h, inline);
+        assert!(s2h.iter().all(|&c| (c as u32) < Q));
+    }
+
+    #[test]
+    fn test_verify_context_matches_verify_512() {
+        let pubkey = hex::decode(TEST_PUBKEY_HEX).unwrap();
+        let sig = hex::decode(TEST_SIG_HELLO_HEX).unwrap();
+        let mut ctx = FalconVerifyContext::new();
+
+        // Interleave valid and invalid inputs so stale buffers would show up
+        let mut bad_sig = sig.clone();
+        bad_sig[50] ^= 0x01;
+        let cases: [(&[u8], &[u8], &[u8]); 5] = [
+            (&pubkey, b"Hello, Falcon!", &sig),
+            (&pubkey, b"Wrong message", &sig),
+            (&pubkey, b"Hello, Falcon!", &bad_sig),
+            (&pubkey[..100], b"Hello, Falcon!", &sig),
+            (&pubkey, b"Hello, Falcon!", &sig),
+        ];
+        for (pk, msg, sig) in cases {
+            assert_eq!(
+                ctx.verify_512(pk, msg, sig),
+                FalconVerifier::verify_512(pk, msg, sig)
+            );
+        }
+        assert!(ctx.verify_512(&pubkey, b"Hello, Falcon!", &sig));
+    }
+
+    #[test]
+    fn test_verify_context_reuse_matches_verify_512() {
+        let pubkey = hex::decode(TEST_PUBKEY_HEX).unwrap();
+        let sig = hex::decode(TEST_SIG_HELLO_HEX).unwrap();
+        let mut bad_sig = sig.clone();
+        bad_sig[60] ^= 0x10;
+        let mut ctx = FalconVerifyContext::new();
+
+        // Run every case twice through the same context, valid ones last
+        let cases: [(&[u8], &[u8], &[u8]); 4] = [
+            (&pubkey, b"Hello, Falcon!", &bad_sig),
+            (&sig, b"Hello, Falcon!", &pubkey),
+            (&pubkey, b"", &sig),
+            (&pubkey, b"Hello, Falcon!", &sig),
+        ];
+        for _ in 0..2 {
+            for (pk, msg, sig) in cases {
+                assert_eq!(
+                    ctx.verify_512(pk, msg, sig),
+                    FalconVerifier::verify_512(pk, msg, sig)
+                );
+            }
+        }
+        assert!(ctx.verify_512(&pubkey, b"Hello, Falcon!", &sig));
+    }
+
+    #[test]
+    fn test_verify_512_with_challenge() {
+        let pubkey = hex::decode(TEST_PUBKEY_HEX).unwrap();
+        let sig = hex::decode(TEST_SIG_HELLO_HEX).unwrap();
+
+        for msg in [&b"Hello, Falcon!"[..], b"Wrong message", b""] {
+            let mut c0 = [0u16; FALCON_512_N];
+            FalconVerifier::hash_to_point(&sig[1..41], msg, &mut c0);
+            assert_eq!(
+                FalconVerifier::verify_512_with_challenge(&pubkey, &c0, &sig),
+                FalconVerifier::verify_512(&pubkey, msg, &sig)
+            );
+        }
+
+        // One challenge, reused: still only valid for its own signature and key
+        let mut c0 = [0u16; FALCON_512_N];
+        FalconVerifier::hash_to_point(&sig[1..41], b"Hello, Falcon!", &mut c0);
+        assert!(FalconVerifier::verify_512_with_challenge(
+            &pubkey, &c0, &sig
+        ));
+        let mut other_key = pubkey.clone();
+        other_key[1] ^= 0x01;
+        assert!(!FalconVerifier::verify_512_with_challenge(
+            &other_key, &c0, &sig
+        ));
+
+        // A challenge drawn with a different nonce is rejected
+        let mut wrong_nonce = [0u16; FALCON_512_N];
+        FalconVerifier::hash_to_point(&[0u8; 40], b"Hello, Falcon!", &mut wrong_nonce);
+        assert!(!FalconVerifier::verify_512_with_challenge(
+            &pubkey,
+            &wrong_nonce,
+            &sig
+        ));
+    }
+
+    #[test]
+    fn test_hash_to_point_matches_two_byte_reads() {
+        let sig = hex::decode(TEST_SIG_HELLO_HEX).unwrap();
+
+        for msg in [&b"Hello, Falcon!"[..], b""] {
+            let mut c0 = [0u16; FALCON_512_N];
+            FalconVerifier::hash_to_point(&sig[1..41], msg, &mut c0);
+
+            // Reference sampler: one 2-byte read per sample
+            let mut hasher = Shake256::default();
+            hasher.update(&sig[1..41]);
+            hasher.update(msg);
+            let mut xof = hasher.finalize_xof();
+            let mut i = 0;
+            while i < FALCON_512_N {
+                let mut buf = [0u8; 2];
+                xof.read(&mut buf);
+                let w = u16::from_be_bytes(buf) as u32;
+                if w < 5 * Q {
+                    assert_eq!(c0[i] as u32, w % Q, "coefficient {i}");
+                    i += 1;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_hash_to_point_with_params() {
+        let sig = hex::decode(TEST_SIG_HELLO_HEX).unwrap();
+        let nonce = &sig[1..41];
+
+        // The standard parameters reproduce hash_to_point exactly
+        for msg in [&b"Hello, Falcon!"[..], b"", &[0xA5; 300]] {
+            let mut expected = [0u16; FALCON_512_N];
+            assert!(FalconVerifier::hash_to_point(nonce, msg, &mut expected));
+            let mut c0 = [0u16; FALCON_512_N];
+            assert!(FalconVerifier::hash_to_point_with_params(
+                nonce,
+                msg,
+                Q,
+                5 * Q,
+                &mut c0
+            ));
+            assert_eq!(c0, expected);
+        }
+
+        // Another modulus samples the same stream with its own threshold
+        let (q, threshold) = (3329, 19 * 3329);
+        let msg = b"Hello, Falcon!";
+        let mut c0 = [0u16; FALCON_512_N];
+        assert!(FalconVerifier::hash_to_point_with_params(
+            nonce, msg, q, threshold, &mut c0
+        ));
+        let mut hasher = Shake256::default();
+        hasher.update(nonce);
+        hasher.update(msg);
+        let mut xof = hasher.finalize_xof();
+        let mut i = 0;
+        while i < FALCON_512_N {
+            let mut buf = [0u8; 2];
+            xof.read(&mut buf);
+            let w = u16::from_be_bytes(buf) as u32;
+            if w < threshold {
+                assert_eq!(c0[i] as u32, w % q, "coefficient {i}");
+                i += 1;
+            }
+        }
+
+        // Accepting one sample in 2^16 runs into the sample cap
+        assert!(!FalconVerifier::hash_to_point_with_params(
+            nonce, msg, 1, 1, &mut c0
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "accept threshold")]
+    fn test_hash_to_point_with_params_rejects_threshold_below_q() {
+        let mut c0 = [0u16; FALCON_512_N];
+        FalconVerifier::hash_to_point_with_params(&[0; 40], b"", Q, Q - 1, &mut c0);
+    }
+
+    #[cfg(feature = "debug")]
+    #[test]
+    fn test_verify_512_debug_norm_diagnostic() {
+        let (h, pubkey, s2, sig) = near_bound_fixture();
+        let run = |big: i16| {
+            let c0 = near_bound_challenge(&h, &s2, big);
+            FalconVerifier::debug_with_challenge(&pubkey, &sig, |out| {
+                *out = c0;
+                true
+            })
+        };
+
+        // 511 · 180² + 930² + 511 · 180² + 200² = 34,017,700: within the bound
+        assert_eq!(run(-930), Ok(()));
+        // 950² pushes the norm to 34,055,300, just over 34,034,726
+        let (err, diagnostic) = run(-950).unwrap_err();
+        assert_eq!(err, VerifyError::NormTooLarge);
+        assert_eq!(
+            diagnostic,
+            Some(NormDiagnostic {
+                norm: 34_055_300,
+                s1_max: (77, -950),
+                s2_max: (300, 200),
+            })
+        );
+
+        // Other rejections carry no diagnostic
+        let pubkey_hello = hex::decode(TEST_PUBKEY_HEX).unwrap();
+        assert_eq!(
+            FalconVerifier::verify_512_debug(&pubkey_hello, b"", &[0x39; 40]),
+            Err((VerifyError::InvalidSignatureSize, None))
+        );
+    }
+
+    #[test]
+    fn test_challenge_sample_cap() {
+        // Falcon-1024 needs about 1092 samples; honest inputs never get near the cap
+        let mut c0 = [0u16; FALCON_1024_N];
+        let mut most = 0;
+        for i in 0u32..1000 {
+            let mut stream = ChallengeStream::new(&i.to_be_bytes(), b"Hello, Falcon!");
+            assert!(stream.fill(&mut c0));
+            most = most.max(MAX_CHALLENGE_SAMPLES - stream.samples_left);
+        }
+        assert!(most < 1200, "{most} samples");
+
+        // A stream that runs dry stops instead of sampling on
+        let mut stream = ChallengeStream::new(b"", b"");
+        stream.samples_left = FALCON_512_N as u32;
+        let mut c0 = [0u16; FALCON_512_N];
+        assert!(!stream.fill(&mut c0));
+        assert_eq!(stream.next_coeff(), None);
+    }
+
+    #[test]
+    fn test_challenge_samples_are_big_endian() {
+        assert_eq!(sample_word([0x12, 0x34]), 0x1234);
+
+        // Feed known XOF bytes straight into the sampler's buffer
+        let feed = |bytes: &[u8]| {
+            let mut stream = ChallengeStream::new(b"", b"");
+            stream.buf[..bytes.len()].copy_from_slice(bytes);
+            stream.pos = 0;
+            stream.next_coeff().unwrap()
+        };
+        // 0xF000 = 61440 < 5q, so 61440 - 4q; little-endian would give 240
+        assert_eq!(feed(&[0xF0, 0x00]), 12284);
+        // 0x3002 = q + 1 reduces to 1; little-endian would give 560
+        assert_eq!(feed(&[0x30, 0x02]), 1);
+        // 0xF005 = 5q is rejected and the next sample is used; little-endian
+        // would accept 0x05F0 = 1520
+        assert_eq!(feed(&[0xF0, 0x05, 0x00, 0x07]), 7);
+    }
+
+    #[test]
+    fn test_decode_pubkeys_batch() {
+        let valid = hex::decode(TEST_PUBKEY_HEX).unwrap();
+        let mut other = valid.clone();
+        other[1] ^= 0x01;
+        let mut bad_coeff = valid.clone();
+        bad_coeff[1] = 0xFF; // 16383 is out of range for q = 12289
+        bad_coeff[2] = 0xFF;
+        let bad_header = [&[0x0Au8][..], &valid[1..]].concat();
+
+        let mut expected = [[0u16; FALCON_512_N]; 2];
+        assert!(FalconVerifier::decode_pubkey(&valid, &mut expected[0]));
+        assert!(FalconVerifier::decode_pubkey(&other, &mut expected[1]));
+
+        let mut out = [[0u16; FALCON_512_N]; 4];
+        let keys: [&[u8]; 4] = [&valid, &other, &bad_coeff, &valid];
+        assert_eq!(FalconVerifier::decode_pubkeys(&keys, &mut out), 2);
+        assert_eq!(out[..2], expected);
+
+        // Each invalid key stops the batch at its own index
+        for (i, bad) in [&bad_coeff[..], &bad_header, &valid[..100]]
+            .iter()
+            .enumerate()
+        {
+            let keys: [&[u8]; 3] = [&valid, &other, bad];
+            assert_eq!(
+                FalconVerifier::decode_pubkeys(&keys, &mut out),
+                2,
+                "case {i}"
+            );
+            let keys: [&[u8]; 3] = [bad, &valid, &other];
+            assert_eq!(
+                FalconVerifier::decode_pubkeys(&keys, &mut out),
+                0,
+                "case {i}"
+            );
+        }
+
+        // Limited by the output buffer, and trivially by an empty batch
+        assert_eq!(
+            FalconVerifier::decode_pubkeys(&[&valid, &other, &valid], &mut out[..1]),
+            1
+        );
+        assert_eq!(FalconVerifier::decode_pubkeys(&[], &mut out), 0);
+    }
+
+    #[test]
+    fn test_compressed_trailing_bytes_rejected() {
+        let pubkey = hex::decode(TEST_PUBKEY_HEX).unwrap();
+        let sig = hex::decode(TEST_SIG_HELLO_HEX).unwrap();
+        let mut s2 = [0i16; FALCON_512_N];
+        let exact_len = 41 + FalconVerifier::decode_sig_compressed(&sig[41..], &mut s2);
+        assert!(exact_len < sig.len());
+        let exact = &sig[..exact_len];
+
+        for header in [0x39, 0x29] {
+            let mut base = exact.to_vec();
+            base[0] = header;
+            assert!(FalconVerifier::verify_512(
+                &pubkey,
+                b"Hello, Falcon!",
+                &base
+            ));
+
+            // Zero padding to exactly 666 bytes is the padded encoding
+            let mut padded = base.clone();
+            padded.resize(666, 0);
+            assert!(FalconVerifier::verify_512(
+                &pubkey,
+                b"Hello, Falcon!",
+                &padded
+            ));
+
+            // Any other number of appended zeros is a second encoding
+            for len in [exact_len + 1, exact_len + 7, 665] {
+                let mut extended = base.clone();
+                extended.resize(len, 0);
+                assert_eq!(
+                    FalconSignature::from_bytes(&extended),
+                    Err(VerifyError::SignatureDecodeFailed),
+                    "header {header:#x}, {len} bytes"
+                );
+                assert!(!FalconVerifier::verify_512(
+                    &pubkey,
+                    b"Hello, Falcon!",
+                    &extended
+                ));
+            }
+        }
+
+        // Past the padded size the compressed header is rejected too
+        let mut long = sig.clone();
+        long.push(0);
+        assert_eq!(
+            FalconSignature::from_bytes(&long),
+            Err(VerifyError::SignatureDecodeFailed)
+        );
+    }
+
+    /// Packs s2 into a compressed body: sign, low 7 bits, then the high bits
+    /// in unary. Magnitudes above 2047 are written as-is so tests can build
+    /// bodies the decoder must reject. Returns the buffer and its used length.
+    fn encode_compressed_body(s2: &[i16; FALCON_512_N]) -> ([u8; 1024], usize) {
+        let mut out = [0u8; 1024];
+        let mut bit = 0;
+        let mut push = |b: u32| {
+            out[bit / 8] |= (b as u8) << (7 - bit % 8);
+            bit += 1;
+        };
+        for &c in s2.iter() {
+            let m = c.unsigned_abs() as u32;
+            push((c < 0) as u32);
+            for i in (0..7).rev() {
+                push((m >> i) & 1);
+            }
+            for _ in 0..(m >> 7) {
+                push(0);
+            }
+            push(1);
+        }
+        (out, bit.div_ceil(8))
+    }
+
+    #[test]
+    fn test_nonce_len_same_for_1024() {
+        // header || 40-byte nonce || all-zero body, nine bits per coefficient
+        let mut sig = [0u8; 1 + NONCE_LEN + 9 * FALCON_1024_N / 8];
+        sig[0] = 0x3A;
+        for (i, b) in sig[1..1 + NONCE_LEN].iter_mut().enumerate() {
+            *b = i as u8 + 1;
+        }
+        for i in 0..FALCON_1024_N {
+            let bit = 9 * i + 8;
+            sig[1 + NONCE_LEN + bit / 8] |= 0x80 >> (bit % 8);
+        }
+
+        let mut s2 = [1i16; FALCON_1024_N];
+        let (header, nonce, format) = parse_signature(&sig, 10, &mut s2).unwrap();
+        assert_eq!((header, format), (0x3A, SigFormat::Compressed));
+        assert_eq!(&nonce[..], &sig[1..41]);
+        assert_eq!(s2, [0; FALCON_1024_N]);
+        assert_eq!(
+            sig_size(SigFormat::ConstantTime, FALCON_1024_LOGN),
+            Some(1 + 40 + 1536)
+        );
+    }
+
+    #[test]
+    fn test_compressed_unary_total_is_capped() {
+        assert_eq!(MAX_UNARY_BITS_512, 1031);
+        assert_eq!(MAX_UNARY_BITS_1024, 2095);
+
+        // A genuine signature stays well under the cap and still verifies
+        let pubkey = hex::decode(TEST_PUBKEY_HEX).unwrap();
+        let sig = hex::decode(TEST_SIG_HELLO_HEX).unwrap();
+        let parsed = FalconSignature::from_bytes(&sig).unwrap();
+        let unary: u32 = parsed
+            .s2()
+            .iter()
+            .map(|&c| c.unsigned_abs() as u32 >> 7)
+            .sum();
+        assert!(unary < MAX_UNARY_BITS_512);
+        assert_eq!(
+            FalconVerifier::verify_512_detailed(&pubkey, b"Hello, Falcon!", &sig),
+            Ok(())
+        );
+
+        // 343 · 3 + 2 = 1031 unary bits: exactly at the cap
+        let mut s2 = [0i16; FALCON_512_N];
+        s2[..343].fill(-384);
+        s2[343..345].fill(128);
+        let mut out = [0i16; FALCON_512_N];
+        let (body, len) = encode_compressed_body(&s2);
+        assert_eq!(
+            FalconVerifier::decode_sig_compressed(&body[..len], &mut out),
+            len
+        );
+        assert_eq!(out, s2);
+
+        // One more unary bit is rejected, though every coefficient is in range
+        s2[345] = 128;
+        let (body, len) = encode_compressed_body(&s2);
+        assert_eq!(
+            FalconVerifier::decode_sig_compressed(&body[..len], &mut out),
+            0
+        );
+
+        // A body of 1536 unary bits fails before the norm is ever computed
+        let (body, len) = encode_compressed_body(&[384; FALCON_512_N]);
+        let mut long = sig[..41].to_vec();
+        long.extend_from_slice(&body[..len]);
+        assert_eq!(long.len(), FALCON_SIG_MAX_SIZE as usize);
+        assert_eq!(
+            FalconVerifier::verify_512_detailed(&pubkey, b"Hello, Falcon!", &long),
+            Err(VerifyError::SignatureDecodeFailed)
+        );
+    }
+
+    #[test]
+    fn test_can_decode_signature() {
+        let sig = hex::decode(TEST_SIG_HELLO_HEX).unwrap();
+        let parsed = FalconSignature::from_bytes(&sig).unwrap();
+        assert!(FalconVerifier::can_decode_signature(&sig));
+
+        let mut ct = sig[..41].to_vec();
+        ct[0] = 0x59;
+        ct.extend_from_slice(&encode_ct_body(parsed.s2()));
+        assert!(FalconVerifier::can_decode_signature(&ct));
+
+        // An all-zero Falcon-1024 body: nine bits per coefficient
+        let mut sig_1024 = [0u8; 1 + 40 + 9 * FALCON_1024_N / 8];
+        sig_1024[0] = 0x3A;
+        for i in 0..FALCON_1024_N {
+            let bit = 9 * i + 8;
+            sig_1024[41 + bit / 8] |= 0x80 >> (bit % 8);
+        }
+        assert!(FalconVerifier::can_decode_signature(&sig_1024));
+        sig_1024[41] = 0x80; // Minus zero
+        assert!(!FalconVerifier::can_decode_signature(&sig_1024));
+
+        // Trailing bytes other than padding to exactly 666 bytes
+        let (body, len) = encode_compressed_body(parsed.s2());
+        let exact = [&sig[..41], &body[..len]].concat();
+        assert!(FalconVerifier::can_decode_signature(&exact));
+        let mut extended = exact.clone();
+        extended.push(0);
+        assert!(!FalconVerifier::can_decode_signature(&extended));
+        let mut padded = exact.clone();
+        padded.resize(666, 0);
+        assert!(FalconVerifier::can_decode_signature(&padded));
+        *padded.last_mut().unwrap() = 1;
+        assert!(!FalconVerifier::can_decode_signature(&padded));
+
+        // A coefficient magnitude past 2047
+        let mut big = *parsed.s2();
+        big[0] = 2048;
+        let (body, len) = encode_compressed_body(&big);
+        let overflow = [&sig[..41], &body[..len]].concat();
+        assert!(!FalconVerifier::can_decode_signature(&overflow));
+
+        // Headers and sizes that never reach a decoder
+        let mut wrong_logn = sig.clone();
+        wrong_logn[0] = 0x38;
+        assert!(!FalconVerifier::can_decode_signature(&wrong_logn));
+        let mut wrong_format = sig.clone();
+        wrong_format[0] = 0x49;
+        assert!(!FalconVerifier::can_decode_signature(&wrong_format));
+        assert!(!FalconVerifier::can_decode_signature(&ct[..ct.len() - 1]));
+        assert!(!FalconVerifier::can_decode_signature(&sig[..41]));
+        assert!(!FalconVerifier::can_decode_signature(&[]));
+    }
+
+    #[test]
+    fn test_compressed_magnitude_limit() {
+        let pubkey = hex::decode(TEST_PUBKEY_HEX).unwrap();
+        let sig = hex::decode(TEST_SIG_HELLO_HEX).unwrap();
+        let parsed = FalconSignature::from_bytes(&sig).unwrap();
+
+        // The encoder reproduces the fixture's body exactly
+        let (body, len) = encode_compressed_body(parsed.s2());
+        let mut s2 = [0i16; FALCON_512_N];
+        assert_eq!(
+            FalconVerifier::decode_sig_compressed(&sig[41..], &mut s2),
+            len
+        );
+        assert_eq!(&body[..len], &sig[41..41 + len]);
+
+        for m in [2047i16, -2047] {
+            let mut big = *parsed.s2();
+            big[0] = m;
+            let (body, len) = encode_compressed_body(&big);
+            assert_eq!(
+                FalconVerifier::decode_sig_compressed(&body[..len], &mut s2),
+                len
+            );
+            assert_eq!(s2[0], m);
+        }
+
+        // 2048 takes sixteen unary zeros, one past the limit
+        for m in [2048i16, -2048, 2175] {
+            let mut big = *parsed.s2();
+            big[0] = m;
+            let (body, len) = encode_compressed_body(&big);
+            assert_eq!(
+                FalconVerifier::decode_sig_compressed(&body[..len], &mut s2),
+                0,
+                "{m}"
+            );
+
+            let mut framed = sig[..41].to_vec();
+            framed.extend_from_slice(&body[..len]);
+            assert_eq!(
+                FalconSignature::from_bytes(&framed),
+                Err(VerifyError::SignatureDecodeFailed)
+            );
+            assert!(!FalconVerifier::verify_512(
+                &pubkey,
+                b"Hello, Falcon!",
+                &framed
+            ));
+        }
+    }
+
+    #[test]
+    fn test_compressed_non_canonical_rejected() {
+        let sig = hex::decode(TEST_SIG_HELLO_HEX).unwrap();
+        let parsed = FalconSignature::from_bytes(&sig).unwrap();
+        let mut s2 = [0i16; FALCON_512_N];
+        let framed = |body: &[u8]| {
+            let mut framed = sig[..41].to_vec();
+            framed.extend_from_slice(body);
+            framed
+        };
+
+        // "-0": a zero coefficient with the sign bit set
+        let mut zero_first = *parsed.s2();
+        zero_first[0] = 0;
+        let (mut body, len) = encode_compressed_body(&zero_first);
+        assert_eq!(
+            FalconVerifier::decode_sig_compressed(&body[..len], &mut s2),
+            len
+        );
+        body[0] |= 0x80;
+        assert_eq!(
+            FalconVerifier::decode_sig_compressed(&body[..len], &mut s2),
+            0
+        );
+        assert_eq!(
+            FalconSignature::from_bytes(&framed(&body[..len])),
+            Err(VerifyError::SignatureDecodeFailed)
+        );
+
+        // An extra leading unary zero is not a longer spelling of the same
+        // value: it adds 128 to the magnitude
+        let mut small = *parsed.s2();
+        small[0] = 5;
+        let (body, len) = encode_compressed_body(&small);
+        small[0] = 5 + 128;
+        let (padded, padded_len) = encode_compressed_body(&small);
+        assert_ne!(&body[..len], &padded[..padded_len]);
+        assert_eq!(
+            FalconVerifier::decode_sig_compressed(&padded[..padded_len], &mut s2),
+            padded_len
+        );
+        assert_eq!(s2[0], 133);
+
+        // Non-zero bits after the last coefficient in the final byte
+        let mut odd = *parsed.s2();
+        let bits = |s2: &[i16; FALCON_512_N]| {
+            s2.iter()
+                .map(|c| 9 + (c.unsigned_abs() as usize >> 7))
+                .sum::<usize>()
+        };
+        if bits(&odd) % 8 == 0 {
+            odd[0] += 128;
+        }
+        let (mut body, len) = encode_compressed_body(&odd);
+        assert_ne!(bits(&odd) % 8, 0);
+        body[len - 1] |= 1;
+        assert_eq!(
+            FalconVerifier::decode_sig_compressed(&body[..len], &mut s2),
+            0
+        );
+        assert_eq!(
+            FalconSignature::from_bytes(&framed(&body[..len])),
+            Err(VerifyError::SignatureDecodeFailed)
+        );
+    }
+
+    #[test]
+    fn test_rejects_truncated_zero_padded_pubkey() {
+        let pubkey = hex::decode(TEST_PUBKEY_HEX).unwrap();
+        let sig = hex::decode(TEST_SIG_HELLO_HEX).unwrap();
+        let msg = b"Hello, Falcon!";
+        let mut h = [0u16; FALCON_512_N];
+        assert!(FalconVerifier::decode_pubkey(&pubkey, &mut h));
+        let lsb = encode_pubkey(&h, Packing::LsbFirst);
+
+        // 14 bytes zero the last 8 coefficients; up to the whole body
+        for cut in [14, 15, 100, 448, 896] {
+            let mut padded = pubkey.clone();
+            padded[FALCON_512_PUBKEY_SIZE - cut..].fill(0);
+            let mut out = [0u16; FALCON_512_N];
+            assert!(!FalconVerifier::decode_pubkey(&padded, &mut out), "{cut}");
+            assert!(!FalconVerifier::verify_512(&padded, msg, &sig));
+            assert_eq!(
+                FalconVerifier::verify_512_detailed(&padded, msg, &sig),
+                Err(VerifyError::InvalidPublicKey)
+            );
+
+            let mut padded = lsb;
+            padded[FALCON_512_PUBKEY_SIZE - cut..].fill(0);
+            assert!(
+                !FalconVerifier::decode_pubkey_lsb(&padded, &mut out),
+                "{cut}"
+            );
+        }
+
+        // Shorter truncations leave fewer zero coefficients than an honest
+        // key could have; they decode and fail verification
+        let mut padded = pubkey.clone();
+        padded[FALCON_512_PUBKEY_SIZE - 13..].fill(0);
+        assert!(FalconVerifier::decode_pubkey(&padded, &mut h));
+        assert_eq!(
+            FalconVerifier::verify_512_detailed(&padded, msg, &sig),
+            Err(VerifyError::NormTooLarge)
+        );
+
+        // Falcon-1024 keys go through the same check
+        let mut out = [0u16; FALCON_1024_N];
+        let body = [0u8; FALCON_1024_PUBKEY_SIZE - 1];
+        assert!(!FalconVerifier::decode_pubkey_coeffs(&body, &mut out));
+    }
+
+    #[test]
+    fn test_pubkey_zero_tail_boundary() {
+        let pubkey = hex::decode(TEST_PUBKEY_HEX).unwrap();
+        let mut h = [0u16; FALCON_512_N];
+        assert!(FalconVerifier::decode_pubkey(&pubkey, &mut h));
+
+        // Seven trailing zeros are still a key; the eighth makes it truncated
+        h[FALCON_512_N - 7..].fill(0);
+        let mut out = [0u16; FALCON_512_N];
+        assert!(FalconVerifier::decode_pubkey_coeffs(
+            &encode_pubkey(&h, Packing::MsbFirst)[1..],
+            &mut out
+        ));
+        assert_eq!(out, h);
+        h[FALCON_512_N - 8] = 0;
+        for packing in [Packing::MsbFirst, Packing::LsbFirst] {
+            let key = encode_pubkey(&h, packing);
+            let decoded = match packing {
+                Packing::MsbFirst => FalconVerifier::decode_pubkey(&key, &mut out),
+                Packing::LsbFirst => FalconVerifier::decode_pubkey_lsb(&key, &mut out),
+            };
+            assert!(!decoded, "{packing:?}");
+        }
+    }
+
+    #[test]
+    fn test_strict_keys_rejects_zero_ntt_coefficient() {
+        // All NTT coefficients 1 except one, mapped back to a key
+        let mut h = [1u16; FALCON_512_N];
+        h[5] = 0;
+        ntt_inverse(&mut h);
+        let mut out = [0u16; FALCON_512_N];
+
+        for packing in [Packing::MsbFirst, Packing::LsbFirst] {
+            let weak = encode_pubkey(&h, packing);
+            let decoded = match packing {
+                Packing::MsbFirst => FalconVerifier::decode_pubkey(&weak, &mut out),
+                Packing::LsbFirst => FalconVerifier::decode_pubkey_lsb(&weak, &mut out),
+            };
+            assert_eq!(decoded, !cfg!(feature = "strict_keys"), "{packing:?}");
+        }
+
+        // The test key is invertible and decodes either way
+        let pubkey = hex::decode(TEST_PUBKEY_HEX).unwrap();
+        assert!(FalconVerifier::decode_pubkey(&pubkey, &mut out));
+    }
+
+    #[test]
+    fn test_verify_with_id() {
+        let pubkey = hex::decode(TEST_PUBKEY_HEX).unwrap();
+        let sig = hex::decode(TEST_SIG_HELLO_HEX).unwrap();
+        let expected_id = u32::from_be_bytes([sig[1], sig[2], sig[3], sig[4]]);
+
+        let (valid, id) = FalconVerifier::verify_512_with_id(&pubkey, b"Hello, Falcon!", &sig);
+        assert!(valid);
+        assert_eq!(id, expected_id);
+
+        // The id only depends on the signature, not on the verification result
+        let (valid, id) = FalconVerifier::verify_512_with_id(&pubkey, b"Wrong message", &sig);
+        assert!(!valid);
+        assert_eq!(id, expected_id);
+
+        assert_eq!(
+            FalconVerifier::verify_512_with_id(&pubkey, b"", &sig[..3]),
+            (false, 0)
+        );
+    }
+
+    #[test]
+    fn test_rejects_logn_mismatch() {
+        let pubkey = hex::decode(TEST_PUBKEY_HEX).unwrap();
+        let sig = hex::decode(TEST_SIG_HELLO_HEX).unwrap();
+        let parsed = FalconSignature::from_bytes(&sig).unwrap();
+        assert_eq!(parsed.logn(), pubkey[0]);
+
+        // A logn = 10 key header paired with a logn = 9 signature
+        let mut pk_1024_hdr = pubkey.clone();
+        pk_1024_hdr[0] = 10;
+        assert!(!FalconVerifier::verify_512_parsed(
+            &pk_1024_hdr,
+            b"Hello, Falcon!",
+            &parsed
+        ));
+        let mut scratch = [0u16; FALCON_512_N];
+        assert!(!FalconVerifier::verify_512_with_scratch(
+            &pk_1024_hdr,
+            b"Hello, Falcon!",
+            &sig,
+            &mut scratch
+        ));
+
+        assert!(FalconVerifier::verify_512_parsed(
+            &pubkey,
+            b"Hello, Falcon!",
+            &parsed
+        ));
+    }
+
+    #[test]
+    fn test_pubkeys_equivalent() {
+        let pubkey = hex::decode(TEST_PUBKEY_HEX).unwrap();
+        assert!(FalconVerifier::pubkeys_equivalent(&pubkey, &pubkey.clone()));
+
+        // Different key: change the last coefficient (low 14 bits of the blob)
+        let mut other = pubkey.clone();
+        other[FALCON_512_PUBKEY_SIZE - 1] ^= 0x01;
+        assert!(!FalconVerifier::pubkeys_equivalent(&pubkey, &other));
+
+        // Re-encoding the first coefficient as w + q is a non-canonical variant
+        // of the same key; it must not decode, so it is never "equivalent".
+        let mut h = [0u16; FALCON_512_N];
+        assert!(FalconVerifier::decode_pubkey(&pubkey, &mut h));
+        let w = h[0] as u32 + Q;
+        assert!(w < (1 << 14));
+        let mut variant = pubkey.clone();
+        variant[1] = (w >> 6) as u8;
+        variant[2] = (variant[2] & 0x03) | ((w as u8 & 0x3F) << 2);
+        assert!(!FalconVerifier::pubkeys_equivalent(&pubkey, &variant));
+
+        // Undecodable input on either side
+        assert!(!FalconVerifier::pubkeys_equivalent(&pubkey, &pubkey[..100]));
+        assert!(!FalconVerifier::pubkeys_equivalent(&[], &pubkey));
+    }
+
+    #[test]
+    fn test_rejects_oversized_norm() {
+        let pubkey = hex::decode(TEST_PUBKEY_HEX).unwrap();
+        let sig = hex::decode(TEST_SIG_HELLO_HEX).unwrap();
+
+        // CT signature with every s2 coefficient at the maximum magnitude:
+        // ||s2||² alone is 512 · 2047² ≫ L2_BOUND_512.
+        let mut ct = [0u8; 1 + 40 + 768];
+        ct[0] = 0x59;
+        ct[1..41].copy_from_slice(&sig[1..41]);
+        ct[41..].copy_from_slice(&encode_ct_body(&[2047; FALCON_512_N]));
+
+        assert!(!FalconVerifier::verify_512(&pubkey, b"Hello, Falcon!", &ct));
+        let mut scratch = [0u16; FALCON_512_N];
+        assert!(!FalconVerifier::verify_512_with_scratch(
+            &pubkey,
+            b"Hello, Falcon!",
+            &ct,
+            &mut scratch
+        ));
+
+        let mut s1 = [0i16; FALCON_512_N];
+        s1[0] = 1;
+        assert!(!FalconVerifier::is_short(&s1, &[2047; FALCON_512_N]));
+        assert!(FalconVerifier::is_short(&s1, &[0; FALCON_512_N]));
+    }
+
+    #[test]
+    fn test_verify_raw_512_ntt_matches_verify_raw_512() {
+        let pubkey = hex::decode(TEST_PUBKEY_HEX).unwrap();
+        let sig = FalconSignature::from_bytes(&hex::decode(TEST_SIG_HELLO_HEX).unwrap()).unwrap();
+        let mut h = [0u16; FALCON_512_N];
+        assert!(FalconVerifier::decode_pubkey(&pubkey, &mut h));
+        FalconVerifier::prepare_pubkey(&mut h);
+
+        let mut s2_ntt = [0u16; FALCON_512_N];
+        for (w, &z) in s2_ntt.iter_mut().zip(sig.s2().iter()) {
+            *w = field_from_signed(z as i32) as u16;
+        }
+        ntt_forward(&mut s2_ntt);
+
+        for (msg, valid) in [(&b"Hello, Falcon!"[..], true), (b"Wrong message", false)] {
+            let mut c0 = [0u16; FALCON_512_N];
+            assert!(FalconVerifier::hash_to_point(sig.nonce(), msg, &mut c0));
+            assert_eq!(FalconVerifier::verify_raw_512(&c0, sig.s2(), &h), valid);
+            assert_eq!(FalconVerifier::verify_raw_512_ntt(&c0, &s2_ntt, &h), valid);
+        }
+
+        // Unreduced coefficients are rejected rather than multiplied
+        let mut c0 = [0u16; FALCON_512_N];
+        FalconVerifier::hash_to_point(sig.nonce(), b"Hello, Falcon!", &mut c0);
+        let mut unreduced = s2_ntt;
+        unreduced[7] += Q as u16;
+        assert!(!FalconVerifier::verify_raw_512_ntt(&c0, &unreduced, &h));
+    }
+
+    #[test]
+    fn test_rejects_zero_s2() {
+        // With c0 = 0 the zero signature has norm 0 and is short, but is
+        // still rejected
+        let zero = [0i16; FALCON_512_N];
+        assert!(FalconVerifier::is_short(&zero, &zero));
+        let mut h = [1u16; FALCON_512_N];
+        FalconVerifier::prepare_pubkey(&mut h);
+        assert!(!FalconVerifier::verify_raw_512(
+            &[0; FALCON_512_N],
+            &zero,
+            &h
+        ));
+        let mut s2 = zero;
+        s2[0] = 1;
+        // c0 = s2·h gives s1 = 0 and norm 1, which passes
+        let c0 = FalconVerifier::compute_s2h_ntt(&s2, &h);
+        assert!(FalconVerifier::verify_raw_512(&c0, &s2, &h));
+
+        // The same holds for a zero body in a real signature
+        let pubkey = hex::decode(TEST_PUBKEY_HEX).unwrap();
+        let sig = hex::decode(TEST_SIG_HELLO_HEX).unwrap();
+        let (body, len) = encode_compressed_body(&zero);
+        let mut zero_sig = sig[..41].to_vec();
+        zero_sig.extend_from_slice(&body[..len]);
+        assert!(!FalconVerifier::verify_512(
+            &pubkey,
+            b"Hello, Falcon!",
+            &zero_sig
+        ));
+        let mut scratch = [0u16; FALCON_512_N];
+        assert!(!FalconVerifier::verify_512_with_scratch(
+            &pubkey,
+            b"Hello, Falcon!",
+            &zero_sig,
+            &mut scratch
+        ));
+    }
+
+    #[test]
+    fn test_verify_512_detailed() {
+        let pubkey = hex::decode(TEST_PUBKEY_HEX).unwrap();
+        let sig = hex::decode(TEST_SIG_HELLO_HEX).unwrap();
+        let msg = b"Hello, Falcon!";
+
+        assert_eq!(
+            FalconVerifier::verify_512_detailed(&pubkey, msg, &sig),
+            Ok(())
+        );
+        assert_eq!(
+            FalconVerifier::verify_512_detailed(&pubkey, b"Wrong message", &sig),
+            Err(VerifyError::NormTooLarge)
+        );
+        assert_eq!(
+            FalconVerifier::verify_512_detailed(&pubkey, msg, &sig[..41]),
+            Err(VerifyError::InvalidSignatureSize)
+        );
+
+        let mut bad_header = sig.clone();
+        bad_header[0] = 0x49;
+        assert_eq!(
+            FalconVerifier::verify_512_detailed(&pubkey, msg, &bad_header),
+            Err(VerifyError::BadFormat)
+        );
+
+        let mut bad_body = sig.clone();
+        *bad_body.last_mut().unwrap() = 0xFF;
+        assert_eq!(
+            FalconVerifier::verify_512_detailed(&pubkey, msg, &bad_body),
+            Err(VerifyError::SignatureDecodeFailed)
+        );
+
+        assert_eq!(
+            FalconVerifier::verify_512_detailed(&pubkey[..896], msg, &sig),
+            Err(VerifyError::InvalidPublicKey)
+        );
+    }
+
+    #[test]
+    fn test_verify_512_detailed_flags_swapped_arguments() {
+        let pubkey = hex::decode(TEST_PUBKEY_HEX).unwrap();
+        let sig = hex::decode(TEST_SIG_HELLO_HEX).unwrap();
+        let msg = b"Hello, Falcon!";
+
+        assert_eq!(
+            FalconVerifier::verify_512_detailed(&sig, msg, &pubkey),
+            Err(VerifyError::LikelyArgumentSwap)
+        );
+        assert!(!FalconVerifier::verify_512(&sig, msg, &pubkey));
+
+        // Either half of the swap is enough: a signature-sized key, for every
+        // Falcon-512 signature length, or a key-sized signature
+        let key = [0x09; FALCON_SIG_MAX_SIZE as usize];
+        for len in signature_size_bounds(FALCON_512_LOGN as u8) {
+            assert_eq!(
+                FalconVerifier::verify_512_detailed(&key[..len], msg, &sig),
+                Err(VerifyError::LikelyArgumentSwap)
+            );
         }
+        assert_eq!(
+            FalconVerifier::verify_512_detailed(&[], msg, &pubkey),
+            Err(VerifyError::LikelyArgumentSwap)
+        );
 
-        true
+        // A correctly sized key keeps the usual errors
+        assert_eq!(
+            FalconVerifier::verify_512_detailed(&pubkey, msg, &pubkey),
+            Err(VerifyError::InvalidSignatureSize)
+        );
+        assert_eq!(
+            FalconVerifier::verify_512_detailed(&pubkey[..100], msg, &sig),
+            Err(VerifyError::InvalidPublicKey)
+        );
     }
 
-    /// Decodes a signature from compressed format. Returns bytes consumed, or 0 on error.
-    fn decode_sig_compressed(data: &[u8], s2: &mut [i16; FALCON_512_N]) -> usize {
-        let mut acc: u32 = 0; // Bit accumulator
-        let mut acc_len: u32 = 0; // Valid bits in accumulator
-        let mut v = 0; // Input byte index
+    #[test]
+    fn test_verify_512_with_margin() {
+        let pubkey = hex::decode(TEST_PUBKEY_HEX).unwrap();
+        let sig = hex::decode(TEST_SIG_HELLO_HEX).unwrap();
+        let msg = b"Hello, Falcon!";
 
-        for u in 0..FALCON_512_N {
-            // Read next byte containing sign bit and low 7 bits
-            if v >= data.len() {
-                return 0;
-            }
-            acc = (acc << 8) | (data[v] as u32);
-            v += 1;
+        let outcome = FalconVerifier::verify_512_with_margin(&pubkey, msg, &sig).unwrap();
+        assert!(outcome.valid);
+        assert!(outcome.margin > 0);
 
-            // Extract the 8 bits we just added
-            let b = acc >> acc_len;
-            let sign = b & 128; // Bit 7: sign (1 = negative)
-            let mut m = (b & 127) as u32; // Bits 0-6: low 7 bits of |value|
+        // A wrong message gives a random s1, far over the bound
+        let outcome = FalconVerifier::verify_512_with_margin(&pubkey, b"Wrong", &sig).unwrap();
+        assert!(!outcome.valid);
+        assert!(outcome.margin < 0);
 
-            // Decode unary high part: count zeros until we hit a 1
-            loop {
-                if acc_len == 0 {
-                    // Need more bits
-                    if v >= data.len() {
-                        return 0;
-                    }
-                    acc = (acc << 8) | (data[v] as u32);
-                    v += 1;
-                    acc_len = 8;
-                }
-                acc_len -= 1;
+        // Nothing to report without a norm check
+        assert_eq!(
+            FalconVerifier::verify_512_with_margin(&pubkey, msg, &sig[..41]),
+            None
+        );
+        assert_eq!(
+            FalconVerifier::verify_512_with_margin(&pubkey[..896], msg, &sig),
+            None
+        );
+    }
 
-                if ((acc >> acc_len) & 1) != 0 {
-                    // Hit the terminating 1 bit
-                    break;
-                }
-                // Each 0 bit adds 128 to the magnitude
-                m += 128;
-                if m > 2047 {
-                    // Maximum allowed magnitude exceeded
-                    return 0;
-                }
-            }
+    #[test]
+    fn test_verify_512_with_margin_near_bound() {
+        let (h, pubkey, s2, sig) = near_bound_fixture();
+        let run = |big: i16| {
+            let c0 = near_bound_challenge(&h, &s2, big);
+            FalconVerifier::margin_with_challenge(&pubkey, &sig, |out| {
+                *out = c0;
+                true
+            })
+        };
 
-            // "-0" is forbidden (use "+0" instead)
-            if sign != 0 && m == 0 {
-                return 0;
-            }
+        // Norm 34,017,700, just within 34,034,726
+        assert_eq!(
+            run(-930),
+            Some(VerifyOutcome {
+                valid: true,
+                margin: 17_026,
+            })
+        );
+        // Norm 34,055,300, just over
+        assert_eq!(
+            run(-950),
+            Some(VerifyOutcome {
+                valid: false,
+                margin: -20_574,
+            })
+        );
+    }
 
-            // Apply sign and store
-            s2[u] = if sign != 0 { -(m as i16) } else { m as i16 };
-        }
+    /// A key and signature for tests at the norm bound. s1 = c0 - s2·h, so
+    /// choosing c0 = s1 + s2·h fixes both. Any key works; h = 1 + x^511 keeps
+    /// the last coefficient non-zero. s2 is 180 everywhere but s2[300] = 200.
+    fn near_bound_fixture() -> (
+        [u16; FALCON_512_N],
+        [u8; FALCON_512_PUBKEY_SIZE],
+        [i16; FALCON_512_N],
+        FalconSignature,
+    ) {
+        let mut h = [0u16; FALCON_512_N];
+        h[0] = 1;
+        h[FALCON_512_N - 1] = 1;
+        let pubkey = encode_pubkey(&h, Packing::MsbFirst);
 
-        // Any leftover bits in accumulator must be zero
-        if (acc & ((1u32 << acc_len) - 1)) != 0 {
-            return 0;
-        }
+        let mut s2 = [180i16; FALCON_512_N];
+        s2[300] = 200;
+        let (body, len) = encode_compressed_body(&s2);
+        let mut sig_bytes = [0u8; 1 + 40 + 1024];
+        sig_bytes[0] = 0x39;
+        sig_bytes[41..41 + len].copy_from_slice(&body[..len]);
+        let sig = FalconSignature::from_bytes(&sig_bytes[..41 + len]).unwrap();
+        (h, pubkey, s2, sig)
+    }
 
-        v
+    /// The challenge under which the [`near_bound_fixture`] signature has s1
+    /// 180 everywhere but s1[77] = `big`.
+    fn near_bound_challenge(
+        h: &[u16; FALCON_512_N],
+        s2: &[i16; FALCON_512_N],
+        big: i16,
+    ) -> [u16; FALCON_512_N] {
+        let mut s1 = [180i16; FALCON_512_N];
+        s1[77] = big;
+        challenge_for(&s1, s2, h)
     }
 
-    /// Decodes a signature from constant-time (CT) format (12 bits per coefficient). Returns bytes consumed, or 0 on error.
-    fn decode_sig_ct(data: &[u8], s2: &mut [i16; FALCON_512_N]) -> usize {
-        const BITS: u32 = 12; // Bits per coefficient for Falcon-512
-        let n = FALCON_512_N;
-        let in_len = ((n as u32 * BITS) + 7) / 8; // Total bytes needed
+    /// c0 = s1 + s2·h mod q, the challenge under which a signature with body
+    /// s2 has the chosen s1.
+    fn challenge_for(
+        s1: &[i16; FALCON_512_N],
+        s2: &[i16; FALCON_512_N],
+        h: &[u16; FALCON_512_N],
+    ) -> [u16; FALCON_512_N] {
+        let mut prepared = *h;
+        poly_prepare_for_mul(&mut prepared);
+        let s2h = FalconVerifier::compute_s2h_ntt(s2, &prepared);
+        let mut c0 = [0u16; FALCON_512_N];
+        for (c, (&a, &t)) in c0.iter_mut().zip(s1.iter().zip(s2h.iter())) {
+            *c = ((field_from_signed(a as i32) + t as u32) % Q) as u16;
+        }
+        c0
+    }
 
-        if data.len() < in_len as usize {
-            return 0;
+    /// Packs h into a public key blob in the given bit order.
+    fn encode_pubkey(h: &[u16; FALCON_512_N], packing: Packing) -> [u8; FALCON_512_PUBKEY_SIZE] {
+        let mut out = [0u8; FALCON_512_PUBKEY_SIZE];
+        out[0] = FALCON_512_LOGN as u8;
+        let mut acc: u32 = 0;
+        let mut acc_len = 0;
+        let mut v = 1;
+        for &w in h.iter() {
+            match packing {
+                Packing::MsbFirst => acc = (acc << 14) | w as u32,
+                Packing::LsbFirst => acc |= (w as u32) << acc_len,
+            }
+            acc_len += 14;
+            while acc_len >= 8 {
+                acc_len -= 8;
+                out[v] = match packing {
+                    Packing::MsbFirst => (acc >> acc_len) as u8,
+                    Packing::LsbFirst => {
+                        let b = acc as u8;
+                        acc >>= 8;
+                        b
+                    }
+                };
+                v += 1;
+            }
         }
+        out
+    }
 
-        let mut acc: u32 = 0; // Bit accumulator
-        let mut acc_len: u32 = 0; // Valid bits in accumulator
-        let mask1 = (1u32 << BITS) - 1; // 0xFFF: mask for 12 bits
-        let mask2 = 1u32 << (BITS - 1); // 0x800: sign bit position
-        let mut buf_idx = 0;
-        let mut u = 0;
+    #[test]
+    fn test_pubkey_packing_round_trip() {
+        let pubkey = hex::decode(TEST_PUBKEY_HEX).unwrap();
+        let sig = hex::decode(TEST_SIG_HELLO_HEX).unwrap();
+        let msg = b"Hello, Falcon!";
 
-        while u < n {
-            // Load next byte (MSB-first packing)
-            acc = (acc << 8) | (data[buf_idx] as u32);
-            buf_idx += 1;
-            acc_len += 8;
+        let mut h = [0u16; FALCON_512_N];
+        assert!(FalconVerifier::decode_pubkey(&pubkey, &mut h));
+        assert_eq!(encode_pubkey(&h, Packing::MsbFirst)[..], pubkey[..]);
 
-            // Extract coefficients while we have enough bits
-            while acc_len >= BITS && u < n {
-                acc_len -= BITS;
-                let mut w = (acc >> acc_len) & mask1;
+        let lsb = encode_pubkey(&h, Packing::LsbFirst);
+        let mut h_lsb = [0u16; FALCON_512_N];
+        assert!(FalconVerifier::decode_pubkey_lsb(&lsb, &mut h_lsb));
+        assert_eq!(h_lsb, h);
 
-                // Sign extension for negative values (two's complement)
-                // If bit 11 is set, the value is negative; extend sign to full u32
-                if (w & mask2) != 0 {
-                    w |= !mask1; // Set all upper bits to 1
-                }
+        assert!(FalconVerifier::verify_512_with_packing(
+            &pubkey,
+            msg,
+            &sig,
+            Packing::MsbFirst
+        ));
+        assert!(FalconVerifier::verify_512_with_packing(
+            &lsb,
+            msg,
+            &sig,
+            Packing::LsbFirst
+        ));
 
-                if w == (0u32.wrapping_sub(mask2) & mask1) {
-                    return 0;
-                }
+        // Decoding in the wrong order yields a different h (or no h at all)
+        assert!(!FalconVerifier::verify_512_with_packing(
+            &lsb,
+            msg,
+            &sig,
+            Packing::MsbFirst
+        ));
+        assert!(!FalconVerifier::verify_512_with_packing(
+            &pubkey,
+            msg,
+            &sig,
+            Packing::LsbFirst
+        ));
+    }
 
-                s2[u] = w as i16;
-                u += 1;
+    #[test]
+    fn test_verify_512_any_message() {
+        let pubkey = hex::decode(TEST_PUBKEY_HEX).unwrap();
+        let sig = hex::decode(TEST_SIG_HELLO_HEX).unwrap();
+        let (a, b, c): (&[u8], &[u8], &[u8]) = (b"Hello, Falcon?", b"Hello, Falcon!", b"");
+
+        assert_eq!(
+            FalconVerifier::verify_512_any_message(&pubkey, &[a, b, c], &sig),
+            Some(1)
+        );
+        assert_eq!(
+            FalconVerifier::verify_512_any_message(&pubkey, &[b, b], &sig),
+            Some(0)
+        );
+        assert_eq!(
+            FalconVerifier::verify_512_any_message(&pubkey, &[a, c], &sig),
+            None
+        );
+        assert_eq!(
+            FalconVerifier::verify_512_any_message(&pubkey, &[], &sig),
+            None
+        );
+        assert_eq!(
+            FalconVerifier::verify_512_any_message(&pubkey[..896], &[b], &sig),
+            None
+        );
+        assert_eq!(
+            FalconVerifier::verify_512_any_message(&pubkey, &[b], &sig[..100]),
+            None
+        );
+    }
+
+    #[test]
+    fn test_verify_state_matches_verify_512() {
+        let pubkey = hex::decode(TEST_PUBKEY_HEX).unwrap();
+        let sig = hex::decode(TEST_SIG_HELLO_HEX).unwrap();
+        let mut bad_key = pubkey.clone();
+        bad_key[0] = 10;
+
+        for (pk, msg) in [
+            (&pubkey, &b"Hello, Falcon!"[..]),
+            (&pubkey, &b"Hello, Falcon?"[..]),
+            (&bad_key, &b"Hello, Falcon!"[..]),
+        ] {
+            let mut state = VerifyState::new();
+            let mut steps = 0;
+            while state.result().is_none() {
+                state = state.step(pk, msg, &sig);
+                steps += 1;
             }
+            assert_eq!(
+                state.result(),
+                Some(FalconVerifier::verify_512(pk, msg, &sig))
+            );
+            // Decoding rejects the mismatched key; the others run all four stages
+            assert_eq!(steps, if pk == &bad_key { 1 } else { 4 });
+            assert_eq!(state.clone().step(pk, msg, &sig), state);
         }
+        let state = VerifyState::new().step(&pubkey, b"Hello, Falcon!", &sig[..40]);
+        assert_eq!(state, VerifyState::Done(false));
+    }
 
-        // Any leftover bits in accumulator must be zero
-        if (acc & ((1u32 << acc_len) - 1)) != 0 {
-            return 0;
+    /// A state stored between every step, as across invocations, gives the
+    /// same result.
+    #[test]
+    fn test_verify_state_round_trips_between_steps() {
+        let pubkey = hex::decode(TEST_PUBKEY_HEX).unwrap();
+        let sig = hex::decode(TEST_SIG_HELLO_HEX).unwrap();
+
+        for msg in [&b"Hello, Falcon!"[..], b"Hello, Falcon?"] {
+            let mut stored = VerifyState::new().to_bytes();
+            let mut tags = [0u8; 5];
+            for (i, tag) in tags.iter_mut().enumerate() {
+                let state = VerifyState::from_bytes(&stored).unwrap();
+                assert_eq!(state.to_bytes(), stored);
+                *tag = stored[0];
+                if let Some(valid) = state.result() {
+                    assert_eq!(valid, FalconVerifier::verify_512(&pubkey, msg, &sig));
+                    assert_eq!(i, 4);
+                }
+                stored = state.step(&pubkey, msg, &sig).to_bytes();
+            }
+            assert_eq!(tags, [0, 1, 2, 3, 4]);
         }
+    }
 
-        in_len as usize
+    #[test]
+    fn test_verify_state_from_bytes_rejects_malformed() {
+        let pubkey = hex::decode(TEST_PUBKEY_HEX).unwrap();
+        let sig = hex::decode(TEST_SIG_HELLO_HEX).unwrap();
+        let msg = b"Hello, Falcon!";
+        let hash = VerifyState::new().step(&pubkey, msg, &sig);
+        let norm = hash
+            .clone()
+            .step(&pubkey, msg, &sig)
+            .step(&pubkey, msg, &sig);
+        let (hash, norm) = (hash.to_bytes(), norm.to_bytes());
+        // Offsets of the first s2 coefficient in Hash and of tt in Norm
+        let (hash_s2, norm_tt) = (1 + NONCE_LEN, 1 + 4 * FALCON_512_N);
+
+        let mut unknown_tag = VerifyState::Done(true).to_bytes();
+        unknown_tag[0] = 5;
+        let mut bad_result = VerifyState::Done(true).to_bytes();
+        bad_result[1] = 2;
+        let mut dirty_tail = VerifyState::Done(true).to_bytes();
+        dirty_tail[VerifyState::ENCODED_LEN - 1] = 1;
+        let mut big_s2 = hash;
+        big_s2[hash_s2..hash_s2 + 2].copy_from_slice(&2048i16.to_le_bytes());
+        let mut big_tt = norm;
+        big_tt[norm_tt..norm_tt + 2].copy_from_slice(&(Q as u16).to_le_bytes());
+
+        let cases: [(&str, &[u8]); 6] = [
+            ("short", &hash[..VerifyState::ENCODED_LEN - 1]),
+            ("unknown tag", &unknown_tag),
+            ("result not 0 or 1", &bad_result),
+            ("non-zero tail", &dirty_tail),
+            ("s2 coefficient out of range", &big_s2),
+            ("tt coefficient not below q", &big_tt),
+        ];
+        for (name, bytes) in cases {
+            assert_eq!(VerifyState::from_bytes(bytes), None, "{name}");
+        }
+        assert!(VerifyState::from_bytes(&hash).is_some());
+        assert!(VerifyState::from_bytes(&norm).is_some());
     }
 
-    /// Hashes nonce || message to a challenge polynomial using SHAKE256 with rejection sampling.
-    fn hash_to_point(nonce: &[u8], message: &[u8], c0: &mut [u16; FALCON_512_N]) {
-        use sha3::{
-            digest::{ExtendableOutput, Update, XofReader},
-            Shake256,
-        };
+    #[test]
+    fn test_falcon_l2_bound() {
+        const BOUND_512: Option<u64> = falcon_l2_bound(9);
+        assert_eq!(BOUND_512, Some(34034726));
+        assert_eq!(falcon_l2_bound(10), Some(70265242));
+        assert_eq!(Some(L2_BOUND_512 as u64), BOUND_512);
+        assert_eq!(Some(L2_BOUND_1024 as u64), falcon_l2_bound(10));
 
-        let mut hasher = Shake256::default();
-        hasher.update(nonce);
-        hasher.update(message);
-        let mut xof = hasher.finalize_xof();
+        // The standardised entries follow from σ: with σ scaled by 10^9,
+        // β² = 121 · 2n · σ² / 10^20 in exact integer arithmetic
+        for (logn, sigma) in [(9u32, 165_736_617_183u128), (10, 168_388_571_447)] {
+            let bound = 121 * (2u128 << logn) * sigma * sigma / 100_000_000_000_000_000_000;
+            assert_eq!(falcon_l2_bound(logn), Some(bound as u64));
+        }
 
-        let mut remaining = FALCON_512_N;
-        let mut idx = 0;
+        // Roughly doubles with the degree, and is undefined outside 1..=10
+        for logn in 2..=10 {
+            let (prev, cur) = (
+                falcon_l2_bound(logn - 1).unwrap(),
+                falcon_l2_bound(logn).unwrap(),
+            );
+            assert!(cur > 2 * prev && cur < 3 * prev, "logn {logn}");
+        }
+        for logn in [0, 11, u32::MAX] {
+            assert_eq!(falcon_l2_bound(logn), None);
+        }
+    }
 
-        while remaining > 0 {
-            let mut buf = [0u8; 2];
-            xof.read(&mut buf);
+    #[test]
+    fn test_sig_size() {
+        use SigFormat::*;
 
-            let w = ((buf[0] as u32) << 8) | (buf[1] as u32);
+        assert_eq!(sig_size(Compressed, 9), None);
+        assert_eq!(sig_size(Compressed, 10), None);
+        assert_eq!(sig_size(Padded, 9), Some(666));
+        assert_eq!(sig_size(Padded, 10), Some(1280));
+        assert_eq!(sig_size(ConstantTime, 9), Some(809));
+        assert_eq!(sig_size(ConstantTime, 10), Some(1577));
+        assert_eq!(
+            sig_size(ConstantTime, FALCON_512_LOGN),
+            Some(FALCON_SIG_MAX_SIZE as usize)
+        );
 
-            const ACCEPT_THRESHOLD: u32 = 5 * Q;
-            if w < ACCEPT_THRESHOLD {
-                let mut v = w;
-                while v >= Q {
-                    v -= Q;
-                }
-                c0[idx] = v as u16;
-                idx += 1;
-                remaining -= 1;
-            }
+        // The length checks use the same sizes
+        for logn in [9, 10] {
+            assert_eq!(
+                format_expects_length(Padded, logn),
+                0..=sig_size(Padded, logn as u32).unwrap()
+            );
+            let ct = sig_size(ConstantTime, logn as u32).unwrap();
+            assert_eq!(format_expects_length(ConstantTime, logn), ct..=ct);
+            assert_eq!(*signature_size_bounds(logn).end(), ct);
         }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn test_sig_size_rejects_other_degrees() {
+        use SigFormat::*;
+
+        for logn in [0, 8, 11, u32::MAX] {
+            for format in [Compressed, Padded, ConstantTime] {
+                assert_eq!(sig_size(format, logn), None, "logn {logn}");
+            }
+            for len in [0, 666, 809, 1280, 1577] {
+                assert_eq!(FalconVerifier::format_from_length(len, logn), None);
+            }
+        }
+    }
 
     #[test]
-    fn test_is_short_zero() {
-        let s1 = [0i16; FALCON_512_N];
-        let s2 = [0i16; FALCON_512_N];
-        assert!(FalconVerifier::is_short(&s1, &s2));
+    #[should_panic(expected = "only defined for logn 9 and 10")]
+    fn test_format_expects_length_rejects_other_degrees() {
+        format_expects_length(SigFormat::Padded, 8);
     }
 
     #[test]
-    fn test_is_short_small() {
-        let mut s1 = [0i16; FALCON_512_N];
-        let mut s2 = [0i16; FALCON_512_N];
+    fn test_format_from_length() {
+        use SigFormat::*;
 
-        // Small values should pass
-        for i in 0..FALCON_512_N {
-            s1[i] = ((i % 10) as i16) - 5;
-            s2[i] = ((i % 10) as i16) - 5;
+        assert_eq!(FalconVerifier::format_from_length(666, 9), Some(Padded));
+        assert_eq!(
+            FalconVerifier::format_from_length(809, 9),
+            Some(ConstantTime)
+        );
+        assert_eq!(FalconVerifier::format_from_length(1280, 10), Some(Padded));
+        assert_eq!(
+            FalconVerifier::format_from_length(1577, 10),
+            Some(ConstantTime)
+        );
+
+        // Sizes of the other degree, and compressed-only lengths
+        for len in [0, 41, 665, 667, 808, 810, 1280, 1577] {
+            assert_eq!(FalconVerifier::format_from_length(len, 9), None);
         }
-        assert!(FalconVerifier::is_short(&s1, &s2));
+        for len in [0, 666, 809, 1279, 1281, 1576, 1578] {
+            assert_eq!(FalconVerifier::format_from_length(len, 10), None);
+        }
+
+        // Only a hint: the test signature has the padded size but a
+        // compressed header, as in the NIST KAT files
+        let sig = hex::decode(TEST_SIG_HELLO_HEX).unwrap();
+        assert_eq!(sig[0] & 0xF0, 0x30);
+        assert_eq!(
+            FalconVerifier::format_from_length(sig.len(), 9),
+            Some(Padded)
+        );
     }
 
     #[test]
-    fn test_pubkey_decode_header() {
-        let mut h = [0u16; FALCON_512_N];
+    fn test_verify_512_no_ct() {
+        let pubkey = hex::decode(TEST_PUBKEY_HEX).unwrap();
+        let sig = hex::decode(TEST_SIG_HELLO_HEX).unwrap();
+        let msg = b"Hello, Falcon!";
 
-        // Wrong header should fail
-        let bad_pk = [8u8; FALCON_512_PUBKEY_SIZE]; // Wrong logn
-        assert!(!FalconVerifier::decode_pubkey(&bad_pk, &mut h));
+        let mut s2 = [0i16; FALCON_512_N];
+        FalconVerifier::decode_sig_compressed(&sig[41..], &mut s2);
+        let mut ct = [0u8; 41 + 768];
+        ct[0] = 0x59;
+        ct[1..41].copy_from_slice(&sig[1..41]);
+        ct[41..].copy_from_slice(&encode_ct_body(&s2));
+        assert!(FalconVerifier::verify_512(&pubkey, msg, &ct));
 
-        // Too short should fail
-        let short_pk = [9u8; 100];
-        assert!(!FalconVerifier::decode_pubkey(&short_pk, &mut h));
+        assert!(FalconVerifier::verify_512_no_ct(&pubkey, msg, &sig));
+        assert!(!FalconVerifier::verify_512_no_ct(&pubkey, msg, &ct));
+        assert!(!FalconVerifier::verify_512_no_ct(
+            &pubkey,
+            b"Hello, Falcon?",
+            &sig
+        ));
+        assert!(!FalconVerifier::verify_512_no_ct(&pubkey, msg, &[]));
     }
 
     #[test]
-    fn test_cross_verify_with_c_bindings() {
-        // Generated with seed: 2a31383f464d545b626970777e858c939aa1a8afb6bdc4cbd2d9e0e7eef5fc030a11181f262d343b424950575e656c73
-        let pubkey_hex = "0902c671f64d92df6c446a63f5061d73fab61be667e74db66752251102a105922a6fe56a7b3a48196bafc22de2275600dfd8b4149842bf0a5f3b7df4e1f6608f5394aae63e918a7bc492426a62e64d1873fb72c020a3c6be3a9295bc29aaf1c351267c6b00ffc2aa003f64fa9133628b2996b4327b7ee6366b9acb4067e30715fcf68273e04880a453eb468eff0a8d563af3235c6cae44984e8ed8911a34222ed6ec3274f8c491893a9f74ab6b1d67daa0083eb666c098acd4745aa208362a8e14b906437c2cc1ca044a5b903724c9066cd662a622cc38165a4d91322e193c48d12b5e20977bdb4816d6c1aa6a8a4118705029de6fd8723d3ca408ea0c296ceba31e903fbbc9dd60b0c1ca74a1a995d3cf449518815ab29f227d257491f758630484e3a6e36c83008069e538e3e65272f0a5440d8e6998e516e1a5390045b986c24975567c8ce8eae5b29916797516c04f69085a0112e9295b8d96e878410e12507ff9ba012c1f352a84be660a467a95321c8947b07440d58ac215b9cc2ee3d2e5c5af1e9044aed41e94305390c5110c27e5ee3a620c898f90671911e58f75c1085551618b5b4443e3e3527955357007d8696bb59e0d625f248f513de19916a093b43ef00b8d8211a3801874c9687b792e9588a59622b748ae5adc1ff98d0040506cd7c720e64123631bdd70628fa2534bf1094d92b82f2d5fb586d715dee362ac6cd33268a3249669c853fde1643222968b072d07be36764962d3c6a0550038bce88219585357616fb63e701f923ae986247850c7c5ad74bd3e8cf342623cabb8e467fe55a1103975f9af1235995ca30bfe8ea9af0619a2995a283e5cd49bae9a9737201d152d253f50e526d55c59ae8675eeca051bbf44f4c9e530cdfca2c0b192cf8f779a85de921e06a48b71ac1170af6c50c16d3328149c5a682ceb18a01f1de6207319d54a5f205ff82d8ae5536a924721e68c83b82d47dbc0854db1d392e055e2702e8a9401e200616d43aa8c25075712b1f0274f097cf51423685a051d35afb9a9d3217e365e95d95bff5a31e8320bc423bc5052d1ec04739005090a8e6f95b53014129aa30b937cf157c6d0bfa77263e3a2d435954e30f790a4ca062e7d17aa2d52a5a4aec83108c12e24fcf97a9119554eadf26b5447b1d0d7e0484b58122a1b68aa15bd3e5db8927b4240785966f5cba8784b752d723a86c13c005ec57fe22bb18afd43d1093d232ac8b09f920d2a8cbec54e56f93edd6dd235a1ef";
-        let pubkey = hex::decode(pubkey_hex).expect("Invalid pubkey hex");
+    fn test_verify_512_nonce_predicate() {
+        let pubkey = hex::decode(TEST_PUBKEY_HEX).unwrap();
+        let sig = hex::decode(TEST_SIG_HELLO_HEX).unwrap();
+        let msg = b"Hello, Falcon!";
+        let epoch: [u8; 4] = sig[1..5].try_into().unwrap();
+        let has_epoch = |e: [u8; 4]| move |nonce: &[u8; 40]| nonce[..4] == e;
 
-        // falcon512_0: "Hello, Falcon!" (0x48656c6c6f2c2046616c636f6e21)
-        let sig_0 = "399e11dbc7c5328dbdd260d989a2e58c18e698b7ee2c94235312fabbae38c24058d1dd43fe030b3f2583c4e2dcc445a1c76624aa2e2a0527fd6a6398a521b5c6d6391c9caf0729893d087fd672d38c0232e9ff98e313bebbe069e93a371de31f7e6c2905544a210fa3363aa23ce2418803d6b1fee2a275f3e8f2d6585ffa30ac2bf639345d78b1da59a2c1187a3f79190b3b788537993873fb9755bc8dd7723fbbefeaa5fd89a25298609f4f7ec5988292c4a976f833d6f312eaea792e53d9b49b31bd5bd20ee4bef5a887359d5c71e86e4d14c56848d23d65f2dd65775d2a0f47549d6289b1ab4897142aa12d7424ac17c4ce1ba84ea6094f448e0e57c53ea64521596220cdef215ad311b6d57723de37438ebae27d38fae24e81eefc98a88e9ea39d5418a53b9fd4912624ae4f81e219759ecb1759b6bee72de06285432f3c7c310c0b867b5afdff29658f45610854fbdecb1b04524cc0b6d16edccb37dace29db3becd6779ded4caa6f5a277b852d11ad2a46b8d731c6ef694c39bb3772532bc0f99757ab4ce76ae25d646c7dd8eecdee84b3b3040797975ff39782a11b8eb65507fe415c5a39b6862949f6eeb1c53c996f14be765154c9b239230990621e52513b5da72bcfc6a48433cefcb843a1127a2335d559161f9db54eb798bb15c65d4ad073f0d9f52cc6cba122ed824726758226cbe41d340bd495c131f891eecb1837b9df7e66e8695355fd5853e736d4bedc224063f08ac33b6e9bd5e21ad8ec52a2b14e225299399a26287f28c4d8a3567f3a685fa5dfa2f94ac8476b38793b7d4fd711bafb5ebeac3f65e70466a51455cba3946a6688e6cb14ef1386143efc7638f655910f751bd4ecc5168a142495937fb5afb5e84698a35d829ef83a387336c622f1b8b3bab64d9eca1a0000000000000000000000000000";
-        let sig_0_bytes = hex::decode(sig_0).expect("Invalid sig hex");
-        assert!(
-            FalconVerifier::verify_512(&pubkey, b"Hello, Falcon!", &sig_0_bytes),
-            "falcon512_0: 'Hello, Falcon!' verification failed"
+        assert!(FalconVerifier::verify_512_nonce_predicate(
+            &pubkey,
+            msg,
+            &sig,
+            has_epoch(epoch)
+        ));
+        let mut other = epoch;
+        other[3] ^= 1;
+        assert!(!FalconVerifier::verify_512_nonce_predicate(
+            &pubkey,
+            msg,
+            &sig,
+            has_epoch(other)
+        ));
+        // The predicate does not rescue an invalid signature
+        assert!(!FalconVerifier::verify_512_nonce_predicate(
+            &pubkey,
+            b"Hello, Falcon?",
+            &sig,
+            has_epoch(epoch)
+        ));
+        assert!(!FalconVerifier::verify_512_nonce_predicate(
+            &pubkey,
+            msg,
+            &sig[..100],
+            |_| true
+        ));
+    }
+
+    #[test]
+    fn test_verify_512_bounded() {
+        let pubkey = hex::decode(TEST_PUBKEY_HEX).unwrap();
+        let sig = hex::decode(TEST_SIG_HELLO_HEX).unwrap();
+        let msg = b"Hello, Falcon!";
+
+        for max_len in [msg.len(), msg.len() + 1, usize::MAX] {
+            assert_eq!(
+                FalconVerifier::verify_512_bounded(&pubkey, msg, &sig, max_len),
+                Ok(())
+            );
+        }
+        // One byte over the limit errors instead of hashing a truncated message
+        assert_eq!(
+            FalconVerifier::verify_512_bounded(&pubkey, msg, &sig, msg.len() - 1),
+            Err(VerifyError::MessageTooLarge)
         );
+        // The size check comes first
+        assert_eq!(
+            FalconVerifier::verify_512_bounded(&pubkey, msg, &sig[..10], 0),
+            Err(VerifyError::MessageTooLarge)
+        );
+        assert_eq!(
+            FalconVerifier::verify_512_bounded(&pubkey, b"Hello, Falcon?", &sig, 14),
+            Err(VerifyError::NormTooLarge)
+        );
+    }
 
-        // falcon512_1: "Test message for cross-implementation verification"
-        let sig_1 = "399e11dbc7c5328dbdd260d989a2e58c18e698b7ee2c94235312fabbae38c24058d1dd43fe030b3f2591405b77214f2bd4393a17b9c35ef79a4ce21eda5ef85a912da064952654634e34294e4b2dcfdc11257ad245db17ca89445ec4c3871ada38484bf8a2a96829c16edf3d335bb7e96438bbb42b81a490e849d0fc72b2d13eaa152e2549375acd1f1cd6fa9c934987574ac9d2f58cd4e6e5ce5ad91ecc9218d210f5cf173235b9b8e775fae71fc41560c7ab344fc489803372ea7da65b01b5678655d55e1465f218a0344831e968b78dfa696838c50731346792e306b54d64d28675d2c2c65f46caee820edf265bd8dff8fca8e10a4755797751fd643db8b35e68f7546e1292640af0daab7b641ea1f364e98ada0d85441b4ecdd7c947da6d965bb3e7c9bf469ac4c19c3a3cd949385d38aee31bacb0ed3bd65caad0a6dae9ead699b3bef43b4f33aaf34375d7be1f813ac11b26ca7f8179db36cb587a13e4a4f5382cbe65264d99be82daf8d9e4b6149a49d6c5eb14a76642db163912c7e4c7140d07073995d920eddc667f538ed9ed3851cc8cdda11c7d8d9bbcfe4e62f7d35fa561f2f2522850b9fe6a02a4b046596c8a710580b5843f971edac9547ca3aea815393669b6d952082f6be3245f19a8e3c2b97664c8e919ae9972c59acf6d2d5e6e28cf11654f4a32e764de3b295c372101cafbfaf1bf76651c4e99e1096e12f9747635bfa94098c529a36d85b664e7cfd319170a2ff1641a78ba79497970be9fe47ea3a2e660499e73273378377417f6327359b430d1b7ed38aab2bdea3fab0b9d281e8df529b8cf286cf18c506e6ca1b229f8a81c873486cf23f58105d7ec4aa4a2d255b16d9ac0bc7ad7caa7f53c26edd7d99848b34a360cc25eae5bb1cb8b150731216f742bbc2fe9bd6421b9c4000000000000000000";
-        let sig_1_bytes = hex::decode(sig_1).expect("Invalid sig hex");
-        assert!(
-            FalconVerifier::verify_512(
+    #[test]
+    fn test_verify_512_chunks() {
+        let pubkey = hex::decode(TEST_PUBKEY_HEX).unwrap();
+        let sig = hex::decode(TEST_SIG_HELLO_HEX).unwrap();
+        let message: &[u8] = b"Hello, Falcon!";
+
+        for split in 0..=message.len() {
+            let (head, tail) = message.split_at(split);
+            assert!(FalconVerifier::verify_512_chunks(
                 &pubkey,
-                b"Test message for cross-implementation verification",
-                &sig_1_bytes
-            ),
-            "falcon512_1: cross-impl verification failed"
-        );
+                [head, tail].into_iter(),
+                &sig
+            ));
+        }
+        // One byte per chunk, with empty chunks interleaved
+        let bytes = message.chunks(1).flat_map(|b| [b, &[][..]]);
+        assert!(FalconVerifier::verify_512_chunks(&pubkey, bytes, &sig));
 
-        // falcon512_2: empty message
-        let sig_2 = "399e11dbc7c5328dbdd260d989a2e58c18e698b7ee2c94235312fabbae38c24058d1dd43fe030b3f25bbafe2f1767a33929d6cbe92c46e1666c9e36c314cec389f476cf63a639a984e46fd63e4ec65fae59abb3e4570d016d67b6f52bdff6eef1d24d0a20869518d31667dabbd77b3063317b8ce5fa7b94eab750a929066395fbe54fd8897bfe517e12826813c94d2ad9e384391992d8da2851430ba8c0e9d8d547a7525827f0382a13c4e1aab19e98957810975a0d822992439fc03dcd5f9bcb1971e30d87234ec67462dc6d75b5e9a0db6f53f675e5c522951640d675ed096bdfe8889a4b2686829b21eeec48c35662bac39b8e723edaf71920519dbe357366c3c2a7272f192d21315fc7c7749e993aae132cb29dcd41b197e7997f7652c971824438351984c151d06192177319f9da62be786966e495695c4e82d99cb9fcd66e86a3e84d25c56a2c8ea4fddf2ab9c2c1c53acd597aee372867db08fb4f3b92e569027115a475dfed273599a51ed460d35ca7be3f99c22018da0b9c976e20fce8714d71687dfce50588336aeb6d48f926e81b8e9a5aaa9f2702c3bd5baf3b3a9e28956a2118fab99e8ff2e16b44856c83953e6273ce46655a3460ae996ba4520a7a722be6b1a0628802f9c4822b7a27ee529a419fa9d6a767d643fd1a9eea66bf68efd4f92a5f005d48323150b2e5d9379147218a0bb7853067af0faac2cbd3a879d3f87850935b0056bc703bdc3ae33fb2cff849d4e59af2b44ee76316a572d45155d7aaecaf2b3fbe271de6cb8e7063c9ad53ca428fa6f60b3a510a260fd091c810a605ef652e542c633deb1c0b31a662b61a2c3a00a6f8bbcc8582db5861e45998f6b60142ab4fa6ade67497c6d8f65f5c604e7efab1cc9ca79e38ddaa7b72b01ddd9ef1318f61e00000000000000000000000000000000";
-        let sig_2_bytes = hex::decode(sig_2).expect("Invalid sig hex");
-        let empty_msg: &[u8] = b"";
-        assert!(
-            FalconVerifier::verify_512(&pubkey, empty_msg, &sig_2_bytes),
-            "falcon512_2: empty message verification failed"
-        );
+        // The same bytes in another order, or with one missing, are a different message
+        assert!(!FalconVerifier::verify_512_chunks(
+            &pubkey,
+            [&message[7..], &message[..7]].into_iter(),
+            &sig
+        ));
+        assert!(!FalconVerifier::verify_512_chunks(
+            &pubkey,
+            [&message[..13]].into_iter(),
+            &sig
+        ));
+        assert!(!FalconVerifier::verify_512_chunks(
+            &pubkey,
+            core::iter::empty(),
+            &sig
+        ));
+        assert!(!FalconVerifier::verify_512_chunks(
+            &pubkey,
+            [message].into_iter(),
+            &sig[..41]
+        ));
+    }
 
-        // falcon512_3: binary data 0x00..0x63 (100 bytes)
-        let sig_3 = "399e11dbc7c5328dbdd260d989a2e58c18e698b7ee2c94235312fabbae38c24058d1dd43fe030b3f2584574f5f13cef9416249e48bd1e249b63af2728c4871e45b21a271d0432b256616b63300cce2dce131833da501e2c7eb7455dd03875579e2c89b553ebd2b9274d19a56f2c4093875b8924ebb1e6b13b61d0868dc5e2aa9a0dfbf0a9f8fa915e238586dc289068d3d32d8269a8e715f99e99072b2d3f306dea87cbcca090353a12dcb3672b3ecda9a9fc6dbdae9e8a5254357384fa8cf6b052084d67fae0479d187e3a3e85a24deb948ecfa8ace45f88d7ed2f50aa4b43a4d65d5c161556bc507debbe9fe9a9c85074688658f84e943e5ffa259af6d5e999dc3f369345d82957f1f6dab8f2d8316c48d21628cd61341313124133291c563892262dca51a95a18f6e77c503d78984dc180617694c49e96b0a95b3a9eee16ab89cae13fb5fa62c824bf776a55f9bd8fff777ba24817d9eca896569077aa416fa16f5ba64ef542429d55cfe3b6410a9525e8fe4655774b3648620b7315cb6cd232a15b358beca70e40e01df74a5bcc74f3066a1ad1cf39eb972fa0bec360beeae2a7913ea4e94033369c9264a7259677aa51c23fd0ec617fe96370cff654541a3a2fc51335f2ebe65f1373a2479fb23066bcf9e6b1d2acf0fdd114c5249560e58311c698c03abefa12d570466286b9ca993837e5d6bfcadb14f7498736b5d22f86ed25ddeab3509a1aa39442f51ae9faeac4a81a573abff6b66253cad32dd774244c62ab74e13226f91b314e5b39daa0237bed0ba0a0ecb356cf27f2ac9b483e0f4c4e3a605ee4f7aba4e567674e7fca18e6a268944a82cdaeaec73bde42b9adab7ac5ad2b294778e8da0ba34e97555ce69bbbffbd640a025d5ba449e98286c4350c7346e4f2935adf00e9628f7c00000000000000000000000";
-        let sig_3_bytes = hex::decode(sig_3).expect("Invalid sig hex");
-        let binary_msg: [u8; 100] = core::array::from_fn(|i| i as u8);
-        assert!(
-            FalconVerifier::verify_512(&pubkey, &binary_msg, &sig_3_bytes),
-            "falcon512_3: binary data verification failed"
-        );
+    #[test]
+    fn test_verify_512_streaming() {
+        let env = soroban_sdk::Env::default();
+        let pubkey = hex::decode(TEST_PUBKEY_HEX).unwrap();
+        let sig = hex::decode(TEST_SIG_HELLO_HEX).unwrap();
 
-        // Negative tests - wrong messages should fail
-        assert!(
-            !FalconVerifier::verify_512(&pubkey, b"Wrong message", &sig_0_bytes),
-            "Wrong message should fail"
-        );
-        assert!(
-            !FalconVerifier::verify_512(&pubkey, b"Hello, Falcon!", &sig_1_bytes),
-            "Mismatched signature should fail"
-        );
-        assert!(
-            !FalconVerifier::verify_512(&pubkey, b"Hello, Falcon!", &sig_2_bytes),
-            "Empty msg signature with 'Hello, Falcon!' should fail"
-        );
+        let pk = Bytes::from_slice(&env, &pubkey);
+        let signature = Bytes::from_slice(&env, &sig);
+        let message = Bytes::from_slice(&env, b"Hello, Falcon!");
+        assert!(FalconVerifier::verify_512_streaming(
+            &pk, &message, &signature
+        ));
+
+        let wrong = Bytes::from_slice(&env, b"Hello, Falcon?");
+        assert!(!FalconVerifier::verify_512_streaming(
+            &pk, &wrong, &signature
+        ));
+        let short_pk = Bytes::from_slice(&env, &pubkey[..896]);
+        assert!(!FalconVerifier::verify_512_streaming(
+            &short_pk, &message, &signature
+        ));
+
+        // Messages spanning several chunks hash the same as the contiguous form
+        let mut long = [0u8; 3 * STREAM_CHUNK as usize + 17];
+        for (i, b) in long.iter_mut().enumerate() {
+            *b = i as u8;
+        }
+        let mut expected = ChallengeStream::new(&sig[1..41], &long);
+        let mut hasher = Shake256::default();
+        hasher.update(&sig[1..41]);
+        absorb_bytes(&mut hasher, &Bytes::from_slice(&env, &long));
+        let mut streamed = ChallengeStream::from_hasher(hasher);
+        for _ in 0..FALCON_512_N {
+            assert_eq!(streamed.next_coeff(), expected.next_coeff());
+        }
     }
 }
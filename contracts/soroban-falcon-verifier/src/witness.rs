@@ -0,0 +1,46 @@
+//! Witness extraction for zero-knowledge proofs of a valid Falcon signature.
+
+extern crate std;
+
+use std::boxed::Box;
+
+use crate::{FalconSignature, FalconVerifier, Packing, FALCON_512_N};
+
+/// A Falcon-512 polynomial on the heap.
+type BoxedPoly = Box<[i16; FALCON_512_N]>;
+
+impl FalconVerifier {
+    /// Verifies a Falcon-512 signature and returns the short vector (s1, s2)
+    /// behind it, as witnesses for a circuit proving knowledge of a valid
+    /// signature.
+    ///
+    /// Both polynomials are in centered form, coefficient i holding the
+    /// coefficient of x^i, and together they satisfy
+    ///
+    /// ```text
+    /// s1 + s2·h = c0  (mod q, mod x^512 + 1)
+    /// ||s1||² + ||s2||² <= L2_BOUND_512
+    /// ```
+    ///
+    /// where h is the decoded public key and c0 the challenge from
+    /// [`hash_to_point`](Self::hash_to_point) over the signature's nonce and
+    /// `message`. s2 is the signature body as decoded, in [-2047, 2047]; s1
+    /// is c0 - s2·h reduced into [-q/2, q/2], as written by
+    /// [`verify_512_into`](Self::verify_512_into). The arrays are boxed to
+    /// keep 2 KB off the caller's stack. Only available with the `std`
+    /// feature.
+    ///
+    /// # Returns
+    /// `Some((s1, s2))` if the signature is valid, `None` otherwise.
+    pub fn verify_512_witness(
+        pubkey: &[u8],
+        message: &[u8],
+        signature: &[u8],
+    ) -> Option<(BoxedPoly, BoxedPoly)> {
+        let sig = FalconSignature::from_bytes(signature).ok()?;
+        let mut s1 = Box::new([0i16; FALCON_512_N]);
+        // Verify the parsed signature so s2 is the polynomial that was checked
+        Self::verify_parsed_into(pubkey, message, &sig, Packing::MsbFirst, &mut s1).ok()?;
+        Some((s1, Box::new(*sig.s2())))
+    }
+}
@@ -0,0 +1,126 @@
+//! Tests for the experimental batch verifier.
+
+#![cfg(feature = "research")]
+
+use soroban_falcon_verifier::{BatchItem, FalconVerifier, FALCON_512_N};
+
+// Keypair from seed 0x30..0x5f, as for the COSE fixture
+const BATCH_PUBKEY_HEX: &str = include_str!("fixtures/cose_pubkey.hex");
+// One padded signature per line, over "Batch message 0" to "Batch message 3"
+const BATCH_SIGNATURES_HEX: &str = include_str!("fixtures/batch_signatures.hex");
+
+struct Signed {
+    message: Vec<u8>,
+    signature: Vec<u8>,
+    s1: [i16; FALCON_512_N],
+}
+
+fn signed_messages(pubkey: &[u8]) -> Vec<Signed> {
+    BATCH_SIGNATURES_HEX
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            let message = format!("Batch message {i}").into_bytes();
+            let signature = hex::decode(line.trim()).unwrap();
+            let mut s1 = [0i16; FALCON_512_N];
+            assert!(FalconVerifier::verify_512_into(
+                pubkey, &message, &signature, &mut s1
+            ));
+            Signed {
+                message,
+                signature,
+                s1,
+            }
+        })
+        .collect()
+}
+
+fn items(signed: &[Signed]) -> Vec<BatchItem<'_>> {
+    signed
+        .iter()
+        .map(|s| BatchItem {
+            message: &s.message,
+            signature: &s.signature,
+            s1: &s.s1,
+        })
+        .collect()
+}
+
+/// The batch result must equal checking every item on its own, with its hint.
+fn assert_matches_individual(pubkey: &[u8], batch: &[BatchItem]) {
+    let individual = batch.iter().all(|item| {
+        let mut s1 = [0i16; FALCON_512_N];
+        FalconVerifier::verify_512_into(pubkey, item.message, item.signature, &mut s1)
+            && s1 == *item.s1
+    });
+    assert_eq!(FalconVerifier::verify_512_batch(pubkey, batch), individual);
+}
+
+#[test]
+fn test_batch_accepts_valid_signatures() {
+    let pubkey = hex::decode(BATCH_PUBKEY_HEX.trim()).unwrap();
+    let signed = signed_messages(&pubkey);
+    assert_eq!(signed.len(), 4);
+    let batch = items(&signed);
+
+    assert!(FalconVerifier::verify_512_batch(&pubkey, &batch));
+    for len in 0..=batch.len() {
+        assert!(FalconVerifier::verify_512_batch(&pubkey, &batch[..len]));
+        assert_matches_individual(&pubkey, &batch[..len]);
+    }
+    // Repeating a valid item is still valid
+    let repeated = [batch[0], batch[1], batch[0]];
+    assert!(FalconVerifier::verify_512_batch(&pubkey, &repeated));
+}
+
+#[test]
+fn test_batch_rejects_any_invalid_item() {
+    let pubkey = hex::decode(BATCH_PUBKEY_HEX.trim()).unwrap();
+    let signed = signed_messages(&pubkey);
+
+    for bad in 0..signed.len() {
+        // A signature over another message
+        let mut batch = items(&signed);
+        batch[bad].message = b"Not a batch message";
+        assert!(!FalconVerifier::verify_512_batch(&pubkey, &batch));
+        assert_matches_individual(&pubkey, &batch);
+
+        // A valid signature with another item's hint
+        let mut batch = items(&signed);
+        batch[bad].s1 = &signed[(bad + 1) % signed.len()].s1;
+        assert!(!FalconVerifier::verify_512_batch(&pubkey, &batch));
+        assert_matches_individual(&pubkey, &batch);
+
+        // A short hint that does not satisfy the relation
+        let zero = [0i16; FALCON_512_N];
+        let mut batch = items(&signed);
+        batch[bad].s1 = &zero;
+        assert!(!FalconVerifier::verify_512_batch(&pubkey, &batch));
+        assert_matches_individual(&pubkey, &batch);
+
+        // A signature that does not decode
+        let mut batch = items(&signed);
+        batch[bad].signature = &signed[bad].signature[..41];
+        assert!(!FalconVerifier::verify_512_batch(&pubkey, &batch));
+        assert_matches_individual(&pubkey, &batch);
+    }
+
+    // Two failing relations that cancel in a plain sum still fail: the hint
+    // errors are +1 and -1 in the same coefficient
+    let mut plus = signed[0].s1;
+    plus[0] += 1;
+    let mut minus = signed[1].s1;
+    minus[0] -= 1;
+    let mut batch = items(&signed);
+    batch[0].s1 = &plus;
+    batch[1].s1 = &minus;
+    assert!(!FalconVerifier::verify_512_batch(&pubkey, &batch));
+
+    // Valid signatures under another key
+    let other_key = hex::decode(include_str!("fixtures/test_pubkey.hex").trim()).unwrap();
+    assert!(!FalconVerifier::verify_512_batch(
+        &other_key,
+        &items(&signed)
+    ));
+    assert!(!FalconVerifier::verify_512_batch(&pubkey[..100], &[]));
+}
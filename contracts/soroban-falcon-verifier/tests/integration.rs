@@ -2,8 +2,13 @@
 
 #![cfg(feature = "testutils")]
 
-use soroban_falcon_verifier::{FalconVerifierContract, FalconVerifierContractClient};
-use soroban_sdk::{Bytes, Env};
+use sha3::{Digest, Sha3_256};
+use soroban_falcon_verifier::{
+    FalconVerifier, FalconVerifierContract, FalconVerifierContractClient, HashMode,
+    FALCON_SIG_MAX_SIZE, FALCON_SIG_MIN_SIZE, MEMO_TTL_LEDGERS, RECORD_TTL_LEDGERS,
+};
+use soroban_sdk::testutils::{storage::Persistent, Ledger};
+use soroban_sdk::{Bytes, BytesN, Env, Symbol};
 
 // Test vector generated using the falcon crate with generate_vectors binary
 // Seed: 2a31383f464d545b626970777e858c939aa1a8afb6bdc4cbd2d9e0e7eef5fc030a11181f262d343b424950575e656c73
@@ -15,6 +20,10 @@ const TEST_PUBKEY_HEX: &str = include_str!("fixtures/test_pubkey.hex");
 const TEST_SIGNATURE_HEX: &str = include_str!("fixtures/test_signature.hex");
 const TEST_MESSAGE: &[u8] = b"Hello, Falcon!";
 
+// Cross-implementation vector for the empty message (same keypair as the C bindings tests)
+const EMPTY_MSG_PUBKEY_HEX: &str = "0902c671f64d92df6c446a63f5061d73fab61be667e74db66752251102a105922a6fe56a7b3a48196bafc22de2275600dfd8b4149842bf0a5f3b7df4e1f6608f5394aae63e918a7bc492426a62e64d1873fb72c020a3c6be3a9295bc29aaf1c351267c6b00ffc2aa003f64fa9133628b2996b4327b7ee6366b9acb4067e30715fcf68273e04880a453eb468eff0a8d563af3235c6cae44984e8ed8911a34222ed6ec3274f8c491893a9f74ab6b1d67daa0083eb666c098acd4745aa208362a8e14b906437c2cc1ca044a5b903724c9066cd662a622cc38165a4d91322e193c48d12b5e20977bdb4816d6c1aa6a8a4118705029de6fd8723d3ca408ea0c296ceba31e903fbbc9dd60b0c1ca74a1a995d3cf449518815ab29f227d257491f758630484e3a6e36c83008069e538e3e65272f0a5440d8e6998e516e1a5390045b986c24975567c8ce8eae5b29916797516c04f69085a0112e9295b8d96e878410e12507ff9ba012c1f352a84be660a467a95321c8947b07440d58ac215b9cc2ee3d2e5c5af1e9044aed41e94305390c5110c27e5ee3a620c898f90671911e58f75c1085551618b5b4443e3e3527955357007d8696bb59e0d625f248f513de19916a093b43ef00b8d8211a3801874c9687b792e9588a59622b748ae5adc1ff98d0040506cd7c720e64123631bdd70628fa2534bf1094d92b82f2d5fb586d715dee362ac6cd33268a3249669c853fde1643222968b072d07be36764962d3c6a0550038bce88219585357616fb63e701f923ae986247850c7c5ad74bd3e8cf342623cabb8e467fe55a1103975f9af1235995ca30bfe8ea9af0619a2995a283e5cd49bae9a9737201d152d253f50e526d55c59ae8675eeca051bbf44f4c9e530cdfca2c0b192cf8f779a85de921e06a48b71ac1170af6c50c16d3328149c5a682ceb18a01f1de6207319d54a5f205ff82d8ae5536a924721e68c83b82d47dbc0854db1d392e055e2702e8a9401e200616d43aa8c25075712b1f0274f097cf51423685a051d35afb9a9d3217e365e95d95bff5a31e8320bc423bc5052d1ec04739005090a8e6f95b53014129aa30b937cf157c6d0bfa77263e3a2d435954e30f790a4ca062e7d17aa2d52a5a4aec83108c12e24fcf97a9119554eadf26b5447b1d0d7e0484b58122a1b68aa15bd3e5db8927b4240785966f5cba8784b752d723a86c13c005ec57fe22bb18afd43d1093d232ac8b09f920d2a8cbec54e56f93edd6dd235a1ef";
+const EMPTY_MSG_SIGNATURE_HEX: &str = "399e11dbc7c5328dbdd260d989a2e58c18e698b7ee2c94235312fabbae38c24058d1dd43fe030b3f25bbafe2f1767a33929d6cbe92c46e1666c9e36c314cec389f476cf63a639a984e46fd63e4ec65fae59abb3e4570d016d67b6f52bdff6eef1d24d0a20869518d31667dabbd77b3063317b8ce5fa7b94eab750a929066395fbe54fd8897bfe517e12826813c94d2ad9e384391992d8da2851430ba8c0e9d8d547a7525827f0382a13c4e1aab19e98957810975a0d822992439fc03dcd5f9bcb1971e30d87234ec67462dc6d75b5e9a0db6f53f675e5c522951640d675ed096bdfe8889a4b2686829b21eeec48c35662bac39b8e723edaf71920519dbe357366c3c2a7272f192d21315fc7c7749e993aae132cb29dcd41b197e7997f7652c971824438351984c151d06192177319f9da62be786966e495695c4e82d99cb9fcd66e86a3e84d25c56a2c8ea4fddf2ab9c2c1c53acd597aee372867db08fb4f3b92e569027115a475dfed273599a51ed460d35ca7be3f99c22018da0b9c976e20fce8714d71687dfce50588336aeb6d48f926e81b8e9a5aaa9f2702c3bd5baf3b3a9e28956a2118fab99e8ff2e16b44856c83953e6273ce46655a3460ae996ba4520a7a722be6b1a0628802f9c4822b7a27ee529a419fa9d6a767d643fd1a9eea66bf68efd4f92a5f005d48323150b2e5d9379147218a0bb7853067af0faac2cbd3a879d3f87850935b0056bc703bdc3ae33fb2cff849d4e59af2b44ee76316a572d45155d7aaecaf2b3fbe271de6cb8e7063c9ad53ca428fa6f60b3a510a260fd091c810a605ef652e542c633deb1c0b31a662b61a2c3a00a6f8bbcc8582db5861e45998f6b60142ab4fa6ade67497c6d8f65f5c604e7efab1cc9ca79e38ddaa7b72b01ddd9ef1318f61e00000000000000000000000000000000";
+
 #[test]
 fn test_verify_with_generated_signature() {
     // Skip if fixtures don't exist yet
@@ -24,7 +33,7 @@ fn test_verify_with_generated_signature() {
     }
 
     let env = Env::default();
-    let contract_id = env.register(FalconVerifierContract, ());
+    let contract_id = env.register(FalconVerifierContract, (false,));
     let client = FalconVerifierContractClient::new(&env, &contract_id);
 
     // Decode hex fixtures
@@ -49,7 +58,7 @@ fn test_verify_with_generated_signature() {
 #[test]
 fn test_verify_invalid_pubkey_size() {
     let env = Env::default();
-    let contract_id = env.register(FalconVerifierContract, ());
+    let contract_id = env.register(FalconVerifierContract, (false,));
     let client = FalconVerifierContractClient::new(&env, &contract_id);
 
     // Wrong size public key
@@ -64,7 +73,7 @@ fn test_verify_invalid_pubkey_size() {
 #[test]
 fn test_verify_invalid_signature_size() {
     let env = Env::default();
-    let contract_id = env.register(FalconVerifierContract, ());
+    let contract_id = env.register(FalconVerifierContract, (false,));
     let client = FalconVerifierContractClient::new(&env, &contract_id);
 
     // Valid size public key but too short signature
@@ -74,4 +83,437 @@ fn test_verify_invalid_signature_size() {
 
     let result = client.verify(&pubkey, &message, &signature);
     assert!(!result, "Too short signature should fail");
+
+    let signature = Bytes::from_slice(&env, &[0u8; FALCON_SIG_MAX_SIZE as usize + 1]);
+    let result = client.verify(&pubkey, &message, &signature);
+    assert!(!result, "Too long signature should fail");
+}
+
+#[test]
+fn test_verify_signature_size_lower_bound() {
+    let env = Env::default();
+    let contract_id = env.register(FalconVerifierContract, (false,));
+    let client = FalconVerifierContractClient::new(&env, &contract_id);
+
+    // 512 coefficients need at least 9 bits each after the header and nonce
+    assert_eq!(FALCON_SIG_MIN_SIZE, 617);
+
+    let pubkey = Bytes::from_slice(&env, &hex::decode(TEST_PUBKEY_HEX).unwrap());
+    let message = Bytes::from_slice(&env, TEST_MESSAGE);
+    let sig_bytes = hex::decode(TEST_SIGNATURE_HEX).unwrap();
+    let min = FALCON_SIG_MIN_SIZE as usize;
+
+    // Below the bound the call returns before copying or decoding anything
+    env.cost_estimate().budget().reset_default();
+    let short = Bytes::from_slice(&env, &sig_bytes[..min - 1]);
+    assert!(!client.verify(&pubkey, &message, &short));
+    let rejected_cpu = env.cost_estimate().budget().cpu_instruction_cost();
+
+    // At the bound the signature is decoded (and fails, being truncated)
+    env.cost_estimate().budget().reset_default();
+    let at_min = Bytes::from_slice(&env, &sig_bytes[..min]);
+    assert!(!client.verify(&pubkey, &message, &at_min));
+    let decoded_cpu = env.cost_estimate().budget().cpu_instruction_cost();
+
+    assert!(
+        rejected_cpu < decoded_cpu,
+        "{rejected_cpu} >= {decoded_cpu}"
+    );
+}
+
+#[test]
+fn test_verify_signature_without_body() {
+    let env = Env::default();
+    let contract_id = env.register(FalconVerifierContract, (false,));
+    let client = FalconVerifierContractClient::new(&env, &contract_id);
+
+    let pubkey = Bytes::from_slice(&env, &hex::decode(TEST_PUBKEY_HEX).unwrap());
+    let message = Bytes::from_slice(&env, TEST_MESSAGE);
+    let sig_bytes = hex::decode(TEST_SIGNATURE_HEX).unwrap();
+    let nonce = BytesN::from_array(&env, &sig_bytes[1..41].try_into().unwrap());
+
+    // Header and nonce only, then a single body byte
+    for body_len in [0, 1] {
+        let signature = Bytes::from_slice(&env, &sig_bytes[..41 + body_len]);
+        assert!(!client.verify(&pubkey, &message, &signature));
+        let body = Bytes::from_slice(&env, &sig_bytes[41..41 + body_len]);
+        assert!(!client.verify_typed(&pubkey, &message, &nonce, &body, &0x30));
+    }
+}
+
+#[test]
+fn test_verify_empty_message() {
+    let env = Env::default();
+    let contract_id = env.register(FalconVerifierContract, (false,));
+    let client = FalconVerifierContractClient::new(&env, &contract_id);
+
+    let pubkey_bytes = hex::decode(EMPTY_MSG_PUBKEY_HEX).expect("Invalid pubkey hex");
+    let sig_bytes = hex::decode(EMPTY_MSG_SIGNATURE_HEX).expect("Invalid signature hex");
+
+    let pubkey = Bytes::from_slice(&env, &pubkey_bytes);
+    let signature = Bytes::from_slice(&env, &sig_bytes);
+
+    // A zero-length message must reach the hasher as an empty slice
+    let empty = Bytes::new(&env);
+    assert_eq!(empty.len(), 0);
+    let result = client.verify(&pubkey, &empty, &signature);
+    assert!(result, "Empty message signature should verify");
+
+    // A single zero byte is not the empty message
+    let one_byte = Bytes::from_slice(&env, &[0u8]);
+    let result = client.verify(&pubkey, &one_byte, &signature);
+    assert!(
+        !result,
+        "Empty message signature must not verify a 1-byte message"
+    );
+}
+
+#[test]
+fn test_verify_and_record() {
+    let env = Env::default();
+    let contract_id = env.register(FalconVerifierContract, (false,));
+    let client = FalconVerifierContractClient::new(&env, &contract_id);
+
+    let pubkey = Bytes::from_slice(&env, &hex::decode(TEST_PUBKEY_HEX.trim()).unwrap());
+    let signature = Bytes::from_slice(&env, &hex::decode(TEST_SIGNATURE_HEX.trim()).unwrap());
+    let message = Bytes::from_slice(&env, TEST_MESSAGE);
+    let wrong_message = Bytes::from_slice(&env, b"Wrong message");
+
+    let key = client.record_key(&pubkey, &message);
+    let wrong_key = client.record_key(&pubkey, &wrong_message);
+    assert_ne!(key, wrong_key);
+    assert!(!client.was_verified(&key));
+
+    // Nothing is recorded for an invalid signature or a key naming another message
+    assert!(!client.verify_and_record(&pubkey, &wrong_message, &signature, &wrong_key));
+    assert!(!client.verify_and_record(&pubkey, &message, &signature, &wrong_key));
+    assert!(!client.verify_and_record(
+        &pubkey,
+        &message,
+        &signature,
+        &BytesN::from_array(&env, &[0; 32])
+    ));
+    assert!(!client.was_verified(&wrong_key));
+    assert!(!client.was_verified(&key));
+
+    assert!(client.verify_and_record(&pubkey, &message, &signature, &key));
+    assert!(client.was_verified(&key));
+    assert!(!client.was_verified(&wrong_key));
+
+    // The record expires unless it is recorded again
+    let record = (Symbol::new(&env, "VERIFIED"), key.clone());
+    let ttl = || env.as_contract(&contract_id, || env.storage().persistent().get_ttl(&record));
+    assert_eq!(ttl(), RECORD_TTL_LEDGERS);
+    env.ledger().with_mut(|l| l.sequence_number += 20_000);
+    assert_eq!(ttl(), RECORD_TTL_LEDGERS - 20_000);
+    assert!(client.was_verified(&key));
+    assert!(client.verify_and_record(&pubkey, &message, &signature, &key));
+    assert_eq!(ttl(), RECORD_TTL_LEDGERS);
+}
+
+#[test]
+fn test_verify_stats() {
+    let env = Env::default();
+    let client =
+        FalconVerifierContractClient::new(&env, &env.register(FalconVerifierContract, (true,)));
+    let pubkey = Bytes::from_slice(&env, &hex::decode(TEST_PUBKEY_HEX.trim()).unwrap());
+    let message = Bytes::from_slice(&env, TEST_MESSAGE);
+    let signature = Bytes::from_slice(&env, &hex::decode(TEST_SIGNATURE_HEX.trim()).unwrap());
+    let wrong_message = Bytes::from_slice(&env, b"Wrong message");
+    let short_signature = signature.slice(..41);
+    assert_eq!(client.stats(), (0, 0, 0));
+
+    assert!(client.verify(&pubkey, &message, &signature));
+    assert!(!client.verify(&pubkey, &wrong_message, &signature));
+    assert!(client.verify(&pubkey, &message, &signature));
+    // Rejected by the size checks before any decoding, still a failure
+    assert!(!client.verify(&pubkey, &message, &short_signature));
+    assert_eq!(client.stats(), (4, 2, 2));
+
+    // Other entry points are not counted
+    assert!(client.verify_segments(
+        &pubkey,
+        &soroban_sdk::vec![&env, message.clone()],
+        &signature
+    ));
+    assert_eq!(client.stats(), (4, 2, 2));
+
+    // Without the constructor flag nothing is counted or stored
+    let contract_id = env.register(FalconVerifierContract, (false,));
+    let uncounted = FalconVerifierContractClient::new(&env, &contract_id);
+    assert!(uncounted.verify(&pubkey, &message, &signature));
+    assert!(!uncounted.verify(&pubkey, &wrong_message, &signature));
+    assert_eq!(uncounted.stats(), (0, 0, 0));
+    env.as_contract(&contract_id, || {
+        assert!(!env.storage().instance().has(&Symbol::new(&env, "STATS")));
+    });
+}
+
+#[test]
+fn test_verify_512_memo() {
+    let env = Env::default();
+    let contract_id = env.register(FalconVerifierContract, (false,));
+
+    let pubkey = Bytes::from_slice(&env, &hex::decode(TEST_PUBKEY_HEX.trim()).unwrap());
+    let signature = Bytes::from_slice(&env, &hex::decode(TEST_SIGNATURE_HEX.trim()).unwrap());
+    let message = Bytes::from_slice(&env, TEST_MESSAGE);
+    let wrong_message = Bytes::from_slice(&env, b"Wrong message");
+
+    // Returns the result and the CPU it cost
+    let memo = |message: &Bytes| {
+        env.cost_estimate().budget().reset_default();
+        let valid = env.as_contract(&contract_id, || {
+            FalconVerifier::verify_512_memo(&env, &pubkey, message, &signature)
+        });
+        (valid, env.cost_estimate().budget().cpu_instruction_cost())
+    };
+
+    let (valid, miss_cpu) = memo(&message);
+    assert!(valid);
+    let (valid, hit_cpu) = memo(&message);
+    assert!(valid);
+    assert!(hit_cpu < miss_cpu, "hit {hit_cpu} >= miss {miss_cpu}");
+
+    // Invalid results are cached too, under their own key
+    let (valid, wrong_miss_cpu) = memo(&wrong_message);
+    assert!(!valid);
+    let (valid, wrong_hit_cpu) = memo(&wrong_message);
+    assert!(!valid);
+    assert!(wrong_hit_cpu < wrong_miss_cpu);
+
+    // Once the entry expires the next call verifies again
+    env.ledger()
+        .with_mut(|l| l.sequence_number += MEMO_TTL_LEDGERS + 1);
+    let (valid, expired_cpu) = memo(&message);
+    assert!(valid);
+    assert!(expired_cpu > hit_cpu);
+}
+
+#[test]
+fn test_verify_512_aad() {
+    // Signed over the testnet passphrase followed by "Hello, Falcon!"
+    let pubkey = hex::decode(TEST_PUBKEY_HEX.trim()).unwrap();
+    let signature = hex::decode(include_str!("fixtures/aad_signature.hex")).unwrap();
+    let aad = b"Test SDF Network ; September 2015";
+    let verify = FalconVerifier::verify_512_aad;
+
+    assert!(verify(&pubkey, TEST_MESSAGE, aad, &signature));
+    assert!(!verify(
+        &pubkey,
+        TEST_MESSAGE,
+        b"Public Global Stellar Network ; September 2015",
+        &signature
+    ));
+    assert!(!verify(
+        &pubkey,
+        TEST_MESSAGE,
+        &aad[..aad.len() - 1],
+        &signature
+    ));
+    assert!(!verify(&pubkey, TEST_MESSAGE, b"", &signature));
+    assert!(!verify(&pubkey, b"Wrong message", aad, &signature));
+    assert!(!FalconVerifier::verify_512(
+        &pubkey,
+        TEST_MESSAGE,
+        &signature
+    ));
+
+    // The absorbed bytes are nonce || aad || message
+    let concatenated = [&aad[..], TEST_MESSAGE].concat();
+    assert!(FalconVerifier::verify_512(
+        &pubkey,
+        &concatenated,
+        &signature
+    ));
+
+    // Empty aad is plain verification
+    let plain = hex::decode(TEST_SIGNATURE_HEX.trim()).unwrap();
+    assert!(verify(&pubkey, TEST_MESSAGE, b"", &plain));
+    assert!(!verify(&pubkey, TEST_MESSAGE, aad, &plain));
+}
+
+/// A hand-built COSE_Sign1 over `Hello, COSE!`, signed by a separate keypair.
+#[cfg(feature = "std")]
+#[test]
+fn test_verify_512_with_hash_prehash() {
+    // Signed over SHA3-256("Hello, Falcon!") with the COSE fixture key
+    let pubkey = hex::decode(include_str!("fixtures/cose_pubkey.hex")).unwrap();
+    let signature = hex::decode(include_str!("fixtures/prehash_signature.hex")).unwrap();
+    let digest: [u8; 32] = Sha3_256::digest(TEST_MESSAGE).into();
+
+    assert!(FalconVerifier::verify_512_with_hash(
+        &pubkey,
+        TEST_MESSAGE,
+        &signature,
+        HashMode::Sha3Prehash
+    ));
+    assert!(!FalconVerifier::verify_512_with_hash(
+        &pubkey,
+        b"Wrong message",
+        &signature,
+        HashMode::Sha3Prehash
+    ));
+    // The same signature is a standard signature over the digest only
+    assert!(!FalconVerifier::verify_512_with_hash(
+        &pubkey,
+        TEST_MESSAGE,
+        &signature,
+        HashMode::Shake256
+    ));
+    assert!(FalconVerifier::verify_512(&pubkey, &digest, &signature));
+
+    // And a standard signature does not verify as prehashed
+    let test_pubkey = hex::decode(TEST_PUBKEY_HEX.trim()).unwrap();
+    let test_signature = hex::decode(TEST_SIGNATURE_HEX.trim()).unwrap();
+    assert!(FalconVerifier::verify_512_with_hash(
+        &test_pubkey,
+        TEST_MESSAGE,
+        &test_signature,
+        HashMode::default()
+    ));
+    assert!(!FalconVerifier::verify_512_with_hash(
+        &test_pubkey,
+        TEST_MESSAGE,
+        &test_signature,
+        HashMode::Sha3Prehash
+    ));
+}
+
+#[test]
+fn test_verify_cose_falcon() {
+    let pubkey = hex::decode(include_str!("fixtures/cose_pubkey.hex")).unwrap();
+    let signature = hex::decode(include_str!("fixtures/cose_signature.hex")).unwrap();
+    // {3: 0}, a text/plain content type
+    let protected = [0xA1, 0x03, 0x00];
+    let payload = b"Hello, COSE!";
+
+    // [protected, unprotected, payload, signature], with `unprotected` given raw
+    let sign1 = |protected: &[u8], unprotected: &[u8], payload: &[u8], signature: &[u8]| {
+        let mut out = vec![0x84, 0x40 | protected.len() as u8];
+        out.extend_from_slice(protected);
+        out.extend_from_slice(unprotected);
+        out.push(0x40 | payload.len() as u8);
+        out.extend_from_slice(payload);
+        out.extend_from_slice(&[0x59, (signature.len() >> 8) as u8, signature.len() as u8]);
+        out.extend_from_slice(signature);
+        out
+    };
+
+    let cose = sign1(&protected, &[0xA0], payload, &signature);
+    assert!(FalconVerifier::verify_cose_falcon(&cose, &pubkey));
+    // Tag 18 is optional
+    let tagged = [&[0xD2][..], &cose].concat();
+    assert!(FalconVerifier::verify_cose_falcon(&tagged, &pubkey));
+    // Unprotected headers are skipped, even nested: {4: h'01', -1: [1, "a", {}]}
+    let unprotected = [0xA2, 0x04, 0x41, 0x01, 0x20, 0x83, 0x01, 0x61, 0x61, 0xA0];
+    let cose_kid = sign1(&protected, &unprotected, payload, &signature);
+    assert!(FalconVerifier::verify_cose_falcon(&cose_kid, &pubkey));
+
+    // The signature covers the protected header and the payload
+    assert!(!FalconVerifier::verify_cose_falcon(
+        &sign1(&[0xA1, 0x03, 0x01], &[0xA0], payload, &signature),
+        &pubkey
+    ));
+    assert!(!FalconVerifier::verify_cose_falcon(
+        &sign1(&protected, &[0xA0], b"Hello, COSE?", &signature),
+        &pubkey
+    ));
+    // A raw Falcon signature over the payload is not a COSE signature
+    let raw_pubkey = hex::decode(TEST_PUBKEY_HEX.trim()).unwrap();
+    let raw_signature = hex::decode(TEST_SIGNATURE_HEX.trim()).unwrap();
+    assert!(!FalconVerifier::verify_cose_falcon(
+        &sign1(&[], &[0xA0], TEST_MESSAGE, &raw_signature),
+        &raw_pubkey
+    ));
+
+    // Malformed CBOR is rejected, never a panic
+    let malformed: [&[u8]; 8] = [
+        &[],
+        &cose[..cose.len() - 1],
+        &[&cose[..], &[0x00]].concat(),
+        &[&[0x83], &cose[1..]].concat(),
+        &[&[0xD1], &cose[..]].concat(),
+        // Unprotected header that is not a map
+        &sign1(&protected, &[0x80], payload, &signature),
+        // Detached (nil) payload
+        &[
+            &[0x84, 0x43],
+            &protected[..],
+            &[0xA0, 0xF6],
+            &cose[cose.len() - signature.len() - 3..],
+        ]
+        .concat(),
+        // Indefinite-length unprotected map
+        &sign1(&protected, &[0xBF, 0xFF], payload, &signature),
+    ];
+    for cose in malformed {
+        assert!(!FalconVerifier::verify_cose_falcon(cose, &pubkey));
+    }
+    // Nesting past the skip limit
+    let deep = [vec![0xA1, 0x01], [0x81].repeat(16), vec![0x00]].concat();
+    assert!(!FalconVerifier::verify_cose_falcon(
+        &sign1(&protected, &deep, payload, &signature),
+        &pubkey
+    ));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_absorbed_input_matches_hash_to_point() {
+    use soroban_falcon_verifier::{FalconSignature, FALCON_512_N};
+
+    let signature = hex::decode(TEST_SIGNATURE_HEX.trim()).unwrap();
+    let sig = FalconSignature::from_bytes(&signature).unwrap();
+    let nonce = sig.nonce();
+
+    let absorbed = FalconVerifier::absorbed_input(nonce, TEST_MESSAGE);
+    assert_eq!(absorbed.len(), nonce.len() + TEST_MESSAGE.len());
+    assert_eq!(&absorbed[..nonce.len()], nonce);
+    assert_eq!(&absorbed[nonce.len()..], TEST_MESSAGE);
+
+    // SHAKE256 over the absorbed bytes alone gives the verifier's challenge
+    let mut expected = [0u16; FALCON_512_N];
+    assert!(FalconVerifier::hash_to_point(
+        nonce,
+        TEST_MESSAGE,
+        &mut expected
+    ));
+    let mut c0 = [0u16; FALCON_512_N];
+    assert!(FalconVerifier::hash_to_point(&[], &absorbed, &mut c0));
+    assert_eq!(c0, expected);
+
+    // Message-then-nonce is a different challenge
+    let swapped = [TEST_MESSAGE, nonce].concat();
+    assert!(FalconVerifier::hash_to_point(&[], &swapped, &mut c0));
+    assert_ne!(c0, expected);
+}
+
+/// Hex pasted by hand, with line breaks, spaces and `0x` prefixes.
+#[cfg(feature = "std")]
+#[test]
+fn test_verify_512_hex_normalizes_input() {
+    let pubkey_hex = TEST_PUBKEY_HEX.trim();
+    let signature_hex = TEST_SIGNATURE_HEX.trim();
+    let wrapped = |hex: &str, width: usize| {
+        hex.as_bytes()
+            .chunks(width)
+            .map(|line| std::str::from_utf8(line).unwrap())
+            .collect::<Vec<_>>()
+            .join("\r\n")
+    };
+
+    let verify = FalconVerifier::verify_512_hex;
+
+    let pubkey = format!("0x{}\n", wrapped(pubkey_hex, 64));
+    let signature = format!("  0X {}\t", wrapped(signature_hex, 2).replace("\r\n", " "));
+    assert!(verify(&pubkey, TEST_MESSAGE, &signature));
+    assert!(!verify(&pubkey, b"Wrong message", &signature));
+
+    // Genuinely invalid hex is still rejected
+    let odd_length = format!("0x{}", &pubkey_hex[1..]);
+    assert!(!verify(&odd_length, TEST_MESSAGE, &signature));
+    let double_prefix = format!("0x0x{pubkey_hex}");
+    assert!(!verify(&double_prefix, TEST_MESSAGE, &signature));
+    let bad_digit = format!("{}g", &signature_hex[..signature_hex.len() - 1]);
+    assert!(!verify(&pubkey, TEST_MESSAGE, &bad_digit));
 }
@@ -0,0 +1,121 @@
+//! Tests for verifying Merkle leaves under one Falcon signature over the root.
+
+use sha3::{Digest, Sha3_256};
+use soroban_falcon_verifier::FalconVerifier;
+
+// Keypair from seed 0x30..0x5f, as for the COSE fixture
+const MERKLE_PUBKEY_HEX: &str = include_str!("fixtures/cose_pubkey.hex");
+// Compressed signature over the root of the tree of "Merkle leaf 0" to
+// "Merkle leaf 3"
+const MERKLE_SIGNATURE_HEX: &str = include_str!("fixtures/merkle_signature.hex");
+
+fn leaf_hash(leaf: &[u8]) -> [u8; 32] {
+    Sha3_256::new()
+        .chain_update([0x00])
+        .chain_update(leaf)
+        .finalize()
+        .into()
+}
+
+fn node_hash(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    Sha3_256::new()
+        .chain_update([0x01])
+        .chain_update(left)
+        .chain_update(right)
+        .finalize()
+        .into()
+}
+
+fn leaves() -> Vec<Vec<u8>> {
+    (0..4)
+        .map(|i| format!("Merkle leaf {i}").into_bytes())
+        .collect()
+}
+
+/// The root of the 4-leaf tree and the inclusion proof of each leaf.
+fn tree(leaves: &[Vec<u8>]) -> ([u8; 32], Vec<Vec<u8>>) {
+    let l: Vec<_> = leaves.iter().map(|leaf| leaf_hash(leaf)).collect();
+    let n = [node_hash(&l[0], &l[1]), node_hash(&l[2], &l[3])];
+    let root = node_hash(&n[0], &n[1]);
+
+    let proofs = (0..4)
+        .map(|i| {
+            let mut proof = Vec::new();
+            // Sibling leaf, on the right for even indices
+            proof.push((i % 2) as u8);
+            proof.extend_from_slice(&l[i ^ 1]);
+            // Sibling node
+            proof.push((i / 2) as u8);
+            proof.extend_from_slice(&n[(i / 2) ^ 1]);
+            proof
+        })
+        .collect();
+    (root, proofs)
+}
+
+#[test]
+fn test_merkle_accepts_committed_leaves() {
+    let pubkey = hex::decode(MERKLE_PUBKEY_HEX.trim()).unwrap();
+    let signature = hex::decode(MERKLE_SIGNATURE_HEX.trim()).unwrap();
+    let leaves = leaves();
+    let (root, proofs) = tree(&leaves);
+    assert!(FalconVerifier::verify_512(&pubkey, &root, &signature));
+
+    for (leaf, proof) in leaves.iter().zip(&proofs) {
+        assert!(FalconVerifier::verify_512_merkle(
+            &pubkey, leaf, proof, &root, &signature
+        ));
+    }
+}
+
+#[test]
+fn test_merkle_rejects_invalid_leaf_or_proof() {
+    let pubkey = hex::decode(MERKLE_PUBKEY_HEX.trim()).unwrap();
+    let signature = hex::decode(MERKLE_SIGNATURE_HEX.trim()).unwrap();
+    let leaves = leaves();
+    let (root, proofs) = tree(&leaves);
+    let verify = |leaf: &[u8], proof: &[u8], root: &[u8; 32], signature: &[u8]| {
+        FalconVerifier::verify_512_merkle(&pubkey, leaf, proof, root, signature)
+    };
+
+    // A leaf that is not in the tree, or a real leaf with another's proof
+    assert!(!verify(b"Merkle leaf 4", &proofs[1], &root, &signature));
+    assert!(!verify(&leaves[1], &proofs[2], &root, &signature));
+
+    // The sibling on the wrong side, or a side byte that is neither 0 nor 1
+    let mut flipped = proofs[1].clone();
+    flipped[0] ^= 1;
+    assert!(!verify(&leaves[1], &flipped, &root, &signature));
+    let mut bad_side = proofs[1].clone();
+    bad_side[0] = 2;
+    assert!(!verify(&leaves[1], &bad_side, &root, &signature));
+
+    // Truncated proofs, including a whole level dropped
+    assert!(!verify(&leaves[1], &proofs[1][..65], &root, &signature));
+    assert!(!verify(&leaves[1], &proofs[1][..33], &root, &signature));
+    assert!(!verify(&leaves[1], &[], &root, &signature));
+
+    // An inner node passed off as a leaf, with the rest of the path
+    let l: Vec<_> = leaves.iter().map(|leaf| leaf_hash(leaf)).collect();
+    let node = [l[0], l[1]].concat();
+    assert!(!verify(&node, &proofs[0][33..], &root, &signature));
+
+    // A valid proof against a root the signature does not cover
+    let (other_root, other_proofs) = tree(&[
+        leaves[0].clone(),
+        leaves[1].clone(),
+        leaves[2].clone(),
+        b"Merkle leaf 4".to_vec(),
+    ]);
+    assert!(!verify(
+        b"Merkle leaf 4",
+        &other_proofs[3],
+        &other_root,
+        &signature
+    ));
+
+    // A broken signature over the right root
+    let mut bad_signature = signature.clone();
+    bad_signature[1] ^= 1;
+    assert!(!verify(&leaves[1], &proofs[1], &root, &bad_signature));
+}
@@ -0,0 +1,33 @@
+//! Checks that the contract build stays `no_std`.
+//!
+//! The crate only links `std` under the `std` and `testutils` features, so
+//! compiling the library without them fails on any `std` item that leaked
+//! into the contract path, e.g. a hex or COSE helper used by `verify` or
+//! `ntt` without being feature-gated. The check runs `cargo check` on the
+//! library, in a target directory of its own.
+
+use std::path::Path;
+use std::process::Command;
+
+fn check_lib(features: &str) {
+    let manifest_dir = env!("CARGO_MANIFEST_DIR");
+    let output = Command::new(env!("CARGO"))
+        .args(["check", "--lib", "--quiet", "--no-default-features"])
+        .args(["--features", features])
+        .current_dir(manifest_dir)
+        .env("CARGO_TARGET_DIR", Path::new(manifest_dir).join("target/no_std"))
+        .output()
+        .expect("failed to run cargo");
+    assert!(
+        output.status.success(),
+        "library with features [{features}] does not build without std:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+fn test_contract_build_is_no_std() {
+    check_lib("");
+    // Every feature a deployed contract may enable
+    check_lib("fast_reject,strict_keys,debug");
+}
@@ -703,7 +703,15 @@ export async function transferFromSmartAccount(
     // Step 4: Re-simulate with signed auth
     updateStep(3, 'running')
 
-    const sigScVal = StellarSdk.xdr.ScVal.scvBytes(Buffer.from(falconSignature))
+    // HybridSignature struct, fields in key order. The demo account has no
+    // Ed25519 key, so the Ed25519 fields are ignored and left zeroed.
+    const field = (key: string, val: StellarSdk.xdr.ScVal) =>
+      new StellarSdk.xdr.ScMapEntry({ key: StellarSdk.xdr.ScVal.scvSymbol(key), val })
+    const sigScVal = StellarSdk.xdr.ScVal.scvMap([
+      field('ed25519_pk', StellarSdk.xdr.ScVal.scvBytes(Buffer.alloc(32))),
+      field('ed25519_sig', StellarSdk.xdr.ScVal.scvBytes(Buffer.alloc(64))),
+      field('falcon', StellarSdk.xdr.ScVal.scvBytes(Buffer.from(falconSignature))),
+    ])
 
     const signedCreds = StellarSdk.xdr.SorobanCredentials.sorobanCredentialsAddress(
       new StellarSdk.xdr.SorobanAddressCredentials({